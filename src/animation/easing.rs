@@ -157,3 +157,86 @@ pub fn back_in_out(t: f32) -> f32 {
 }
 
 // cubic, quartic, quintic, etc
+
+/// Create a custom timing curve, like CSS's `cubic-bezier(x1, y1, x2, y2)`
+///
+/// The curve starts at `(0, 0)` and ends at `(1, 1)`, with `(x1, y1)` and `(x2, y2)` as its control points.
+///
+/// The `x` coordinates are clamped to `0.0..=1.0` so the curve is a function of time, the `y` coordinates can overshoot.
+///
+/// ```rust
+/// # use too::animation::easing::cubic_bezier;
+/// let ease = cubic_bezier(0.25, 0.1, 0.25, 1.0);
+/// assert_eq!(ease(0.0), 0.0);
+/// assert_eq!(ease(1.0), 1.0);
+/// assert!((ease(0.5) - 0.8024).abs() < 0.001);
+///
+/// // input is clamped
+/// assert_eq!(ease(-1.0), 0.0);
+/// assert_eq!(ease(2.0), 1.0);
+///
+/// // control points outside of the unit square are still well behaved
+/// let wild = cubic_bezier(-2.0, 3.0, 5.0, -4.0);
+/// assert!((0..=100).map(|t| wild(t as f32 / 100.0)).all(f32::is_finite));
+/// ```
+pub fn cubic_bezier(
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+) -> impl Fn(f32) -> f32 + Copy + Send + Sync {
+    // the polynomial coefficients for a curve from 0 to 1
+    const fn coefficients(p1: f32, p2: f32) -> (f32, f32, f32) {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+        (a, b, c)
+    }
+
+    fn sample((a, b, c): (f32, f32, f32), t: f32) -> f32 {
+        a.mul_add(t, b).mul_add(t, c) * t
+    }
+
+    fn derivative((a, b, c): (f32, f32, f32), t: f32) -> f32 {
+        (3.0 * a).mul_add(t, 2.0 * b).mul_add(t, c)
+    }
+
+    let x = coefficients(x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    let y = coefficients(y1, y2);
+
+    move |target: f32| {
+        let target = target.clamp(0.0, 1.0);
+
+        // newton's method converges quickly, unless the slope is flat
+        let mut t = target;
+        for _ in 0..8 {
+            let error = sample(x, t) - target;
+            if error.abs() < 1e-6 && (0.0..=1.0).contains(&t) {
+                return sample(y, t);
+            }
+            let slope = derivative(x, t);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            t -= error / slope;
+        }
+
+        // so fall back to bisecting, x(t) is monotonic because the x coordinates are clamped
+        let (mut lo, mut hi) = (0.0, 1.0);
+        t = target;
+        for _ in 0..32 {
+            let current = sample(x, t);
+            if (current - target).abs() < 1e-6 {
+                break;
+            }
+            if current < target {
+                lo = t
+            } else {
+                hi = t
+            }
+            t = (lo + hi) / 2.0;
+        }
+
+        sample(y, t)
+    }
+}
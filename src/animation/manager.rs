@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use slotmap::Key;
 
@@ -61,9 +61,24 @@ pub struct AnimationMut<'a> {
 /// A manager for dispatching/updating many animations at once.
 ///
 /// This lets you add, retrieve and remove animations from the system
-#[derive(Default)]
 pub struct Animations {
     animations: HashMap<Id, (Animation, f32), DefaultIntHasher>,
+    sequences: HashMap<Id, VecDeque<Animation>, DefaultIntHasher>,
+    completed: Vec<Id>,
+    time_scale: f32,
+    paused: bool,
+}
+
+impl Default for Animations {
+    fn default() -> Self {
+        Self {
+            animations: HashMap::default(),
+            sequences: HashMap::default(),
+            completed: Vec::new(),
+            time_scale: 1.0,
+            paused: false,
+        }
+    }
 }
 
 impl Animations {
@@ -71,6 +86,75 @@ impl Animations {
         Self::default()
     }
 
+    /// Scale the delta-time used to update the animations
+    ///
+    /// `1.0` is normal speed, `0.5` is half speed and `0.0` pauses them. Negative scales are treated as `0.0`
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::animation::{easing, Animation, Animations, Id};
+    /// const FADE: Id = Id::new("fade");
+    /// let mut animations = Animations::new();
+    /// animations.add(
+    ///     FADE,
+    ///     Animation::new()
+    ///         .with(easing::linear)
+    ///         .oneshot(true)
+    ///         .schedule(Duration::from_secs(1))
+    ///         .unwrap(),
+    ///     0.0,
+    /// );
+    ///
+    /// animations.set_time_scale(0.5);
+    /// animations.update(0.5);
+    /// assert_eq!(*animations.value(FADE).value, 0.25);
+    ///
+    /// // paused animations are kept, and continue from where they were
+    /// animations.pause();
+    /// animations.update(10.0);
+    /// assert_eq!(*animations.value(FADE).value, 0.25);
+    ///
+    /// animations.resume();
+    /// animations.set_time_scale(1.0);
+    /// animations.update(0.25);
+    /// assert_eq!(*animations.value(FADE).value, 0.5);
+    /// ```
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0);
+    }
+
+    /// The scale applied to the delta-time, see [`Animations::set_time_scale`]
+    pub const fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Pause all animations, they'll continue from where they were when [resumed](Self::resume)
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resume the animations, if they were [paused](Self::pause)
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Are the animations paused?
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Is any animation still moving?
+    ///
+    /// Finished animations that are kept around don't count, and nothing moves while [paused](Self::pause)
+    pub fn is_active(&self) -> bool {
+        !self.paused
+            && self.time_scale > 0.0
+            && self
+                .animations
+                .values()
+                .any(|(animation, _)| !animation.is_finished())
+    }
+
     /// Add a new animation
     ///
     /// Use the provided key to refer to it
@@ -117,6 +201,69 @@ impl Animations {
         &mut self.animations.entry(key).or_insert_with(once).0
     }
 
+    /// Add a sequence of animations, played one after another
+    ///
+    /// Use the provided key to refer to the currently playing animation
+    ///
+    /// Once the last animation finishes, [`Animations::just_completed`] will be `true` for that update
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::animation::{easing, Animation, Animations, Id};
+    /// let fade = || {
+    ///     Animation::new()
+    ///         .with(easing::linear)
+    ///         .schedule(Duration::from_millis(100))
+    ///         .unwrap()
+    /// };
+    ///
+    /// const REVEAL: Id = Id::new("reveal");
+    /// let mut animations = Animations::new();
+    /// animations.sequence(REVEAL, [fade(), fade()]);
+    ///
+    /// animations.update(0.05);
+    /// assert_eq!(animations.queued(REVEAL), 1);
+    ///
+    /// // the first finishes, the second starts with the left over time
+    /// animations.update(0.06);
+    /// assert_eq!(animations.queued(REVEAL), 0);
+    /// assert!(!animations.just_completed(REVEAL));
+    ///
+    /// animations.update(0.1);
+    /// assert!(animations.just_completed(REVEAL));
+    ///
+    /// animations.update(0.1);
+    /// assert!(!animations.just_completed(REVEAL));
+    /// ```
+    pub fn sequence(
+        &mut self,
+        key: impl Into<Id>,
+        animations: impl IntoIterator<Item = Animation>,
+    ) {
+        let key = key.into();
+        let mut queue: VecDeque<_> = animations.into_iter().collect();
+        let Some(first) = queue.pop_front() else {
+            return;
+        };
+
+        self.animations.insert(key, (first, 0.0));
+        if queue.is_empty() {
+            self.sequences.remove(&key);
+        } else {
+            self.sequences.insert(key, queue);
+        }
+    }
+
+    /// How many animations are waiting to be played after the current one, for this key
+    pub fn queued(&self, key: impl Into<Id>) -> usize {
+        self.sequences.get(&key.into()).map_or(0, VecDeque::len)
+    }
+
+    /// Did the animation (or the last animation of a sequence) for this key finish during the last update?
+    pub fn just_completed(&self, key: impl Into<Id>) -> bool {
+        self.completed.contains(&key.into())
+    }
+
     /// Get an immutable reference to the animation and its value
     ///
     /// This panics if the 'key' was not in the manager
@@ -150,14 +297,42 @@ impl Animations {
     /// Remove this animation, returning it if it existed
     pub fn remove(&mut self, key: impl Into<Id>) -> Option<Animation> {
         let key = key.into();
+        self.sequences.remove(&key);
         self.animations.remove(&key).map(|(animation, _)| animation)
     }
 
     /// Update all animations with this delta-time
+    ///
+    /// The delta-time is scaled by the [time scale](Self::set_time_scale), and nothing is updated while [paused](Self::pause)
     pub fn update(&mut self, dt: f32) {
+        self.completed.clear();
+
+        // nothing moves, so nothing can finish
+        if self.paused || self.time_scale == 0.0 {
+            return;
+        }
+        let dt = dt * self.time_scale;
+
         let mut dead = vec![];
         for (key, (animation, value)) in self.animations.iter_mut() {
+            let finished = animation.is_finished();
             *value = animation.update(dt);
+
+            if !finished && animation.is_finished() {
+                match self.sequences.get_mut(key).and_then(VecDeque::pop_front) {
+                    Some(next) => {
+                        let overflow = animation.overflow();
+                        *animation = next;
+                        *value = animation.update(overflow);
+                        continue;
+                    }
+                    None => {
+                        self.sequences.remove(key);
+                        self.completed.push(*key)
+                    }
+                }
+            }
+
             if animation.is_done() {
                 dead.push(*key);
             }
@@ -171,5 +346,7 @@ impl Animations {
     /// Remove all animations
     pub fn clear(&mut self) {
         self.animations.clear();
+        self.sequences.clear();
+        self.completed.clear();
     }
 }
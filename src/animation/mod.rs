@@ -1,7 +1,7 @@
 //! Interpolated animations
 use std::time::Duration;
 
-use easing::{linear, round_trip, Easing};
+use easing::{linear, round_trip};
 
 pub mod easing;
 
@@ -11,7 +11,7 @@ pub use manager::Animations;
 pub use manager::{AnimationMut, AnimationRef, Id};
 
 struct Keyframe {
-    easing: Easing,
+    easing: Box<dyn Fn(f32) -> f32 + Send + Sync>,
     scheduled: Duration,
     requested: Option<Duration>,
 }
@@ -29,6 +29,7 @@ pub struct Animation {
     repeat: bool,
     round_trip: bool,
     oneshot: bool,
+    reversed: bool,
     position: f32,
 }
 
@@ -41,6 +42,7 @@ impl Animation {
             repeat: false,
             round_trip: false,
             oneshot: false,
+            reversed: false,
             position: 0.0,
         }
     }
@@ -66,9 +68,11 @@ impl Animation {
     /// Add this keyframe.
     ///
     /// The duration of this will come from an even distribution of keyframes that don't specify their time
-    pub fn with(mut self, easing: Easing) -> Self {
+    ///
+    /// This can be any of the functions in [`easing`], or a custom curve like [`easing::cubic_bezier`]
+    pub fn with(mut self, easing: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
         self.keyframes.push(Keyframe {
-            easing,
+            easing: Box::new(easing),
             scheduled: Duration::ZERO,
             requested: None,
         });
@@ -76,9 +80,13 @@ impl Animation {
     }
 
     /// Add this keyframe with a specific duration
-    pub fn with_time(mut self, easing: Easing, duration: Duration) -> Self {
+    pub fn with_time(
+        mut self,
+        easing: impl Fn(f32) -> f32 + Send + Sync + 'static,
+        duration: Duration,
+    ) -> Self {
         self.keyframes.push(Keyframe {
-            easing,
+            easing: Box::new(easing),
             scheduled: Duration::ZERO,
             requested: Some(duration),
         });
@@ -134,7 +142,78 @@ impl Animation {
 
     /// Returns whether the animation is done and should be discarded
     pub fn is_done(&self) -> bool {
-        self.current > self.scheduled && self.oneshot
+        self.is_finished() && self.oneshot
+    }
+
+    /// Returns whether the animation has played past its schedule
+    ///
+    /// When [reversed](Self::reverse), this is whether it has played back past its start
+    ///
+    /// Repeating animations never finish
+    pub fn is_finished(&self) -> bool {
+        let past = if self.reversed {
+            self.current < 0.0
+        } else {
+            self.current > self.scheduled
+        };
+        past && !self.repeat
+    }
+
+    /// Is this animation playing backwards?
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Flip the playback direction, continuing from the current point
+    ///
+    /// A finished animation starts playing back from the end it finished at.
+    ///
+    /// Repeating animations wrap around to the other end, and [`round_trip`](Self::round_trip) is applied the same way in either direction.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::animation::{easing, Animation};
+    /// let mut animation = Animation::new()
+    ///     .with(easing::linear)
+    ///     .schedule(Duration::from_secs(1))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(animation.update(0.75), 0.75);
+    ///
+    /// // interrupted, so rewind from where it was
+    /// animation.reverse();
+    /// assert_eq!(animation.update(0.25), 0.5);
+    /// assert!(!animation.is_finished());
+    ///
+    /// animation.update(0.75);
+    /// assert!(animation.is_finished());
+    ///
+    /// // and it can be played forward again from the start
+    /// animation.reverse();
+    /// assert_eq!(animation.update(0.25), 0.25);
+    ///
+    /// // jump to a specific point
+    /// assert_eq!(animation.set_position(0.5), 0.5);
+    /// assert_eq!(animation.seek(Duration::from_secs(2)), 1.0);
+    /// assert!(!animation.is_finished());
+    /// ```
+    pub fn reverse(&mut self) {
+        self.reversed = !self.reversed;
+        self.current = self.current.clamp(0.0, self.scheduled);
+    }
+
+    /// Jump to a point in the schedule, in the range of `0.0..=1.0`, returning the new value
+    pub fn set_position(&mut self, position: f32) -> f32 {
+        self.current = position.clamp(0.0, 1.0) * self.scheduled;
+        self.sample()
+    }
+
+    /// Jump to a point in time in the schedule, returning the new value
+    ///
+    /// This is clamped to the schedule
+    pub fn seek(&mut self, time: Duration) -> f32 {
+        self.current = time.as_secs_f32().min(self.scheduled);
+        self.sample()
     }
 
     /// Reset the position of each keyframe (e.g. this animation is reset to zero)
@@ -144,16 +223,99 @@ impl Animation {
     }
 
     /// Update this animation with a delta time, returning the new value
+    ///
+    /// If this animation is [reversed](Self::reverse), it plays backwards
+    ///
+    /// See [`Animation::step`] to find out if a keyframe was crossed
     pub fn update(&mut self, dt: f32) -> f32 {
-        self.current += dt;
+        self.step(dt).value
+    }
+
+    /// Update this animation with a delta time, returning the new value and which boundaries were crossed
+    ///
+    /// A repeating animation wrapping around is a new keyframe and a completion, even if it only has one keyframe
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::animation::{easing, Animation};
+    /// let mut animation = Animation::new()
+    ///     .with(easing::linear)
+    ///     .with(easing::linear)
+    ///     .repeat(true)
+    ///     .schedule(Duration::from_secs(1))
+    ///     .unwrap();
+    ///
+    /// let step = animation.step(0.25);
+    /// assert_eq!((step.frame_changed(), step.completed()), (None, false));
+    ///
+    /// let step = animation.step(0.5);
+    /// assert_eq!((step.frame_changed(), step.completed()), (Some(1), false));
+    ///
+    /// // it wrapped around to the first keyframe
+    /// let step = animation.step(0.5);
+    /// assert_eq!((step.frame_changed(), step.completed()), (Some(0), true));
+    ///
+    /// let mut animation = Animation::new()
+    ///     .with(easing::linear)
+    ///     .schedule(Duration::from_secs(1))
+    ///     .unwrap();
+    /// assert!(animation.step(2.0).completed());
+    ///
+    /// // a finished animation only completes once
+    /// assert!(!animation.step(1.0).completed());
+    /// ```
+    pub fn step(&mut self, dt: f32) -> AnimationStep {
+        let finished = self.is_finished();
+        let frame = self.frame_index();
+
+        if self.reversed {
+            self.current -= dt;
+        } else {
+            self.current += dt;
+        }
+
+        let mut wrapped = false;
+        if !(0.0..=self.scheduled).contains(&self.current) && self.repeat {
+            self.current = self.current.rem_euclid(self.scheduled);
+            wrapped = true;
+        }
 
-        if self.current > self.scheduled {
-            if !self.repeat {
-                return self.position;
+        let value = if (0.0..=self.scheduled).contains(&self.current) {
+            self.sample()
+        } else {
+            self.position
+        };
+
+        let current = self.frame_index();
+        AnimationStep {
+            value,
+            completed: wrapped || (!finished && self.is_finished()),
+            frame_changed: (wrapped || current != frame).then_some(current),
+        }
+    }
+
+    // the keyframe the current time is in
+    fn frame_index(&self) -> usize {
+        let mut elapsed = Duration::ZERO;
+        for (i, frame) in self.keyframes.iter().enumerate() {
+            elapsed += frame.scheduled;
+            if self.current <= elapsed.as_secs_f32() {
+                return i;
             }
-            self.current %= self.scheduled
         }
+        self.keyframes.len().saturating_sub(1)
+    }
+
+    // how far this has played past the end it's heading towards
+    fn overflow(&self) -> f32 {
+        if self.reversed {
+            -self.current
+        } else {
+            self.current - self.scheduled
+        }
+    }
 
+    fn sample(&mut self) -> f32 {
         let mut elapsed = Duration::ZERO;
         for frame in &self.keyframes {
             elapsed += frame.scheduled;
@@ -169,3 +331,30 @@ impl Animation {
         self.position
     }
 }
+
+/// The result of [`Animation::step`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AnimationStep {
+    value: f32,
+    completed: bool,
+    frame_changed: Option<usize>,
+}
+
+impl AnimationStep {
+    /// The new value of the animation
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Did the animation reach its end (or its start, when reversed) during this step?
+    ///
+    /// Repeating animations complete each time they wrap around
+    pub const fn completed(&self) -> bool {
+        self.completed
+    }
+
+    /// The index of the keyframe the animation moved into during this step, if it moved into one
+    pub const fn frame_changed(&self) -> Option<usize> {
+        self.frame_changed
+    }
+}
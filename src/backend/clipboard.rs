@@ -0,0 +1,75 @@
+/// A clipboard that text can be copied to, and pasted from
+///
+/// Views can reach the clipboard through [`EventCtx::clipboard`](crate::view::EventCtx::clipboard).
+///
+/// By default, [`NoClipboard`] is used, which does nothing.
+/// With the `clipboard` feature, `SystemClipboard` uses the system clipboard.
+pub trait Clipboard {
+    /// Get the text currently on the clipboard, if there is any
+    fn get(&mut self) -> Option<String>;
+    /// Put this text on the clipboard
+    fn set(&mut self, data: &str);
+}
+
+/// A clipboard that does nothing
+///
+/// Nothing is ever on it, and anything put on it is discarded
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoClipboard;
+
+impl Clipboard for NoClipboard {
+    fn get(&mut self) -> Option<String> {
+        None
+    }
+
+    fn set(&mut self, _data: &str) {}
+}
+
+/// The system clipboard, using [`arboard`]
+///
+/// If the system clipboard can't be opened (e.g. there is no display server), this does nothing, like [`NoClipboard`]
+///
+/// This requires the `clipboard` feature
+#[cfg(feature = "clipboard")]
+pub struct SystemClipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+#[cfg(feature = "clipboard")]
+impl SystemClipboard {
+    /// Open the system clipboard
+    pub fn new() -> Self {
+        Self {
+            inner: arboard::Clipboard::new().ok(),
+        }
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl Default for SystemClipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl std::fmt::Debug for SystemClipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemClipboard")
+            .field("available", &self.inner.is_some())
+            .finish()
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl Clipboard for SystemClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.inner.as_mut()?.get_text().ok()
+    }
+
+    fn set(&mut self, data: &str) {
+        if let Some(clipboard) = &mut self.inner {
+            let _ = clipboard.set_text(data);
+        }
+    }
+}
@@ -10,6 +10,8 @@ pub enum Command {
     SwitchAltScreen,
     /// Request the backend to quit
     RequestQuit,
+    /// Put this text on the system clipboard
+    SetClipboard(String),
 }
 
 impl Command {
@@ -32,4 +34,12 @@ impl Command {
     pub const fn request_quit() -> Self {
         Self::RequestQuit
     }
+
+    /// Put this text on the system clipboard
+    ///
+    /// The terminal backend does this with `OSC 52`, so it works over ssh for terminals that support it.
+    /// Backends without a clipboard ignore this
+    pub fn set_clipboard(data: impl ToString) -> Self {
+        Self::SetClipboard(data.to_string())
+    }
 }
@@ -34,6 +34,22 @@ pub enum Key {
     Escape,
     /// Tab
     Tab,
+    /// The null key (some terminals send this for `Ctrl+Space`)
+    Null,
+    /// Caps lock
+    CapsLock,
+    /// Scroll lock
+    ScrollLock,
+    /// Num lock
+    NumLock,
+    /// Print screen
+    PrintScreen,
+    /// Pause
+    Pause,
+    /// Menu
+    Menu,
+    /// The keypad's begin key (typically 5, when num lock is off)
+    KeypadBegin,
 }
 
 impl Key {
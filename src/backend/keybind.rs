@@ -45,8 +45,164 @@ impl Keybind {
         self.modifiers = Modifiers(self.modifiers.0 | Modifiers::ALT.0);
         self
     }
+
+    /// Add [`Modifiers::SUPER`] (`super` is a keyword, so this can't be named `super`)
+    pub const fn super_key(mut self) -> Self {
+        self.modifiers = Modifiers(self.modifiers.0 | Modifiers::SUPER.0);
+        self
+    }
+
+    /// Add [`Modifiers::META`]
+    pub const fn meta(mut self) -> Self {
+        self.modifiers = Modifiers(self.modifiers.0 | Modifiers::META.0);
+        self
+    }
+
+    /// Parse a keybind from a string, like `"ctrl-c"`, `"alt-F4"`, `"shift-tab"`, `"esc"` or `"a"`
+    ///
+    /// Modifiers and key names can be separated with `-` or `+`, and are case-insensitive.
+    /// A single character is used as-is, so `"A"` and `"a"` are different keys.
+    ///
+    /// `"meta"` is [`Modifiers::META`](crate::backend::Modifiers::META), and `"super"`, `"cmd"` and `"win"` are [`Modifiers::SUPER`](crate::backend::Modifiers::SUPER).
+    /// Terminals only report those two when the keyboard enhancement is enabled (see `term::Config::keyboard_enhancement`), otherwise meta usually arrives as alt
+    ///
+    /// The [`Display`](std::fmt::Display) form of a keybind can be parsed back into it.
+    ///
+    /// ```rust
+    /// # use too::backend::{Key, Keybind, KeybindParseError};
+    /// assert_eq!(Keybind::parse("ctrl-c"), Ok(Keybind::from_char('c').ctrl()));
+    /// assert_eq!(Keybind::parse("Alt-F4"), Ok(Keybind::from_key(Key::Function(4)).alt()));
+    /// assert_eq!(Keybind::parse("SHIFT+tab"), Ok(Keybind::from_key(Key::Tab).shift()));
+    /// assert_eq!(Keybind::parse("esc"), Ok(Keybind::from_key(Key::Escape)));
+    /// assert_eq!(Keybind::parse("ctrl--"), Ok(Keybind::from_char('-').ctrl()));
+    ///
+    /// let keybind = Keybind::from_key(Key::PageDown).ctrl().shift();
+    /// assert_eq!(Keybind::parse(&keybind.to_string()), Ok(keybind));
+    ///
+    /// let keybind = Keybind::from_key(Key::PrintScreen).alt();
+    /// assert_eq!(Keybind::parse(&keybind.to_string()), Ok(keybind));
+    ///
+    /// assert_eq!(Keybind::parse("meta-x"), Ok(Keybind::from_char('x').meta()));
+    /// let keybind = Keybind::from_char('x').meta();
+    /// assert_eq!(keybind.to_string(), "Meta + x");
+    /// assert_eq!(Keybind::parse(&keybind.to_string()), Ok(keybind));
+    ///
+    /// assert_eq!(Keybind::parse("cmd-s"), Ok(Keybind::from_char('s').super_key()));
+    /// let keybind = Keybind::from_char('s').super_key().shift();
+    /// assert_eq!(keybind.to_string(), "Shift + Super + s");
+    /// assert_eq!(Keybind::parse(&keybind.to_string()), Ok(keybind));
+    ///
+    /// assert_eq!(Keybind::parse("hyper-a"), Err(KeybindParseError::UnknownModifier("hyper".into())));
+    /// assert_eq!(Keybind::parse("ctrl-foo"), Err(KeybindParseError::UnknownKey("foo".into())));
+    /// assert_eq!(Keybind::parse("ctrl-"), Err(KeybindParseError::MissingKey));
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, KeybindParseError> {
+        const SEPARATORS: [char; 2] = ['-', '+'];
+
+        let input = input.trim();
+        // the key itself could be a separator, e.g. `ctrl--` or `+`
+        let (modifiers, key) = match input.char_indices().next_back() {
+            Some((i, ch)) if SEPARATORS.contains(&ch) => {
+                let rest = input[..i].trim_end();
+                match rest.strip_suffix(SEPARATORS) {
+                    Some(rest) => (rest, &input[i..]),
+                    None if rest.is_empty() => (rest, &input[i..]),
+                    None => return Err(KeybindParseError::MissingKey),
+                }
+            }
+            Some(..) => match input.rfind(SEPARATORS) {
+                Some(i) => (&input[..i], &input[i + 1..]),
+                None => ("", input),
+            },
+            None => return Err(KeybindParseError::MissingKey),
+        };
+
+        let mut this = Self::from_key(Self::parse_key(key.trim())?);
+        for modifier in modifiers.split(SEPARATORS).map(str::trim) {
+            this = match &*modifier.to_ascii_lowercase() {
+                "" if modifiers.trim().is_empty() => this,
+                "ctrl" | "control" => this.ctrl(),
+                "shift" => this.shift(),
+                "alt" => this.alt(),
+                "super" | "cmd" | "win" => this.super_key(),
+                "meta" => this.meta(),
+                _ => return Err(KeybindParseError::UnknownModifier(modifier.to_string())),
+            }
+        }
+        Ok(this)
+    }
+
+    fn parse_key(key: &str) -> Result<Key, KeybindParseError> {
+        let mut chars = key.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return Ok(Key::Char(ch));
+        }
+
+        let lower = key.to_ascii_lowercase();
+        let key = match &*lower {
+            "" => return Err(KeybindParseError::MissingKey),
+            "space" => Key::Char(' '),
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "pageup" | "pgup" => Key::PageUp,
+            "pagedown" | "pgdn" => Key::PageDown,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "insert" | "ins" => Key::Insert,
+            "enter" | "return" => Key::Enter,
+            "delete" | "del" => Key::Delete,
+            "backspace" => Key::Backspace,
+            "escape" | "esc" => Key::Escape,
+            "tab" => Key::Tab,
+            "null" => Key::Null,
+            "capslock" => Key::CapsLock,
+            "scrolllock" => Key::ScrollLock,
+            "numlock" => Key::NumLock,
+            "printscreen" | "prtsc" => Key::PrintScreen,
+            "pause" => Key::Pause,
+            "menu" => Key::Menu,
+            "keypadbegin" => Key::KeypadBegin,
+            function => match function.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                Some(n @ 1..) => Key::Function(n),
+                _ => return Err(KeybindParseError::UnknownKey(key.to_string())),
+            },
+        };
+        Ok(key)
+    }
+}
+
+impl std::str::FromStr for Keybind {
+    type Err = KeybindParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// An error produced by [`Keybind::parse`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeybindParseError {
+    /// There was no key, only modifiers (or nothing at all)
+    MissingKey,
+    /// This modifier isn't known
+    UnknownModifier(String),
+    /// This key isn't known
+    UnknownKey(String),
 }
 
+impl std::fmt::Display for KeybindParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingKey => f.write_str("no key was provided"),
+            Self::UnknownModifier(modifier) => write!(f, "unknown modifier: {modifier}"),
+            Self::UnknownKey(key) => write!(f, "unknown key: {key}"),
+        }
+    }
+}
+
+impl std::error::Error for KeybindParseError {}
+
 impl From<char> for Keybind {
     fn from(value: char) -> Self {
         let mut this = Self::from_char(value);
@@ -65,13 +221,20 @@ impl From<Key> for Keybind {
 
 impl std::fmt::Display for Keybind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        const FIELDS: [&str; 3] = ["Shift", "Ctrl", "Alt"];
+        self.write(f, " + ")
+    }
+}
+
+impl Keybind {
+    // the separator can't have whitespace in it for a key sequence, as thats what separates its keybinds
+    fn write(&self, f: &mut std::fmt::Formatter<'_>, separator: &str) -> std::fmt::Result {
+        const FIELDS: [&str; 5] = ["Shift", "Ctrl", "Alt", "Super", "Meta"];
 
         let mut seen = false;
         for (flag, repr) in (0..).zip(FIELDS) {
             if (self.modifiers.0 >> flag) & 1 == 1 {
                 if seen {
-                    f.write_str(" + ")?;
+                    f.write_str(separator)?;
                 }
                 f.write_str(repr)?;
                 seen |= true
@@ -79,7 +242,7 @@ impl std::fmt::Display for Keybind {
         }
 
         if seen {
-            f.write_str(" + ")?;
+            f.write_str(separator)?;
         }
 
         match self.key {
@@ -100,6 +263,103 @@ impl std::fmt::Display for Keybind {
             Key::Backspace => f.write_str("Backspace"),
             Key::Escape => f.write_str("Escape"),
             Key::Tab => f.write_str("Tab"),
+            Key::Null => f.write_str("Null"),
+            Key::CapsLock => f.write_str("CapsLock"),
+            Key::ScrollLock => f.write_str("ScrollLock"),
+            Key::NumLock => f.write_str("NumLock"),
+            Key::PrintScreen => f.write_str("PrintScreen"),
+            Key::Pause => f.write_str("Pause"),
+            Key::Menu => f.write_str("Menu"),
+            Key::KeypadBegin => f.write_str("KeypadBegin"),
+        }
+    }
+}
+
+/// A sequence of [`Keybind`]s that are pressed one after another, like `g g` in vim
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeySequence {
+    keys: Vec<Keybind>,
+}
+
+impl KeySequence {
+    pub fn new(keys: impl IntoIterator<Item = impl Into<Keybind>>) -> Self {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Parse a sequence from a string of whitespace-separated keybinds, like `"g g"` or `"ctrl-w ctrl-w"`
+    ///
+    /// Each keybind is parsed with [`Keybind::parse`]
+    ///
+    /// ```rust
+    /// # use too::backend::{Key, Keybind, KeybindParseError, KeySequence};
+    /// assert_eq!(KeySequence::parse("g g"), Ok(KeySequence::new(['g', 'g'])));
+    /// assert_eq!(
+    ///     KeySequence::parse("ctrl-w  esc"),
+    ///     Ok(KeySequence::new([Keybind::from_char('w').ctrl(), Keybind::from_key(Key::Escape)]))
+    /// );
+    /// assert_eq!(KeySequence::parse(" "), Err(KeybindParseError::MissingKey));
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, KeybindParseError> {
+        let keys = input
+            .split_whitespace()
+            .map(Keybind::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(KeybindParseError::MissingKey);
+        }
+        Ok(Self { keys })
+    }
+
+    /// The keybinds, in the order they have to be pressed
+    pub fn keys(&self) -> &[Keybind] {
+        &self.keys
+    }
+
+    /// Does this sequence begin with these keybinds?
+    pub fn starts_with(&self, keys: &[Keybind]) -> bool {
+        self.keys.starts_with(keys)
+    }
+}
+
+impl std::str::FromStr for KeySequence {
+    type Err = KeybindParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<Keybind> for KeySequence {
+    fn from(value: Keybind) -> Self {
+        Self { keys: vec![value] }
+    }
+}
+
+/// The keybinds are separated by spaces, in the same form [`KeySequence::parse`] reads
+///
+/// ```rust
+/// # use too::backend::{Key, Keybind, KeySequence};
+/// let seq = KeySequence::new([Keybind::from_char('w').ctrl(), Keybind::from_char('w').ctrl()]);
+/// assert_eq!(seq.to_string(), "Ctrl+w Ctrl+w");
+/// assert_eq!(KeySequence::parse(&seq.to_string()), Ok(seq));
+///
+/// let seq = KeySequence::new([
+///     Keybind::from_char('+').ctrl().shift(),
+///     Keybind::from_char(' '),
+///     Keybind::from_key(Key::PageDown).alt(),
+/// ]);
+/// assert_eq!(seq.to_string(), "Shift+Ctrl++ Space Alt+PageDown");
+/// assert_eq!(KeySequence::parse(&seq.to_string()), Ok(seq));
+/// ```
+impl std::fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, key) in self.keys.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            key.write(f, "+")?;
         }
+        Ok(())
     }
 }
@@ -10,7 +10,7 @@ mod key;
 pub use key::Key;
 
 mod keybind;
-pub use keybind::Keybind;
+pub use keybind::{KeySequence, Keybind, KeybindParseError};
 
 mod modifiers;
 pub use modifiers::Modifiers;
@@ -24,6 +24,12 @@ pub use current_screen::CurrentScreen;
 mod command;
 pub use command::Command;
 
+mod clipboard;
+pub use clipboard::{Clipboard, NoClipboard};
+
+#[cfg(feature = "clipboard")]
+pub use clipboard::SystemClipboard;
+
 /// An abstraction over a writable backend
 pub trait Backend {
     /// The writer for this backend
@@ -46,6 +52,25 @@ pub trait EventReader {
     ///
     /// This'll return None if an event isn't ready
     fn try_read_event(&mut self) -> Option<Event>;
+
+    /// Waits up to `timeout` for an [`Event`]
+    ///
+    /// This'll return None if an event didn't arrive in time
+    ///
+    /// By default this polls [`try_read_event`](Self::try_read_event) every millisecond
+    fn wait_for_event(&mut self, timeout: std::time::Duration) -> Option<Event> {
+        let start = std::time::Instant::now();
+        loop {
+            if let Some(ev) = self.try_read_event() {
+                return Some(ev);
+            }
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return None;
+            }
+            std::thread::sleep(remaining.min(std::time::Duration::from_millis(1)));
+        }
+    }
 }
 
 mod dummy;
@@ -7,6 +7,14 @@ impl Modifiers {
     pub const SHIFT: Self = Self(1 << 0);
     pub const CTRL: Self = Self(1 << 1);
     pub const ALT: Self = Self(1 << 2);
+    /// The Super key, e.g. `Cmd` on macOS or the `Windows` key
+    ///
+    /// This is only reported when the terminal's keyboard enhancement is enabled (see `term::Config::keyboard_enhancement`), and the terminal supports it
+    pub const SUPER: Self = Self(1 << 3);
+    /// The Meta key
+    ///
+    /// Like [`Modifiers::SUPER`], this is only reported with the keyboard enhancement. Otherwise most terminals report it as [`Modifiers::ALT`]
+    pub const META: Self = Self(1 << 4);
 }
 
 impl Modifiers {
@@ -26,6 +34,14 @@ impl Modifiers {
         (self.0 >> 2) & 1 == 1
     }
 
+    pub const fn is_super(&self) -> bool {
+        (self.0 >> 3) & 1 == 1
+    }
+
+    pub const fn is_meta(&self) -> bool {
+        (self.0 >> 4) & 1 == 1
+    }
+
     pub const fn is_shift_only(&self) -> bool {
         self.0 == Self::SHIFT.0
     }
@@ -38,6 +54,14 @@ impl Modifiers {
         self.0 == Self::ALT.0
     }
 
+    pub const fn is_super_only(&self) -> bool {
+        self.0 == Self::SUPER.0
+    }
+
+    pub const fn is_meta_only(&self) -> bool {
+        self.0 == Self::META.0
+    }
+
     pub const fn remove(&self, other: Self) -> Self {
         Self(self.0 & !other.0)
     }
@@ -76,7 +100,7 @@ impl std::ops::Not for Modifiers {
 
 impl std::fmt::Debug for Modifiers {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        const FIELDS: [&str; 3] = ["SHIFT", "CTRL", "ALT"];
+        const FIELDS: [&str; 5] = ["SHIFT", "CTRL", "ALT", "SUPER", "META"];
 
         let mut seen = false;
         for (flag, repr) in (0..).zip(FIELDS) {
@@ -106,6 +130,8 @@ impl std::str::FromStr for Modifiers {
                 s if s.eq_ignore_ascii_case("shift") => Self::SHIFT,
                 s if s.eq_ignore_ascii_case("ctrl") => Self::CTRL,
                 s if s.eq_ignore_ascii_case("alt") => Self::ALT,
+                s if s.eq_ignore_ascii_case("super") => Self::SUPER,
+                s if s.eq_ignore_ascii_case("meta") => Self::META,
                 attr => return Err(format!("unknown modifier: {attr}")),
             }
         }
@@ -9,6 +9,10 @@ pub enum MouseButton {
     Secondary,
     /// The middle button (typically, the scroll wheel)
     Middle,
+    /// The back button (typically, a side button used to navigate back)
+    Back,
+    /// The forward button (typically, a side button used to navigate forward)
+    Forward,
 }
 
 impl MouseButton {
@@ -26,4 +30,14 @@ impl MouseButton {
     pub const fn is_middle(&self) -> bool {
         matches!(self, Self::Middle)
     }
+
+    /// Is this the back button?
+    pub const fn is_back(&self) -> bool {
+        matches!(self, Self::Back)
+    }
+
+    /// Is this the forward button?
+    pub const fn is_forward(&self) -> bool {
+        matches!(self, Self::Forward)
+    }
 }
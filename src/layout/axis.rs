@@ -1,3 +1,5 @@
+use crate::math::{Size, Vec2};
+
 /// A direction such as _Horizontal_ or _Vertical_
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Axis {
@@ -41,6 +43,80 @@ impl Axis {
         }
     }
 
+    /// Pack a main and cross component into a [`Vec2`]
+    ///
+    /// This is a `const` version of [`Axis::pack`]
+    pub const fn pack_vec2(&self, main: i32, cross: i32) -> Vec2 {
+        match self {
+            Self::Horizontal => Vec2::new(main, cross),
+            Self::Vertical => Vec2::new(cross, main),
+        }
+    }
+
+    /// Unpack a [`Vec2`] into its main and cross components
+    ///
+    /// This is a `const` version of [`Axis::unpack`]
+    pub const fn unpack_vec2(&self, value: Vec2) -> (i32, i32) {
+        match self {
+            Self::Horizontal => (value.x, value.y),
+            Self::Vertical => (value.y, value.x),
+        }
+    }
+
+    /// Replace just the main component of a [`Vec2`]
+    ///
+    /// ```rust
+    /// # use too::{layout::Axis, math::vec2};
+    /// assert_eq!(Axis::Horizontal.with_main(vec2(1, 2), 5), vec2(5, 2));
+    /// assert_eq!(Axis::Vertical.with_main(vec2(1, 2), 5), vec2(1, 5));
+    /// assert_eq!(Axis::Vertical.with_cross(vec2(1, 2), 5), vec2(5, 2));
+    ///
+    /// let (main, cross) = Axis::Vertical.unpack_vec2(vec2(1, 2));
+    /// assert_eq!(Axis::Vertical.pack_vec2(main, cross), vec2(1, 2));
+    /// ```
+    pub const fn with_main(&self, value: Vec2, main: i32) -> Vec2 {
+        let (_, cross) = self.unpack_vec2(value);
+        self.pack_vec2(main, cross)
+    }
+
+    /// Replace just the cross component of a [`Vec2`]
+    pub const fn with_cross(&self, value: Vec2, cross: i32) -> Vec2 {
+        let (main, _) = self.unpack_vec2(value);
+        self.pack_vec2(main, cross)
+    }
+
+    /// Pack a main and cross component into a [`Size`]
+    ///
+    /// This is a `const` version of [`Axis::pack`]
+    pub const fn pack_size(&self, main: f32, cross: f32) -> Size {
+        match self {
+            Self::Horizontal => Size::new(main, cross),
+            Self::Vertical => Size::new(cross, main),
+        }
+    }
+
+    /// Unpack a [`Size`] into its main and cross components
+    ///
+    /// This is a `const` version of [`Axis::unpack`]
+    pub const fn unpack_size(&self, size: Size) -> (f32, f32) {
+        match self {
+            Self::Horizontal => (size.width, size.height),
+            Self::Vertical => (size.height, size.width),
+        }
+    }
+
+    /// Replace just the main component of a [`Size`]
+    pub const fn with_main_size(&self, size: Size, main: f32) -> Size {
+        let (_, cross) = self.unpack_size(size);
+        self.pack_size(main, cross)
+    }
+
+    /// Replace just the cross component of a [`Size`]
+    pub const fn with_cross_size(&self, size: Size, cross: f32) -> Size {
+        let (main, _) = self.unpack_size(size);
+        self.pack_size(main, cross)
+    }
+
     pub const fn is_vertical(&self) -> bool {
         matches!(self, Self::Vertical)
     }
@@ -66,9 +66,16 @@ impl LinearAllocator {
 
         let next_main_pos = self.cursor.y + main_sign * size.y;
         if self.anchor.y.exceeds_bounds(next_main_pos, main_rect_max) {
-            if !self.state.wrap {
+            if !self.state.wraps() {
                 return None;
             }
+
+            // it won't fit in any track, so don't start a new one for it
+            let track_main_pos = main_rect_min + main_sign * size.y;
+            if self.state.grid_wrap && self.anchor.y.exceeds_bounds(track_main_pos, main_rect_max) {
+                return None;
+            }
+
             self.cursor.y = main_rect_min;
             self.cursor.x += (self.max.x + self.state.spacing.x) * cross_sign;
             self.max = Vec2::ZERO;
@@ -85,7 +92,7 @@ impl LinearAllocator {
         );
         let rect = Rect::from_min_size(self.cursor - offset, size);
 
-        self.cursor.y += (size.y + self.state.spacing.y) * main_sign;
+        self.cursor.y += (size.y + self.state.spacing.y + self.state.gap) * main_sign;
         self.max.x = self.max.x.max(size.x);
 
         Some(rect)
@@ -104,9 +111,16 @@ impl LinearAllocator {
 
         let next_main_pos = self.cursor.x + main_sign * size.x;
         if self.anchor.x.exceeds_bounds(next_main_pos, main_rect_max) {
-            if !self.state.wrap {
+            if !self.state.wraps() {
                 return None;
             }
+
+            // it won't fit in any track, so don't start a new one for it
+            let track_main_pos = main_rect_min + main_sign * size.x;
+            if self.state.grid_wrap && self.anchor.x.exceeds_bounds(track_main_pos, main_rect_max) {
+                return None;
+            }
+
             self.cursor.x = main_rect_min;
             self.cursor.y += (self.max.y + self.state.spacing.y) * cross_sign;
             self.max = Vec2::ZERO;
@@ -123,7 +137,7 @@ impl LinearAllocator {
         );
         let rect = Rect::from_min_size(self.cursor - offset, size);
 
-        self.cursor.x += (size.x + self.state.spacing.x) * main_sign;
+        self.cursor.x += (size.x + self.state.spacing.x + self.state.gap) * main_sign;
         self.max.y = self.max.y.max(size.y);
 
         Some(rect)
@@ -135,7 +149,9 @@ impl LinearAllocator {
 pub struct LinearLayout {
     axis: Axis,
     wrap: bool,
+    grid_wrap: bool,
     spacing: Vec2,
+    gap: i32,
     anchor: Anchor2,
 }
 
@@ -153,7 +169,9 @@ impl LinearLayout {
     const DEFAULT: Self = Self {
         axis: Axis::Horizontal,
         wrap: false,
+        grid_wrap: false,
         spacing: Vec2::ZERO,
+        gap: 0,
         anchor: Anchor2::LEFT_TOP,
     };
 
@@ -218,6 +236,46 @@ impl LinearLayout {
         self
     }
 
+    /// Should this layout keep starting new tracks until it runs out of space on both axes?
+    ///
+    /// A track is a row for a horizontal layout, or a column for a vertical layout. New tracks start from the side the anchor is on.
+    ///
+    /// This implies [`wrap`](Self::wrap), and an element too long for a whole track is not allocated rather than overflowing the track.
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, layout::{Anchor2, LinearLayout}};
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 2));
+    /// let mut layout = LinearLayout::vertical()
+    ///     .grid_wrap(true)
+    ///     .anchor(Anchor2::RIGHT_TOP)
+    ///     .spacing(vec2(1, 0))
+    ///     .layout(rect);
+    ///
+    /// // the first column is against the right edge
+    /// assert_eq!(layout.allocate(vec2(3, 1)), Some(Rect::from_min_size(pos2(7, 0), vec2(3, 1))));
+    /// assert_eq!(layout.allocate(vec2(2, 1)), Some(Rect::from_min_size(pos2(8, 1), vec2(2, 1))));
+    ///
+    /// // and the next one starts to the left of it, from the top again
+    /// assert_eq!(layout.allocate(vec2(4, 1)), Some(Rect::from_min_size(pos2(2, 0), vec2(4, 1))));
+    ///
+    /// // this is too tall for any column
+    /// assert_eq!(layout.allocate(vec2(1, 3)), None);
+    ///
+    /// // but smaller ones still fit in the current column
+    /// assert_eq!(layout.allocate(vec2(1, 1)), Some(Rect::from_min_size(pos2(5, 1), vec2(1, 1))));
+    ///
+    /// // and there's no room left for another column
+    /// assert_eq!(layout.allocate(vec2(3, 1)), None);
+    /// ```
+    pub const fn grid_wrap(mut self, grid_wrap: bool) -> Self {
+        self.grid_wrap = grid_wrap;
+        self
+    }
+
+    const fn wraps(&self) -> bool {
+        self.wrap || self.grid_wrap
+    }
+
     /// The spacing for the layout.
     ///
     /// Spacing is the gap between 2 elements.
@@ -228,6 +286,37 @@ impl LinearLayout {
         self
     }
 
+    /// The gap between 2 elements along the [`Axis`] of the layout.
+    ///
+    /// This is added to the [`spacing`](Self::spacing) on that axis. There's no gap before the first element or after the last one.
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, layout::{Anchor2, LinearLayout}};
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 2));
+    /// let mut layout = LinearLayout::horizontal()
+    ///     .wrap(true)
+    ///     .gap(2)
+    ///     .anchor(Anchor2::RIGHT_TOP)
+    ///     .layout(rect);
+    ///
+    /// assert_eq!(layout.allocate(vec2(3, 1)), Some(Rect::from_min_size(pos2(7, 0), vec2(3, 1))));
+    /// assert_eq!(layout.allocate(vec2(3, 1)), Some(Rect::from_min_size(pos2(2, 0), vec2(3, 1))));
+    ///
+    /// // this would fit in the row, but not with the gap before it
+    /// assert_eq!(layout.allocate(vec2(1, 1)), Some(Rect::from_min_size(pos2(9, 1), vec2(1, 1))));
+    ///
+    /// // a single element has no gap after it, so it can fill the track
+    /// let mut layout = LinearLayout::vertical()
+    ///     .gap(2)
+    ///     .layout(rect);
+    /// assert_eq!(layout.allocate(vec2(1, 2)), Some(Rect::from_min_size(pos2(0, 0), vec2(1, 2))));
+    /// assert_eq!(layout.allocate(vec2(1, 1)), None);
+    /// ```
+    pub const fn gap(mut self, gap: i32) -> Self {
+        self.gap = gap;
+        self
+    }
+
     /// Construct the [`LinearAllocator`] from this type
     ///
     /// This takes in the target [`Rect`] that the allocator will fit everything into.
@@ -5,6 +5,7 @@
 //! | --- | --- | -- |
 //! |`terminal` | enable the terminal backend | `true` |
 //! |`sync` | enable `Send`+`Sync` wrappers | `false` |
+//! |`clipboard` | enable the system clipboard, with [`arboard`](https://docs.rs/arboard) | `false` |
 //! |`profile` | enable [`profiling`](https://docs.rs/profiling/1.0.16/profiling/index.html) support | `false` |
 //!
 //! # Simple examples
@@ -108,7 +109,7 @@ pub use compact_str::format_compact as ඞ_dont_use_this_because_semver;
 #[cfg(feature = "terminal")]
 mod run;
 #[cfg(feature = "terminal")]
-pub use run::{application, run, RunConfig};
+pub use run::{application, application_with, run, RepaintMode, RunConfig};
 
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
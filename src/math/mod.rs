@@ -1,7 +1,7 @@
 //! Math types and  helpers used by this crate
 
 mod rect;
-pub use rect::{rect, Rect};
+pub use rect::{rect, Cells, Rect};
 
 mod vec2;
 pub use vec2::{vec2, Vec2};
@@ -17,7 +17,7 @@ pub use pos2::{pos2, Pos2};
 
 mod num;
 pub use num::Num;
-pub use num::{almost_eq, denormalize, inverse_lerp, lerp, normalize, remap};
+pub use num::{almost_eq, denormalize, inverse_lerp, lerp, normalize, remap, remap_clamp};
 
 mod margin;
 pub use margin::Margin;
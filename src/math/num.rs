@@ -60,13 +60,44 @@ pub fn denormalize(value: f32, range: RangeInclusive<f32>) -> f32 {
     value * (range.end() - range.start()) + range.start()
 }
 
+/// Remap a value from one range to another
+///
+/// This works with descending ranges, and values outside of the `from` range end up outside of the `to` range.
+///
+/// If the `from` range is empty, this returns the start of the `to` range
+///
+/// ```rust
+/// # use too::math::remap;
+/// assert_eq!(remap(0.25, 0.0..=1.0, -100.0..=100.0), -50.0);
+/// assert_eq!(remap(0.25, 0.0..=1.0, 100.0..=-100.0), 50.0);
+/// assert_eq!(remap(2.0, 0.0..=1.0, 0.0..=10.0), 20.0);
+/// ```
 pub fn remap<N: Num>(val: N, from: RangeInclusive<N>, to: RangeInclusive<N>) -> N {
     let (x1, y1) = (*from.start(), *from.end());
     let (x2, y2) = (*to.start(), *to.end());
     // inverse lerp to map val to the from range
     // lerp the result with the to range to get the remapped value
-    let t = lerp(x2, y2, (val - x1) / (y1 - x1));
-    clamp_num(t, x2, y2)
+    match inverse_lerp(x1, y1, val) {
+        Some(t) => lerp(x2, y2, t),
+        None => x2,
+    }
+}
+
+/// Remap a value from one range to another, keeping it inside of the `to` range
+///
+/// ```rust
+/// # use too::math::remap_clamp;
+/// assert_eq!(remap_clamp(2.0, 0.0..=1.0, 0.0..=10.0), 10.0);
+/// assert_eq!(remap_clamp(-1.0, 0.0..=1.0, 0.0..=10.0), 0.0);
+/// assert_eq!(remap_clamp(2.0, 0.0..=1.0, 100.0..=-100.0), -100.0);
+/// assert_eq!(remap_clamp(0.5, 1.0..=0.0, 0.0..=10.0), 5.0);
+/// ```
+pub fn remap_clamp<N: Num>(val: N, from: RangeInclusive<N>, to: RangeInclusive<N>) -> N {
+    let (min, max) = match (*to.start(), *to.end()) {
+        (start, end) if start <= end => (start, end),
+        (start, end) => (end, start),
+    };
+    clamp_num(remap(val, from, to), min, max)
 }
 
 fn clamp_num<N: Num>(value: N, min: N, max: N) -> N {
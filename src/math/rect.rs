@@ -1,4 +1,7 @@
-use crate::math::{lerp, pos2, vec2, Pos2, Vec2};
+use crate::{
+    layout::Axis,
+    math::{lerp, pos2, vec2, Pos2, Vec2},
+};
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Rect {
@@ -77,18 +80,38 @@ impl Rect {
         (self.distance_sq_to_point(pos) as f32).sqrt() as _
     }
 
+    /// The squared distance from this point to the nearest cell in the rect
+    ///
+    /// This is `0` for any point the rect [`contains`](Self::contains). The right and bottom edges are exclusive, so they're a cell away.
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, vec2, Rect};
+    /// let rect = Rect::from_min_size(pos2(2, 2), vec2(3, 3));
+    /// assert_eq!(rect.distance_sq_to_point(pos2(3, 4)), 0);
+    /// assert_eq!(rect.distance_sq_to_point(pos2(5, 2)), 1);
+    /// assert_eq!(rect.distance_sq_to_point(pos2(0, 0)), 8);
+    /// ```
     pub fn distance_sq_to_point(&self, pos: Pos2) -> i32 {
-        const fn distance(min: i32, max: i32, t: i32) -> i32 {
-            match () {
-                _ if min > t => min - t,
-                _ if t > max => t - max,
-                _ => 0,
-            }
-        }
-
-        let dx = distance(self.min.x, self.max.x, pos.x);
-        let dy = distance(self.min.y, self.max.y, pos.y);
-        pos2(dx, dy).length_sq()
+        (pos - self.nearest_point(pos)).length_sq()
+    }
+
+    /// The cell in the rect that is nearest to this point
+    ///
+    /// A point inside the rect is its own nearest point. An empty rect's nearest point is its `min`.
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, vec2, Rect};
+    /// let rect = Rect::from_min_size(pos2(2, 2), vec2(3, 3));
+    /// assert_eq!(rect.nearest_point(pos2(3, 4)), pos2(3, 4));
+    /// assert_eq!(rect.nearest_point(pos2(10, 3)), pos2(4, 3));
+    /// assert_eq!(rect.nearest_point(pos2(0, 0)), pos2(2, 2));
+    /// ```
+    pub fn nearest_point(&self, pos: Pos2) -> Pos2 {
+        let nearest = |min: i32, max: i32, t: i32| t.min(max - 1).max(min);
+        pos2(
+            nearest(self.min.x, self.max.x, pos.x),
+            nearest(self.min.y, self.max.y, pos.y),
+        )
     }
 
     pub const fn contains(&self, pos: Pos2) -> bool {
@@ -140,11 +163,28 @@ impl Rect {
         Self::from_min_max(self.min.max(other.min), self.max.min(other.max))
     }
 
+    /// Does this rect overlap with the other rect?
+    ///
+    /// Like [`Rect::contains`], the right and bottom edges are exclusive, so rects that only touch don't intersect
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, Rect};
+    /// let a = Rect::from_min_max(pos2(0, 0), pos2(4, 4));
+    /// assert!(a.intersects(Rect::from_min_max(pos2(3, 3), pos2(6, 6))));
+    /// assert!(a.intersects(Rect::from_min_max(pos2(1, 1), pos2(2, 2))));
+    ///
+    /// // only touching the edge
+    /// assert!(!a.intersects(Rect::from_min_max(pos2(4, 0), pos2(6, 4))));
+    /// // an empty rect doesn't overlap anything
+    /// assert!(!a.intersects(Rect::from_min_max(pos2(2, 2), pos2(2, 2))));
+    /// ```
     pub const fn intersects(&self, other: Self) -> bool {
-        self.min.x <= other.max.x
-            && other.min.x <= self.max.x
-            && self.min.y <= other.min.y
-            && other.max.y <= self.max.y
+        self.has_area()
+            && other.has_area()
+            && self.min.x < other.max.x
+            && other.min.x < self.max.x
+            && self.min.y < other.max.y
+            && other.min.y < self.max.y
     }
 
     pub const fn partial_intersects(&self, other: &Self) -> bool {
@@ -154,10 +194,34 @@ impl Rect {
         contains_inclusive(self, other.min)
     }
 
+    /// The smallest rect that contains both this rect and the other rect
+    ///
+    /// If either rect is empty, the other rect is returned unchanged
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, Rect};
+    /// let a = Rect::from_min_max(pos2(0, 0), pos2(2, 2));
+    /// let b = Rect::from_min_max(pos2(5, 1), pos2(6, 4));
+    /// assert_eq!(a.union(b), Rect::from_min_max(pos2(0, 0), pos2(6, 4)));
+    ///
+    /// let empty = Rect::from_min_max(pos2(10, 10), pos2(10, 10));
+    /// assert_eq!(a.union(empty), a);
+    /// assert_eq!(empty.union(b), b);
+    /// ```
     pub fn union(&self, other: Self) -> Self {
+        if !other.has_area() {
+            return *self;
+        }
+        if !self.has_area() {
+            return other;
+        }
         Self::from_min_max(self.min.min(other.min), self.max.max(other.max))
     }
 
+    const fn has_area(&self) -> bool {
+        self.width() > 0 && self.height() > 0
+    }
+
     pub const fn width(&self) -> i32 {
         self.max.x.saturating_sub(self.min.x)
     }
@@ -256,6 +320,139 @@ impl Rect {
         (left, right)
     }
 
+    /// Split this rect into columns, with widths in proportion to the `ratios`
+    ///
+    /// The ratios don't have to sum to `1.0`, they're normalized first. If they sum to zero (or less) the columns are equal.
+    ///
+    /// Each column is rounded down, and what's left over goes to the last one
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, vec2, Rect};
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 2));
+    /// let columns = rect.split_horizontal_by(&[1.0, 1.0, 2.0]);
+    /// assert_eq!(columns, [
+    ///     Rect::from_min_size(pos2(0, 0), vec2(2, 2)),
+    ///     Rect::from_min_size(pos2(2, 0), vec2(2, 2)),
+    ///     Rect::from_min_size(pos2(4, 0), vec2(6, 2)),
+    /// ]);
+    ///
+    /// // an empty rect is split into empty columns
+    /// let rect = Rect::from_min_size(pos2(3, 3), vec2(0, 0));
+    /// assert!(rect.split_horizontal_by(&[0.5, 0.5]).iter().all(|column| column.is_empty()));
+    /// ```
+    pub fn split_horizontal_by(self, ratios: &[f32]) -> Vec<Self> {
+        self.split_by(Axis::Horizontal, ratios)
+    }
+
+    /// Split this rect into rows, with heights in proportion to the `ratios`
+    ///
+    /// See [`Rect::split_horizontal_by`]
+    pub fn split_vertical_by(self, ratios: &[f32]) -> Vec<Self> {
+        self.split_by(Axis::Vertical, ratios)
+    }
+
+    /// Split this rect into `n` equal tracks along the [`Axis`]
+    ///
+    /// What's left over goes to the last track
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, layout::Axis};
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(4, 10));
+    /// let heights: Vec<_> = rect.split_n(Axis::Vertical, 3).iter().map(Rect::height).collect();
+    /// assert_eq!(heights, [3, 3, 4]);
+    /// ```
+    pub fn split_n(self, axis: Axis, n: usize) -> Vec<Self> {
+        self.split_by(axis, &vec![1.0; n])
+    }
+
+    fn split_by(self, axis: Axis, ratios: &[f32]) -> Vec<Self> {
+        let origin: i32 = axis.main(self.min);
+        let length = axis.main::<i32>(self.size()).max(0);
+        let ratios = ratios
+            .iter()
+            .map(|&ratio| if ratio > 0.0 { ratio } else { 0.0 });
+        let total: f32 = ratios.clone().sum();
+
+        let mut start = origin;
+        let mut out = Vec::with_capacity(ratios.len());
+        for (i, ratio) in ratios.clone().enumerate() {
+            let size = match () {
+                _ if i == ratios.len() - 1 => origin + length - start,
+                _ if total > 0.0 && total.is_finite() => (length as f32 * ratio / total) as i32,
+                _ => length / ratios.len() as i32,
+            };
+
+            let rect = match axis {
+                Axis::Horizontal => {
+                    Self::from_min_size(pos2(start, self.min.y), vec2(size, self.height().max(0)))
+                }
+                Axis::Vertical => {
+                    Self::from_min_size(pos2(self.min.x, start), vec2(self.width().max(0), size))
+                }
+            };
+            out.push(rect);
+            start += size;
+        }
+        out
+    }
+
+    /// Iterate over every position in this rect, row by row
+    ///
+    /// This doesn't include the right and bottom edges, like [`Rect::contains`]
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, vec2, Rect};
+    /// let rect = Rect::from_min_size(pos2(1, 1), vec2(2, 2));
+    /// let cells: Vec<_> = rect.cells().collect();
+    /// assert_eq!(cells, [pos2(1, 1), pos2(2, 1), pos2(1, 2), pos2(2, 2)]);
+    /// assert_eq!(rect.cells().len(), 4);
+    ///
+    /// assert_eq!(Rect::from_min_size(pos2(1, 1), vec2(2, 0)).cells().len(), 0);
+    /// ```
+    pub fn cells(&self) -> Cells {
+        let (width, height) = (self.width().max(0), self.height().max(0));
+        Cells {
+            min: self.min,
+            width,
+            front: 0,
+            back: width as usize * height as usize,
+        }
+    }
+
+    /// Iterate over each row of this rect, as a rect with a height of 1
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, vec2, Rect};
+    /// let rect = Rect::from_min_size(pos2(1, 1), vec2(3, 2));
+    /// let rows: Vec<_> = rect.rows().collect();
+    /// assert_eq!(rows, [
+    ///     Rect::from_min_size(pos2(1, 1), vec2(3, 1)),
+    ///     Rect::from_min_size(pos2(1, 2), vec2(3, 1)),
+    /// ]);
+    /// ```
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Self> + DoubleEndedIterator {
+        let (left, right) = (self.left(), self.right());
+        (self.top()..self.bottom())
+            .map(move |y| Self::from_min_max(pos2(left, y), pos2(right, y + 1)))
+    }
+
+    /// Iterate over each column of this rect, as a rect with a width of 1
+    ///
+    /// ```rust
+    /// # use too::math::{pos2, vec2, Rect};
+    /// let rect = Rect::from_min_size(pos2(1, 1), vec2(2, 3));
+    /// let columns: Vec<_> = rect.columns().collect();
+    /// assert_eq!(columns, [
+    ///     Rect::from_min_size(pos2(1, 1), vec2(1, 3)),
+    ///     Rect::from_min_size(pos2(2, 1), vec2(1, 3)),
+    /// ]);
+    /// ```
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = Self> + DoubleEndedIterator {
+        let (top, bottom) = (self.top(), self.bottom());
+        (self.left()..self.right())
+            .map(move |x| Self::from_min_max(pos2(x, top), pos2(x + 1, bottom)))
+    }
+
     pub fn corners(&self) -> [Pos2; 4] {
         [
             self.left_top(),
@@ -266,6 +463,57 @@ impl Rect {
     }
 }
 
+/// An iterator over the positions in a [`Rect`], see [`Rect::cells`]
+#[derive(Copy, Clone, Debug)]
+pub struct Cells {
+    min: Pos2,
+    width: i32,
+    front: usize,
+    back: usize,
+}
+
+impl Cells {
+    const fn pos(&self, index: usize) -> Pos2 {
+        let width = self.width as usize;
+        pos2(
+            self.min.x + (index % width) as i32,
+            self.min.y + (index / width) as i32,
+        )
+    }
+}
+
+impl Iterator for Cells {
+    type Item = Pos2;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let pos = self.pos(self.front);
+        self.front += 1;
+        Some(pos)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Cells {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.pos(self.back))
+    }
+}
+
+impl ExactSizeIterator for Cells {}
+
+impl std::iter::FusedIterator for Cells {}
+
 pub const fn rect(size: Vec2) -> Rect {
     Rect {
         min: Pos2::ZERO,
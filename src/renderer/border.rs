@@ -39,7 +39,151 @@ impl std::fmt::Debug for Border {
     }
 }
 
+/// The sides of a [`Border`]
+///
+/// These can be combined with `|`, e.g. `Sides::BOTTOM | Sides::LEFT`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sides(pub u8);
+
+impl Sides {
+    pub const NONE: Self = Self(0);
+    pub const LEFT: Self = Self(1 << 0);
+    pub const TOP: Self = Self(1 << 1);
+    pub const RIGHT: Self = Self(1 << 2);
+    pub const BOTTOM: Self = Self(1 << 3);
+    pub const ALL: Self = Self(Self::LEFT.0 | Self::TOP.0 | Self::RIGHT.0 | Self::BOTTOM.0);
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for Sides {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for Sides {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for Sides {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs
+    }
+}
+
 impl Border {
+    /// Only keep these sides of the border
+    ///
+    /// A corner between two kept sides is kept, a corner next to only one kept side continues that side's line, otherwise it is removed
+    ///
+    /// ```rust
+    /// # use too::renderer::{Border, Sides};
+    /// let out = too::view::test::render_to_string(|ui| {
+    ///     ui.border(Border::ROUNDED.sides(Sides::BOTTOM | Sides::LEFT), |ui| ui.label("hi"));
+    /// });
+    /// assert_eq!(out, "│hi\n╰──");
+    ///
+    /// // a separator
+    /// let out = too::view::test::render_to_string(|ui| {
+    ///     ui.border(Border::THIN.sides(Sides::BOTTOM), |ui| ui.label("hi"));
+    /// });
+    /// assert_eq!(out, "hi\n──");
+    /// ```
+    pub const fn sides(self, sides: Sides) -> Self {
+        const fn corner(corner: char, a: Option<char>, b: Option<char>) -> char {
+            match (a, b) {
+                (Some(..), Some(..)) => corner,
+                (Some(side), None) | (None, Some(side)) => side,
+                (None, None) => ' ',
+            }
+        }
+
+        const fn side(sides: Sides, side: Sides, ch: char) -> Option<char> {
+            if sides.contains(side) {
+                Some(ch)
+            } else {
+                None
+            }
+        }
+
+        const fn edge(side: Option<char>) -> char {
+            match side {
+                Some(ch) => ch,
+                None => ' ',
+            }
+        }
+
+        let left = side(sides, Sides::LEFT, self.left);
+        let top = side(sides, Sides::TOP, self.top);
+        let right = side(sides, Sides::RIGHT, self.right);
+        let bottom = side(sides, Sides::BOTTOM, self.bottom);
+
+        Self {
+            left_top: corner(self.left_top, top, left),
+            top: edge(top),
+            right_top: corner(self.right_top, top, right),
+            right: edge(right),
+            right_bottom: corner(self.right_bottom, bottom, right),
+            bottom: edge(bottom),
+            left_bottom: corner(self.left_bottom, bottom, left),
+            left: edge(left),
+        }
+    }
+
+    /// Use this character for the left-top corner
+    pub const fn left_top(mut self, left_top: char) -> Self {
+        self.left_top = left_top;
+        self
+    }
+
+    /// Use this character for the top edge
+    pub const fn top(mut self, top: char) -> Self {
+        self.top = top;
+        self
+    }
+
+    /// Use this character for the right-top corner
+    pub const fn right_top(mut self, right_top: char) -> Self {
+        self.right_top = right_top;
+        self
+    }
+
+    /// Use this character for the right edge
+    pub const fn right(mut self, right: char) -> Self {
+        self.right = right;
+        self
+    }
+
+    /// Use this character for the right-bottom corner
+    pub const fn right_bottom(mut self, right_bottom: char) -> Self {
+        self.right_bottom = right_bottom;
+        self
+    }
+
+    /// Use this character for the bottom edge
+    pub const fn bottom(mut self, bottom: char) -> Self {
+        self.bottom = bottom;
+        self
+    }
+
+    /// Use this character for the left-bottom corner
+    pub const fn left_bottom(mut self, left_bottom: char) -> Self {
+        self.left_bottom = left_bottom;
+        self
+    }
+
+    /// Use this character for the left edge
+    pub const fn left(mut self, left: char) -> Self {
+        self.left = left;
+        self
+    }
+
     pub const fn without_top(self) -> Self {
         Self {
             left_top: self.left,
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use compact_str::{CompactString, ToCompactString};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
@@ -39,6 +41,15 @@ impl Cell {
         }
     }
 
+    /// Set the underline style (and color) of this cell
+    pub fn set_underline(&mut self, underline: impl Into<Option<Underline>>) {
+        match self {
+            Self::Grapheme(grapheme) => grapheme.underline = underline.into(),
+            Self::Pixel(pixel) => pixel.underline = underline.into(),
+            _ => {}
+        }
+    }
+
     pub fn patch_fg(&mut self, fg: impl Fn(Rgba) -> Rgba) {
         let Color::Set(rgba) = self.fg() else {
             return;
@@ -46,11 +57,14 @@ impl Cell {
         self.set_fg(fg(rgba))
     }
 
+    /// Patch the background color of this cell
+    ///
+    /// If the new color is translucent, it is composited over the old one
     pub fn patch_bg(&mut self, bg: impl Fn(Rgba) -> Rgba) {
         let Color::Set(rgba) = self.bg() else {
             return;
         };
-        self.set_bg(bg(rgba))
+        self.set_bg(bg(rgba).blend_over(rgba))
     }
 
     pub fn patch_attr(&mut self, attr: impl Fn(Attribute) -> Attribute) {
@@ -83,26 +97,53 @@ impl Cell {
             _ => unreachable!(),
         }
     }
+
+    /// Gets the underline style currently set for this cell
+    pub const fn underline(&self) -> Option<Underline> {
+        match self {
+            Self::Grapheme(grapheme) => grapheme.underline,
+            Self::Pixel(pixel) => pixel.underline,
+            _ => None,
+        }
+    }
+
+    /// Gets the url of the hyperlink this cell is a part of
+    pub fn link(&self) -> Option<&str> {
+        match self {
+            Self::Grapheme(grapheme) => grapheme.link.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 impl Cell {
     pub(crate) fn is_same(&self, other: &Self) -> bool {
-        fn check(fg: Color, bg: Color) -> bool {
-            matches!(bg, Color::Reuse) || matches!(fg, Color::Reuse)
+        // a reused color keeps whatever was there, but any other change to the style has to be drawn
+        fn same_style(left: &Cell, right: &Cell) -> bool {
+            let same = |left, right| matches!(right, Color::Reuse) || left == right;
+            same(left.fg(), right.fg())
+                && same(left.bg(), right.bg())
+                && left.attribute() == right.attribute()
+                && left.underline() == right.underline()
         }
 
         match (self, other) {
             (Cell::Grapheme(left), Cell::Grapheme(right)) => {
-                (left == right) || (left.cluster == right.cluster && check(right.bg, right.fg))
+                (left == right)
+                    || (left.cluster == right.cluster
+                        && left.link == right.link
+                        && same_style(self, other))
             }
             (Cell::Grapheme(left), Cell::Pixel(right)) => {
-                compare(&left.cluster, right.char) && check(right.bg, right.fg)
+                compare(&left.cluster, right.char) && left.link.is_none() && same_style(self, other)
             }
             (Cell::Pixel(left), Cell::Grapheme(right)) => {
-                compare(&right.cluster, left.char) && check(right.bg, right.fg)
+                compare(&right.cluster, left.char)
+                    && right.link.is_none()
+                    && same_style(self, other)
             }
             (Cell::Pixel(left), Cell::Pixel(right)) => {
-                (left == right) || ((left.char == right.char) && check(right.bg, right.fg))
+                (left == right) || ((left.char == right.char) && same_style(self, other))
             }
             (Cell::Empty, Cell::Grapheme(..) | Cell::Pixel(..)) => false,
             (Cell::Empty, Cell::Continuation | Cell::Empty) => true,
@@ -119,7 +160,7 @@ impl Cell {
 
         fn merge_bg(left_bg: &mut Color, right_bg: Color) {
             match (right_bg, &left_bg) {
-                (Color::Set(a), Color::Set(b)) => *left_bg = Color::Set(a.blend_alpha(*b)),
+                (Color::Set(a), Color::Set(b)) => *left_bg = Color::Set(a.blend_over(*b)),
                 (Color::Reset | Color::Set(..), ..) => *left_bg = right_bg,
                 _ => {}
             }
@@ -130,6 +171,8 @@ impl Cell {
                 merge_fg(&mut left.fg, right.fg);
                 merge_bg(&mut left.bg, right.bg);
                 left.attribute = right.attribute;
+                left.underline = right.underline;
+                left.link = right.link.take();
                 left.cluster = std::mem::take(&mut right.cluster);
             }
             (Cell::Grapheme(ref mut left), Cell::Pixel(right)) => {
@@ -140,6 +183,7 @@ impl Cell {
                     fg: left.fg,
                     bg: left.bg,
                     attribute: right.attribute,
+                    underline: right.underline,
                 };
                 *this = Cell::Pixel(pixel)
             }
@@ -151,6 +195,8 @@ impl Cell {
                     fg: left.fg,
                     bg: left.bg,
                     attribute: right.attribute,
+                    underline: right.underline,
+                    link: right.link.take(),
                 };
                 *this = Cell::Grapheme(grapheme)
             }
@@ -159,6 +205,7 @@ impl Cell {
                 merge_fg(&mut left.fg, right.fg);
                 merge_bg(&mut left.bg, right.bg);
                 left.attribute = right.attribute;
+                left.underline = right.underline;
                 left.char = right.char;
             }
 
@@ -175,6 +222,14 @@ impl Cell {
         }
     }
 
+    /// A space with the same background as this cell
+    pub(crate) fn blank(&self) -> Self {
+        match self {
+            Self::Grapheme(..) | Self::Pixel(..) => Self::Pixel(Pixel::new(' ').bg(self.bg())),
+            Self::Empty | Self::Continuation => Self::Empty,
+        }
+    }
+
     pub(crate) const fn is_continuation(&self) -> bool {
         matches!(self, Self::Continuation)
     }
@@ -231,6 +286,7 @@ pub struct Pixel {
     pub(crate) fg: Color,
     pub(crate) bg: Color,
     attribute: Attribute,
+    underline: Option<Underline>,
 }
 
 impl Default for Pixel {
@@ -256,6 +312,7 @@ impl Pixel {
         fg: Color::Reset,
         bg: Color::Reset,
         attribute: Attribute::RESET,
+        underline: None,
     };
 
     pub const fn new(char: char) -> Self {
@@ -264,6 +321,7 @@ impl Pixel {
             fg: Color::Reset,
             bg: Color::Reuse,
             attribute: Attribute::RESET,
+            underline: None,
         }
     }
 
@@ -286,6 +344,11 @@ impl Pixel {
         self.attribute = attribute;
         self
     }
+
+    pub fn underline(mut self, underline: impl Into<Underline>) -> Self {
+        self.underline = Some(underline.into());
+        self
+    }
 }
 
 impl From<char> for Pixel {
@@ -300,6 +363,8 @@ pub struct Grapheme {
     pub(crate) fg: Color,
     pub(crate) bg: Color,
     attribute: Attribute,
+    underline: Option<Underline>,
+    pub(crate) link: Option<Arc<str>>,
 }
 
 impl Grapheme {
@@ -309,6 +374,8 @@ impl Grapheme {
             fg: Color::Reset,
             bg: Color::Reuse,
             attribute: Attribute::RESET,
+            underline: None,
+            link: None,
         }
     }
 
@@ -318,6 +385,8 @@ impl Grapheme {
             fg: Color::Reset,
             bg: Color::Reuse,
             attribute: Attribute::RESET,
+            underline: None,
+            link: None,
         }
     }
 
@@ -335,6 +404,19 @@ impl Grapheme {
         self.attribute = attribute;
         self
     }
+
+    pub fn underline(mut self, underline: impl Into<Underline>) -> Self {
+        self.underline = Some(underline.into());
+        self
+    }
+
+    /// Make this grapheme part of a hyperlink to this url
+    ///
+    /// Consecutive graphemes with the same url are rendered as a single link
+    pub fn link(mut self, url: impl Into<Arc<str>>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
 }
 
 impl<T: ToCompactString> From<T> for Grapheme {
@@ -343,23 +425,79 @@ impl<T: ToCompactString> From<T> for Grapheme {
     }
 }
 
-// #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
-// pub struct Underline(pub(crate) u8);
-// impl Underline {
-//     // <ESC>[4:0m  # no underline
-//     // <ESC>[4:1m  # straight underline
-//     // <ESC>[4:2m  # double underline
-//     // <ESC>[4:3m  # curly underline
-//     // <ESC>[4:4m  # dotted underline
-//     // <ESC>[4:5m  # dashed underline
+/// The style of an [`Underline`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum UnderlineStyle {
+    #[default]
+    Straight,
+    Double,
+    Curly,
+    Dotted,
+    Dashed,
+}
 
-//     pub const NONE: Self = Self(0);
-//     pub const STRAIGHT: Self = Self(1 << 0);
-//     pub const DOUBLE: Self = Self(1 << 1);
-//     pub const CURLY: Self = Self(1 << 2);
-//     pub const DOTTED: Self = Self(1 << 3);
-//     pub const DASHED: Self = Self(1 << 4);
-// }
+impl UnderlineStyle {
+    /// The parameter used by `SGR 4:N` for this style
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::Straight => 1,
+            Self::Double => 2,
+            Self::Curly => 3,
+            Self::Dotted => 4,
+            Self::Dashed => 5,
+        }
+    }
+}
+
+/// An extended underline for a [`Pixel`] or [`Grapheme`], with a style and an optional color
+///
+/// Terminals that don't understand these will generally just draw a straight underline
+///
+/// If no color is set, the terminal uses the foreground color of the cell
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2}, renderer::{Attribute, Pixel, Rgba, Surface, TermRenderer, Underline, UnderlineStyle}};
+/// let render = |pixel: Pixel| {
+///     let mut surface = Surface::new(vec2(1, 1));
+///     surface.set(pos2(0, 0), pixel);
+///     let mut out = Vec::new();
+///     surface.render(&mut TermRenderer::new(&mut out)).unwrap();
+///     String::from_utf8(out).unwrap()
+/// };
+///
+/// let squiggle = Underline::new(UnderlineStyle::Curly).color(Rgba::hex("#F00"));
+/// let out = render(Pixel::new('a').underline(squiggle));
+/// assert!(out.contains("\x1b[4:3m"));
+/// assert!(out.contains("\x1b[58:2::255:0:0m"));
+///
+/// // a plain underline is unaffected
+/// let out = render(Pixel::new('a').attribute(Attribute::UNDERLINE));
+/// assert!(out.contains("\x1b[4m"));
+/// assert!(!out.contains("\x1b[4:"));
+/// assert!(!out.contains("\x1b[58:"));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Underline {
+    pub style: UnderlineStyle,
+    pub color: Option<Rgba>,
+}
+
+impl Underline {
+    pub const fn new(style: UnderlineStyle) -> Self {
+        Self { style, color: None }
+    }
+
+    pub fn color(mut self, color: impl Into<Rgba>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl From<UnderlineStyle> for Underline {
+    fn from(style: UnderlineStyle) -> Self {
+        Self::new(style)
+    }
+}
 
 /// Attributes for a [`Pixel`] like _italic_ or _bold_
 #[derive(Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
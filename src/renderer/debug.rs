@@ -4,7 +4,7 @@ use unicode_width::UnicodeWidthStr;
 use super::Renderer;
 use crate::{
     math::Pos2,
-    renderer::{Attribute, Rgba},
+    renderer::{Attribute, Rgba, Underline},
 };
 
 /// A renderer that explains the actions a render phase would take
@@ -95,6 +95,16 @@ impl Renderer for DebugRenderer {
         Ok(())
     }
 
+    fn set_underline(&mut self, underline: Underline) -> std::io::Result<()> {
+        self.next_entry();
+        _ = writeln!(
+            &mut self.out,
+            "  set_underline: {:?} {:?}",
+            underline.style, underline.color
+        );
+        Ok(())
+    }
+
     fn reset_fg(&mut self) -> std::io::Result<()> {
         self.next_entry();
         _ = writeln!(&mut self.out, "  reset_fg");
@@ -112,4 +122,10 @@ impl Renderer for DebugRenderer {
         _ = writeln!(&mut self.out, "  reset_attr");
         Ok(())
     }
+
+    fn reset_underline(&mut self) -> std::io::Result<()> {
+        self.next_entry();
+        _ = writeln!(&mut self.out, "  reset_underline");
+        Ok(())
+    }
 }
@@ -33,6 +33,86 @@ impl Gradient {
     pub fn as_rgba(&self, time: f32) -> Rgba {
         Rgba::gradient(time, self.offset, self.amp, self.freq, self.phase)
     }
+
+    /// Create a gradient from colors at positions in the range of `0.0..=1.0`
+    ///
+    /// See [`GradientStops`]
+    pub fn stops(stops: &[(f32, Rgba)]) -> GradientStops {
+        GradientStops::new(stops)
+    }
+}
+
+/// A gradient made of colors at positions, with the colors between them interpolated
+///
+/// The positions are clamped to `0.0..=1.0` and sorted. Stops that aren't at a finite position are dropped.
+///
+/// ```rust
+/// # use too::renderer::{Gradient, Rgba};
+/// let (red, yellow, green) = (Rgba::hex("#F00"), Rgba::hex("#FF0"), Rgba::hex("#0F0"));
+/// let heat = Gradient::stops(&[(1.0, green), (0.0, red), (0.5, yellow)]);
+///
+/// assert_eq!(heat.sample(0.0), red);
+/// assert_eq!(heat.sample(0.25), red.lerp(yellow, 0.5));
+/// assert_eq!(heat.sample(0.5), yellow);
+/// assert_eq!(heat.sample(1.0), green);
+///
+/// // `t` is clamped
+/// assert_eq!(heat.sample(-1.0), red);
+/// assert_eq!(heat.sample(2.0), green);
+///
+/// // a single stop is the same color everywhere
+/// let single = Gradient::stops(&[(0.3, yellow)]);
+/// assert_eq!(single.sample(0.0), yellow);
+/// assert_eq!(single.sample(1.0), yellow);
+///
+/// // and without any stops, there is no color
+/// assert_eq!(Gradient::stops(&[]).sample(0.5), Rgba::TRANSPARENT);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GradientStops {
+    stops: Vec<(f32, Rgba)>,
+}
+
+impl GradientStops {
+    pub fn new(stops: &[(f32, Rgba)]) -> Self {
+        let mut stops: Vec<_> = stops
+            .iter()
+            .filter(|(pos, _)| pos.is_finite())
+            .map(|&(pos, color)| (pos.clamp(0.0, 1.0), color))
+            .collect();
+        // this is stable, so stops at the same position keep their order
+        stops.sort_by(|(l, _), (r, _)| l.total_cmp(r));
+        Self { stops }
+    }
+
+    /// The stops, sorted by their position
+    pub fn as_slice(&self) -> &[(f32, Rgba)] {
+        &self.stops
+    }
+
+    /// Sample the color at `t`, in the range of `0.0..=1.0`
+    ///
+    /// Before the first stop, and after the last one, this is the color of that stop
+    pub fn sample(&self, t: f32) -> Rgba {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Rgba::TRANSPARENT,
+        };
+
+        if t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+
+        // the first stop past `t`, there is always one before it because of the checks above
+        let index = self.stops.partition_point(|&(pos, _)| pos <= t);
+        let ((start, from), (end, to)) = (self.stops[index - 1], self.stops[index]);
+        from.lerp(to, (t - start) / (end - start))
+    }
 }
 
 impl Gradient {
@@ -0,0 +1,314 @@
+use std::fmt::Write as _;
+
+use unicode_width::UnicodeWidthStr as _;
+
+use super::Renderer;
+use crate::{
+    math::{pos2, Pos2},
+    renderer::{Attribute, Rgba, Underline, UnderlineStyle},
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Style {
+    fg: Option<Rgba>,
+    bg: Option<Rgba>,
+    attr: Attribute,
+    underline: Option<Underline>,
+    link: Option<String>,
+}
+
+impl Style {
+    fn css(&self) -> String {
+        let (mut fg, mut bg) = (self.fg.map(Self::color), self.bg.map(Self::color));
+        if self.attr.is_reverse() {
+            // without a color, swap the page's colors
+            let swapped = (
+                bg.take().unwrap_or_else(|| String::from("Canvas")),
+                fg.take().unwrap_or_else(|| String::from("CanvasText")),
+            );
+            (fg, bg) = (Some(swapped.0), Some(swapped.1));
+        }
+
+        let mut css = String::new();
+        if let Some(fg) = fg {
+            _ = write!(css, "color:{fg};");
+        }
+        if let Some(bg) = bg {
+            _ = write!(css, "background-color:{bg};");
+        }
+        if self.attr.is_bold() {
+            css.push_str("font-weight:bold;");
+        }
+        if self.attr.is_faint() {
+            css.push_str("opacity:0.5;");
+        }
+        if self.attr.is_italic() {
+            css.push_str("font-style:italic;");
+        }
+
+        let underline = self.underline.is_some() || self.attr.is_underline();
+        let lines = match (underline, self.attr.is_strikeout()) {
+            (true, true) => Some("underline line-through"),
+            (true, false) => Some("underline"),
+            (false, true) => Some("line-through"),
+            (false, false) => None,
+        };
+        if let Some(lines) = lines {
+            _ = write!(css, "text-decoration-line:{lines};");
+        }
+
+        if let Some(underline) = self.underline {
+            let style = match underline.style {
+                UnderlineStyle::Straight => "solid",
+                UnderlineStyle::Double => "double",
+                UnderlineStyle::Curly => "wavy",
+                UnderlineStyle::Dotted => "dotted",
+                UnderlineStyle::Dashed => "dashed",
+            };
+            _ = write!(css, "text-decoration-style:{style};");
+            if let Some(color) = underline.color {
+                _ = write!(css, "text-decoration-color:{};", Self::color(color));
+            }
+        }
+        css
+    }
+
+    fn color(Rgba(r, g, b, ..): Rgba) -> String {
+        format!("#{r:02X}{g:02X}{b:02X}")
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HtmlCell {
+    // empty for the cells a wide grapheme spills into
+    text: String,
+    style: Style,
+}
+
+impl Default for HtmlCell {
+    fn default() -> Self {
+        Self {
+            text: String::from(" "),
+            style: Style::default(),
+        }
+    }
+}
+
+/// A renderer that produces a `<pre>` of styled HTML
+///
+/// Colors, attributes, underlines and hyperlinks are kept as inline styles on each run of cells.
+///
+/// [`Surface::render`](crate::renderer::Surface::render) only produces the cells that changed since it was last rendered,
+/// so render a surface that hasn't been rendered before to get all of it.
+/// Empty cells aren't rendered, so the output is only as wide (and tall) as the last cell that was written, and any gaps are filled with spaces.
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2}, renderer::{Attribute, HtmlRenderer, Pixel, Surface}};
+/// let mut surface = Surface::new(vec2(4, 1));
+/// surface.set(pos2(0, 0), Pixel::new('<').fg("#F00").attribute(Attribute::BOLD | Attribute::ITALIC));
+/// surface.set(pos2(1, 0), Pixel::new('b').fg("#F00").attribute(Attribute::BOLD | Attribute::ITALIC));
+/// surface.set(pos2(2, 0), Pixel::new('c').attribute(Attribute::UNDERLINE));
+///
+/// let mut html = HtmlRenderer::new();
+/// surface.render(&mut html).unwrap();
+/// assert_eq!(
+///     html.to_string(),
+///     "<pre>\
+///     <span style=\"color:#FF0000;font-weight:bold;font-style:italic;\">&lt;b</span>\
+///     <span style=\"text-decoration-line:underline;\">c</span>\
+///     </pre>"
+/// );
+/// ```
+#[derive(Default)]
+pub struct HtmlRenderer {
+    rows: Vec<Vec<HtmlCell>>,
+    cursor: Pos2,
+    style: Style,
+}
+
+impl HtmlRenderer {
+    pub const fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            cursor: Pos2::ZERO,
+            style: Style {
+                fg: None,
+                bg: None,
+                attr: Attribute::RESET,
+                underline: None,
+                link: None,
+            },
+        }
+    }
+
+    fn cell(&mut self, pos: Pos2) -> &mut HtmlCell {
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        if self.rows.len() <= y {
+            self.rows.resize_with(y + 1, Vec::new);
+        }
+        let row = &mut self.rows[y];
+        if row.len() <= x {
+            row.resize_with(x + 1, HtmlCell::default);
+        }
+        &mut row[x]
+    }
+
+    fn escape(out: &mut String, text: &str) {
+        for ch in text.chars() {
+            match ch {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                ch => out.push(ch),
+            }
+        }
+    }
+
+    fn write_run(out: &mut String, style: &Style, text: &str) {
+        if let Some(link) = &style.link {
+            out.push_str("<a href=\"");
+            Self::escape(out, link);
+            out.push_str("\">");
+        }
+
+        let css = style.css();
+        if css.is_empty() {
+            Self::escape(out, text);
+        } else {
+            _ = write!(out, "<span style=\"{css}\">");
+            Self::escape(out, text);
+            out.push_str("</span>");
+        }
+
+        if style.link.is_some() {
+            out.push_str("</a>");
+        }
+    }
+}
+
+impl std::fmt::Display for HtmlRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut out = String::from("<pre>");
+        let blank = HtmlCell::default();
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+
+            let mut run: Option<(&Style, String)> = None;
+            for x in 0..width {
+                let cell = row.get(x).unwrap_or(&blank);
+                match &mut run {
+                    Some((style, text)) if **style == cell.style => text.push_str(&cell.text),
+                    _ => {
+                        if let Some((style, text)) = run.take() {
+                            Self::write_run(&mut out, style, &text);
+                        }
+                        run = Some((&cell.style, cell.text.clone()));
+                    }
+                }
+            }
+            if let Some((style, text)) = run {
+                Self::write_run(&mut out, style, &text);
+            }
+        }
+        out.push_str("</pre>");
+        f.write_str(&out)
+    }
+}
+
+impl Renderer for HtmlRenderer {
+    fn begin(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn end(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn move_to(&mut self, pos: Pos2) -> std::io::Result<()> {
+        self.cursor = pos;
+        Ok(())
+    }
+
+    fn write_str(&mut self, data: &str) -> std::io::Result<()> {
+        use unicode_segmentation::UnicodeSegmentation as _;
+
+        for cluster in data.graphemes(true) {
+            let pos = self.cursor;
+            let style = self.style.clone();
+            *self.cell(pos) = HtmlCell {
+                text: cluster.to_string(),
+                style: style.clone(),
+            };
+
+            let width = cluster.width().max(1) as i32;
+            for x in 1..width {
+                *self.cell(pos + pos2(x, 0)) = HtmlCell {
+                    text: String::new(),
+                    style: style.clone(),
+                };
+            }
+            self.cursor.x += width;
+        }
+        Ok(())
+    }
+
+    fn set_fg(&mut self, rgb: Rgba) -> std::io::Result<()> {
+        self.style.fg = Some(rgb);
+        Ok(())
+    }
+
+    fn set_bg(&mut self, rgb: Rgba) -> std::io::Result<()> {
+        self.style.bg = Some(rgb);
+        Ok(())
+    }
+
+    fn set_attr(&mut self, attr: Attribute) -> std::io::Result<()> {
+        self.style.attr = attr;
+        Ok(())
+    }
+
+    fn reset_fg(&mut self) -> std::io::Result<()> {
+        self.style.fg = None;
+        Ok(())
+    }
+
+    fn reset_bg(&mut self) -> std::io::Result<()> {
+        self.style.bg = None;
+        Ok(())
+    }
+
+    // like a terminal, this resets the colors too
+    fn reset_attr(&mut self) -> std::io::Result<()> {
+        let link = self.style.link.take();
+        self.style = Style {
+            link,
+            ..Style::default()
+        };
+        Ok(())
+    }
+
+    fn set_underline(&mut self, underline: Underline) -> std::io::Result<()> {
+        self.style.underline = Some(underline);
+        Ok(())
+    }
+
+    fn reset_underline(&mut self) -> std::io::Result<()> {
+        self.style.underline = None;
+        Ok(())
+    }
+
+    fn set_hyperlink(&mut self, url: &str) -> std::io::Result<()> {
+        self.style.link = Some(url.to_string());
+        Ok(())
+    }
+
+    fn reset_hyperlink(&mut self) -> std::io::Result<()> {
+        self.style.link = None;
+        Ok(())
+    }
+}
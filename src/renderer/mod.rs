@@ -3,19 +3,22 @@ mod surface;
 pub use surface::Surface;
 
 mod cell;
-pub use cell::{Attribute, Cell, Color, Grapheme, Pixel};
+pub use cell::{Attribute, Cell, Color, Grapheme, Pixel, Underline, UnderlineStyle};
 
 mod rgba;
 pub use rgba::Rgba;
 
 mod gradient;
-pub use gradient::Gradient;
+pub use gradient::{Gradient, GradientStops};
 
 mod border;
-pub use border::Border;
+pub use border::{Border, Sides};
 
 mod rasterizer;
-pub use rasterizer::{Rasterizer, Shape, TextShape};
+pub use rasterizer::{ControlChars, Rasterizer, Shape, TextShape};
+
+pub mod shapes;
+pub use shapes::{BrailleCanvas, GradientFill, HalfBlockCanvas, LineStyle, Polyline};
 
 use crate::math;
 
@@ -32,11 +35,46 @@ pub trait Renderer {
     fn reset_bg(&mut self) -> std::io::Result<()>;
     fn reset_attr(&mut self) -> std::io::Result<()>;
 
+    fn set_underline(&mut self, underline: Underline) -> std::io::Result<()> {
+        _ = underline;
+        Ok(())
+    }
+
+    fn reset_underline(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Start a hyperlink to this url, anything written until [`reset_hyperlink`](Self::reset_hyperlink) is a part of it
+    fn set_hyperlink(&mut self, url: &str) -> std::io::Result<()> {
+        _ = url;
+        Ok(())
+    }
+
+    /// End the current hyperlink
+    fn reset_hyperlink(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Write some text as a hyperlink to this url
+    ///
+    /// Renderers that don't support hyperlinks just write the text
+    fn write_hyperlink(&mut self, url: &str, text: &str) -> std::io::Result<()> {
+        self.set_hyperlink(url)?;
+        self.write_str(text)?;
+        self.reset_hyperlink()
+    }
+
     fn set_title(&mut self, title: &str) -> std::io::Result<()> {
         _ = title;
         Ok(())
     }
 
+    /// Put this text on the system clipboard
+    fn set_clipboard(&mut self, data: &str) -> std::io::Result<()> {
+        _ = data;
+        Ok(())
+    }
+
     fn switch_to_alt_screen(&mut self) -> std::io::Result<()> {
         Ok(())
     }
@@ -55,4 +93,7 @@ pub use debug::DebugRenderer;
 mod dummy;
 pub use dummy::DummyRenderer;
 
+mod html;
+pub use html::HtmlRenderer;
+
 // TODO TestRenderer
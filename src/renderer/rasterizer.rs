@@ -1,5 +1,7 @@
 use std::{borrow::Cow, ops::RangeInclusive};
 
+use unicode_width::{UnicodeWidthChar as _, UnicodeWidthStr as _};
+
 use crate::{
     layout::Axis,
     math::{pos2, Pos2, Rect},
@@ -145,6 +147,18 @@ pub trait Rasterizer {
     fn get_mut(&mut self, pos: Pos2) -> Option<&mut Cell>;
 }
 
+/// What to do with control characters (like `\r` or `\x1b`) in a [`TextShape`]
+///
+/// Tabs are always expanded to spaces
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ControlChars {
+    /// Replace them with their visible symbol, e.g. `\r` becomes `␍`
+    #[default]
+    Replace,
+    /// Remove them entirely
+    Strip,
+}
+
 /// A shape for drawing text
 #[derive(Clone, Debug, PartialEq)]
 pub struct TextShape<'a> {
@@ -152,6 +166,9 @@ pub struct TextShape<'a> {
     pub(crate) fg: Color,
     pub(crate) bg: Color,
     pub(crate) attribute: Option<Attribute>,
+    pub(crate) tab_width: usize,
+    pub(crate) control_chars: ControlChars,
+    pub(crate) link: Option<Cow<'a, str>>,
 }
 
 impl<'a> From<&'a str> for TextShape<'a> {
@@ -178,6 +195,99 @@ impl<'a> TextShape<'a> {
             fg: Color::Reset,
             bg: Color::Reuse,
             attribute: None,
+            tab_width: Self::DEFAULT_TAB_WIDTH,
+            control_chars: ControlChars::Replace,
+            link: None,
+        }
+    }
+
+    /// The default number of columns a tab stops at
+    pub const DEFAULT_TAB_WIDTH: usize = 4;
+
+    /// Expand tabs to the next multiple of this many columns
+    ///
+    /// A width of `0` removes tabs
+    pub const fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// What to do with control characters in the label
+    pub const fn control_chars(mut self, control_chars: ControlChars) -> Self {
+        self.control_chars = control_chars;
+        self
+    }
+
+    /// Make this text a hyperlink to this url
+    ///
+    /// Terminals that don't support hyperlinks just show the text
+    pub fn link(mut self, url: &'a str) -> Self {
+        self.link = Some(Cow::Borrowed(url));
+        self
+    }
+
+    /// The label with its tabs expanded and its control characters handled
+    ///
+    /// ```rust
+    /// # use too::renderer::{ControlChars, TextShape};
+    /// let shape = TextShape::new("a\tb").tab_width(4);
+    /// assert_eq!(shape.sanitized(), "a   b");
+    /// assert_eq!(shape.width(), 5);
+    ///
+    /// // tabs stop at columns, rather than being a fixed number of spaces
+    /// assert_eq!(TextShape::new("abcde\tf").sanitized(), "abcde   f");
+    ///
+    /// let shape = TextShape::new("a\r\x1b[0mb\x00");
+    /// assert_eq!(shape.sanitized(), "a␍␛[0mb␀");
+    /// assert_eq!(shape.width(), 8);
+    ///
+    /// let shape = shape.control_chars(ControlChars::Strip);
+    /// assert_eq!(shape.sanitized(), "a[0mb");
+    /// assert_eq!(shape.width(), 5);
+    /// ```
+    pub fn sanitized(&self) -> Cow<'_, str> {
+        if !self.label.chars().any(char::is_control) {
+            return Cow::Borrowed(&self.label);
+        }
+
+        let mut out = String::with_capacity(self.label.len());
+        let mut column = 0;
+        for ch in self.label.chars() {
+            match ch {
+                '\t' if self.tab_width > 0 => {
+                    let spaces = self.tab_width - column % self.tab_width;
+                    out.extend(std::iter::repeat_n(' ', spaces));
+                    column += spaces;
+                }
+                '\t' => {}
+                ch if ch.is_control() => {
+                    if let (ControlChars::Replace, Some(symbol)) =
+                        (self.control_chars, Self::control_symbol(ch))
+                    {
+                        out.push(symbol);
+                        column += 1;
+                    }
+                }
+                ch => {
+                    out.push(ch);
+                    column += ch.width().unwrap_or(0);
+                }
+            }
+        }
+        Cow::Owned(out)
+    }
+
+    /// The display width of the label, once its been sanitized
+    pub fn width(&self) -> usize {
+        self.sanitized().width()
+    }
+
+    // only C0 controls and DEL have symbols
+    fn control_symbol(ch: char) -> Option<char> {
+        match ch as u32 {
+            c @ 0x00..=0x1F => char::from_u32(0x2400 + c),
+            0x7F => Some('\u{2421}'),
+            _ => None,
         }
     }
 
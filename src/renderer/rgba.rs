@@ -107,6 +107,53 @@ impl Rgba {
         Self(pack(rh, rl), pack(gh, gl), pack(bh, bl), pack(ah, al))
     }
 
+    /// The relative luminance of this color, from `0.0` (black) to `1.0` (white)
+    ///
+    /// This is the [WCAG](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance) definition, which linearizes the sRGB channels first. The alpha is ignored
+    pub fn relative_luminance(&self) -> f32 {
+        let LinearRgba(r, g, b, _) = self.to_linear();
+        0.0722f32.mul_add(b, 0.2126f32.mul_add(r, 0.7152 * g))
+    }
+
+    /// The [WCAG](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) contrast ratio between this color and another one
+    ///
+    /// This goes from `1.0` (the same luminance) to `21.0` (black on white), and is the same either way around
+    pub fn contrast_ratio(&self, other: Self) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Pick the color from `candidates` with the best contrast against this one, e.g. for text on this background
+    ///
+    /// Ties go to the earlier candidate. Without any candidates, this picks black or white
+    ///
+    /// ```rust
+    /// # use too::renderer::Rgba;
+    /// let (black, white) = (Rgba::hex("#000"), Rgba::hex("#FFF"));
+    /// assert_eq!(Rgba::hex("#FF0").best_contrast(&[white, black]), black);
+    /// assert_eq!(Rgba::hex("#00F").best_contrast(&[white, black]), white);
+    ///
+    /// // a naive average would put this in the middle, but green is much brighter than blue
+    /// assert_eq!(Rgba::hex("#0A0").best_contrast(&[]), black);
+    /// assert_eq!(Rgba::hex("#00F").best_contrast(&[]), white);
+    /// ```
+    pub fn best_contrast(&self, candidates: &[Self]) -> Self {
+        const FALLBACK: [Rgba; 2] = [Rgba::hex("#000"), Rgba::hex("#FFF")];
+        let candidates = if candidates.is_empty() {
+            &FALLBACK
+        } else {
+            candidates
+        };
+
+        candidates
+            .iter()
+            .copied()
+            .map(|color| (color, self.contrast_ratio(color)))
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+            .map(|(color, _)| color)
+            .unwrap()
+    }
+
     pub fn is_dark(&self) -> bool {
         let Hsva(_h, _s, v, _a) = self.to_hsva();
         v < 0.6
@@ -212,6 +259,65 @@ impl Rgba {
         Self(r, g, b, a as u8)
     }
 
+    /// Linearly interpolate each channel (including alpha) towards `other`
+    ///
+    /// `t` is clamped to `0.0..=1.0`, where `0.0` is `self` and `1.0` is `other`
+    ///
+    /// ```rust
+    /// # use too::renderer::Rgba;
+    /// let black = Rgba::hex("#000000");
+    /// let white = Rgba::hex("#FFFFFF");
+    /// assert_eq!(black.lerp(white, 0.0), black);
+    /// assert_eq!(black.lerp(white, 1.0), white);
+    /// assert_eq!(black.lerp(white, 0.5), Rgba::new(128, 128, 128, 255));
+    /// ```
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |l: u8, r: u8| (r as f32 - l as f32).mul_add(t, l as f32).round() as u8;
+        Self(
+            lerp(self.0, other.0),
+            lerp(self.1, other.1),
+            lerp(self.2, other.2),
+            lerp(self.3, other.3),
+        )
+    }
+
+    /// Composite this color over a `background` color, using source-over alpha blending
+    ///
+    /// ```rust
+    /// # use too::renderer::Rgba;
+    /// let red = Rgba::hex("#F00");
+    /// let blue = Rgba::hex("#00F");
+    ///
+    /// // opaque colors cover the background
+    /// assert_eq!(red.blend_over(blue), red);
+    /// // transparent colors let the background through
+    /// assert_eq!(red.with_alpha(0).blend_over(blue), blue);
+    /// // and anything in between is mixed
+    /// assert_eq!(red.with_alpha(128).blend_over(blue), Rgba::new(128, 0, 127, 255));
+    /// ```
+    #[must_use]
+    pub fn blend_over(self, background: Self) -> Self {
+        let (sa, da) = (self.3 as f32 / 255.0, background.3 as f32 / 255.0);
+        let a = da.mul_add(1.0 - sa, sa);
+        if a <= 0.0 {
+            return Self(0, 0, 0, 0);
+        }
+
+        let blend = |s: u8, d: u8| {
+            let c = (d as f32 * da).mul_add(1.0 - sa, s as f32 * sa) / a;
+            c.round() as u8
+        };
+
+        Self(
+            blend(self.0, background.0),
+            blend(self.1, background.1),
+            blend(self.2, background.2),
+            (a * 255.0).round() as u8,
+        )
+    }
+
     #[must_use]
     pub fn blend_linear(&self, other: Self, mix: f32) -> Self {
         let [r1, g1, b1, a1] = self.to_float();
@@ -240,6 +346,82 @@ impl Rgba {
         Self::from_float([r * r, g * g, b * b, 1.0])
     }
 
+    /// Create an opaque color from a hue, saturation and lightness
+    ///
+    /// The `hue` is in degrees and wraps around at `360.0`. The `saturation` and `lightness` are clamped to `0.0..=1.0`
+    ///
+    /// ```rust
+    /// # use too::renderer::Rgba;
+    /// assert_eq!(Rgba::from_hsl(0.0, 1.0, 0.5), Rgba::hex("#F00"));
+    /// assert_eq!(Rgba::from_hsl(120.0, 1.0, 0.5), Rgba::hex("#0F0"));
+    /// assert_eq!(Rgba::from_hsl(240.0, 1.0, 0.25), Rgba::hex("#000080"));
+    ///
+    /// // the hue wraps around
+    /// assert_eq!(Rgba::from_hsl(360.0, 1.0, 0.5), Rgba::from_hsl(0.0, 1.0, 0.5));
+    /// assert_eq!(Rgba::from_hsl(-120.0, 1.0, 0.5), Rgba::from_hsl(240.0, 1.0, 0.5));
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let c = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = c * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let m = lightness - c / 2.0;
+
+        let (r, g, b) = match hue {
+            h if h < 60.0 => (c, x, 0.0),
+            h if h < 120.0 => (x, c, 0.0),
+            h if h < 180.0 => (0.0, c, x),
+            h if h < 240.0 => (0.0, x, c),
+            h if h < 300.0 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+
+        Self::from_float([r + m, g + m, b + m, 1.0])
+    }
+
+    /// Get the hue, saturation and lightness of this color, ignoring its alpha
+    ///
+    /// The hue is in degrees, in the range of `0.0..360.0`. Grays have no hue, so they have a hue of `0.0`
+    ///
+    /// ```rust
+    /// # use too::renderer::Rgba;
+    /// assert_eq!(Rgba::hex("#F00").to_hsl(), (0.0, 1.0, 0.5));
+    /// assert_eq!(Rgba::hex("#808080").to_hsl().1, 0.0);
+    ///
+    /// // converting back gives the same color
+    /// for color in ["#FF8000", "#123456", "#C0FFEE", "#7F7F7F", "#010203"] {
+    ///     let color = Rgba::hex(color);
+    ///     let (h, s, l) = color.to_hsl();
+    ///     assert_eq!(Rgba::from_hsl(h, s, l), color);
+    /// }
+    /// ```
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let Self(r, g, b, _) = self;
+        let [r, g, b] = [r, g, b].map(|c| c as f32 / 255.0);
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let lightness = (max + min) / 2.0;
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, saturation.min(1.0), lightness)
+    }
+
     pub fn saturate(self, ratio: f32) -> Self {
         self.to_hsva().saturate(ratio).to_srgb()
     }
@@ -269,7 +451,7 @@ impl Rgba {
                 return v / 12.92;
             }
 
-            ((v + 0.044) / 1.055).powf(2.4)
+            ((v + 0.055) / 1.055).powf(2.4)
         }
 
         let Self(r, g, b, a) = self;
@@ -352,6 +534,12 @@ impl std::str::FromStr for Rgba {
                 6 | 8 => u32::from_str_radix(input, 16)
                     .map_err(|_| "invalid hex digits")
                     .map(|num| {
+                        // without an alpha channel, its opaque
+                        let num = if input.len() == 6 {
+                            num << 8 | 0xFF
+                        } else {
+                            num
+                        };
                         let [r, g, b, a] = num.to_be_bytes();
                         Self(r, g, b, a)
                     }),
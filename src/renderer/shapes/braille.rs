@@ -0,0 +1,189 @@
+use crate::{
+    math::{pos2, vec2, Pos2, Vec2},
+    renderer::{Pixel, Rgba},
+};
+
+use super::Drawable;
+
+/// A canvas that uses braille patterns to get a `2x4` dot grid per cell
+///
+/// Each cell is monochrome, the color of a cell is the color of the last dot set in it
+///
+/// ```rust
+/// # use too::{renderer::{BrailleCanvas, Pixel, Rgba}, math::{pos2, vec2}};
+/// let green = Rgba::hex("#0F0");
+///
+/// let mut canvas = BrailleCanvas::new(vec2(1, 1));
+/// canvas.set_pixel(0, 0, green);
+/// canvas.set_pixel(1, 3, green);
+///
+/// assert_eq!(canvas.cell(pos2(0, 0)), Some(Pixel::new('⢁').fg(green)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BrailleCanvas {
+    dots: Vec<u8>,
+    colors: Vec<Option<Rgba>>,
+    size: Vec2,
+}
+
+impl BrailleCanvas {
+    const BASE: u32 = 0x2800;
+
+    // braille dots are numbered column-major for the first 3 rows, then the 4th row
+    const DOTS: [[u8; 2]; 4] = [
+        [0x01, 0x08], //
+        [0x02, 0x10],
+        [0x04, 0x20],
+        [0x40, 0x80],
+    ];
+
+    /// Create a new canvas that covers `size` cells
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        let size = size.into();
+        let len = size.x.max(0) as usize * size.y.max(0) as usize;
+        Self {
+            dots: vec![0; len],
+            colors: vec![None; len],
+            size,
+        }
+    }
+
+    /// The size of the canvas, in cells
+    pub const fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// The size of the dot grid
+    pub const fn resolution(&self) -> Vec2 {
+        vec2(self.size.x * 2, self.size.y * 4)
+    }
+
+    /// Set a dot, using this color for its cell
+    ///
+    /// Positions outside of the canvas are ignored
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: impl Into<Rgba>) {
+        if let Some((index, bit)) = self.index(x, y) {
+            self.dots[index] |= bit;
+            self.colors[index] = Some(color.into());
+        }
+    }
+
+    /// Clear a dot
+    pub fn unset_pixel(&mut self, x: i32, y: i32) {
+        if let Some((index, bit)) = self.index(x, y) {
+            self.dots[index] &= !bit;
+            if self.dots[index] == 0 {
+                self.colors[index] = None
+            }
+        }
+    }
+
+    /// Is this dot set?
+    pub fn get_pixel(&self, x: i32, y: i32) -> bool {
+        self.index(x, y)
+            .filter(|&(index, bit)| self.dots[index] & bit != 0)
+            .is_some()
+    }
+
+    /// Set the dots on a line between two dot positions, using this color for their cells
+    ///
+    /// Dots outside of the canvas are ignored
+    ///
+    /// ```rust
+    /// # use too::{renderer::{BrailleCanvas, Pixel, Rgba}, math::{pos2, vec2}};
+    /// let mut canvas = BrailleCanvas::new(vec2(2, 1));
+    /// canvas.line(pos2(0, 0), pos2(3, 3), Rgba::hex("#FFF"));
+    ///
+    /// assert!(canvas.get_pixel(1, 1));
+    /// assert!(canvas.get_pixel(3, 3));
+    /// assert!(!canvas.get_pixel(3, 0));
+    /// ```
+    pub fn line(&mut self, start: Pos2, end: Pos2, color: impl Into<Rgba>) {
+        let color = color.into();
+        let (dx, dy) = ((end.x - start.x).abs(), -(end.y - start.y).abs());
+        let (sx, sy) = ((end.x - start.x).signum(), (end.y - start.y).signum());
+        let (mut x, mut y, mut err) = (start.x, start.y, dx + dy);
+
+        loop {
+            self.set_pixel(x, y, color);
+            if (x, y) == (end.x, end.y) {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Set the dots on lines connecting these dot positions, in order
+    pub fn polyline(&mut self, points: &[Pos2], color: impl Into<Rgba>) {
+        let color = color.into();
+        if let [point] = points {
+            self.set_pixel(point.x, point.y, color);
+        }
+        for segment in points.windows(2) {
+            self.line(segment[0], segment[1], color);
+        }
+    }
+
+    /// Clear all of the dots
+    pub fn clear(&mut self) {
+        self.dots.fill(0);
+        self.colors.fill(None);
+    }
+
+    /// Get the [`Pixel`] that represents the cell at this position
+    ///
+    /// This returns `None` if no dots are set in the cell
+    pub fn cell(&self, pos: Pos2) -> Option<Pixel> {
+        if pos.x < 0 || pos.y < 0 || pos.x >= self.size.x || pos.y >= self.size.y {
+            return None;
+        }
+
+        let index = pos.y as usize * self.size.x as usize + pos.x as usize;
+        let dots = self.dots[index];
+        if dots == 0 {
+            return None;
+        }
+
+        let ch = char::from_u32(Self::BASE + dots as u32)?;
+        let mut pixel = Pixel::new(ch);
+        if let Some(color) = self.colors[index] {
+            pixel = pixel.fg(color)
+        }
+        Some(pixel)
+    }
+
+    /// Get all of the cells that have something set, and their local positions
+    pub fn pixels(&self) -> impl Iterator<Item = (Pos2, Pixel)> + '_ {
+        (0..self.size.y)
+            .flat_map(|y| (0..self.size.x).map(move |x| pos2(x, y)))
+            .filter_map(|pos| self.cell(pos).map(|pixel| (pos, pixel)))
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<(usize, u8)> {
+        let size = self.resolution();
+        if x < 0 || y < 0 || x >= size.x || y >= size.y {
+            return None;
+        }
+
+        let index = (y / 4) as usize * self.size.x as usize + (x / 2) as usize;
+        let bit = Self::DOTS[(y % 4) as usize][(x % 2) as usize];
+        Some((index, bit))
+    }
+}
+
+// the canvas has its own size, anything outside of the region is cropped when its drawn
+impl Drawable for BrailleCanvas {
+    fn draw(&self, _: Vec2, put: &mut dyn FnMut(Pos2, Pixel)) {
+        for (pos, pixel) in self.pixels() {
+            put(pos, pixel);
+        }
+    }
+}
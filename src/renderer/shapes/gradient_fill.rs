@@ -0,0 +1,85 @@
+use crate::{
+    layout::Axis,
+    math::{pos2, Pos2, Vec2},
+    renderer::{Gradient, Pixel},
+};
+
+use super::Drawable;
+
+/// Fills a region with a [`Gradient`], sampled along an axis
+///
+/// Each column (for [`Axis::Horizontal`]) or row (for [`Axis::Vertical`]) gets its own color,
+/// going from the start of the gradient to its end.
+///
+/// Its a [`Drawable`], so it can be drawn with [`Render::shape`](crate::view::Render::shape) or anywhere else a shape is accepted
+///
+/// ```rust
+/// # use too::{renderer::{Cell, Color, Gradient, GradientFill, Pixel, Rasterizer as _, Surface}, math::{pos2, vec2, Rect}, view::CroppedSurface};
+/// let fill = GradientFill::horizontal(Gradient::RAINBOW1);
+///
+/// let pixels: Vec<_> = fill.pixels(vec2(3, 2)).collect();
+/// assert_eq!(pixels.len(), 6);
+/// let start = Pixel::new(' ').bg(Gradient::RAINBOW1.as_rgba(0.0));
+/// let end = Pixel::new(' ').bg(Gradient::RAINBOW1.as_rgba(1.0));
+/// assert_eq!(pixels[0], (pos2(0, 0), start));
+/// assert_eq!(pixels[2], (pos2(2, 0), end));
+///
+/// // an empty region has nothing to fill
+/// assert_eq!(fill.pixels(vec2(0, 2)).count(), 0);
+///
+/// // drawing it respects the crop rect
+/// let mut surface = Surface::new(vec2(4, 1));
+/// let mut cropped = CroppedSurface { clip_rect: Rect::from_min_size(pos2(1, 0), vec2(2, 1)), surface: &mut surface };
+/// for (pos, pixel) in fill.pixels(vec2(4, 1)) {
+///     cropped.pixel(pos, pixel);
+/// }
+/// // the start of the gradient ends up at the start of the crop rect, and nothing is drawn outside of it
+/// assert_eq!(surface.get(pos2(1, 0)).unwrap().bg(), Color::Set(Gradient::RAINBOW1.as_rgba(0.0)));
+/// assert!(matches!(surface.get(pos2(0, 0)), Some(Cell::Empty)));
+/// assert!(matches!(surface.get(pos2(3, 0)), Some(Cell::Empty)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GradientFill {
+    pub gradient: Gradient,
+    pub axis: Axis,
+}
+
+impl GradientFill {
+    /// Create a gradient fill along this axis
+    pub const fn new(gradient: Gradient, axis: Axis) -> Self {
+        Self { gradient, axis }
+    }
+
+    /// Create a gradient fill that changes from left to right
+    pub const fn horizontal(gradient: Gradient) -> Self {
+        Self::new(gradient, Axis::Horizontal)
+    }
+
+    /// Create a gradient fill that changes from top to bottom
+    pub const fn vertical(gradient: Gradient) -> Self {
+        Self::new(gradient, Axis::Vertical)
+    }
+
+    /// Get the pixels that fill a region of `size` cells, and their local positions
+    pub fn pixels(&self, size: impl Into<Vec2>) -> impl Iterator<Item = (Pos2, Pixel)> + '_ {
+        let size = size.into();
+        let extent: i32 = self.axis.main(size);
+        // with a single column (or row) there is nothing to interpolate between
+        let last = (extent - 1).max(1) as f32;
+
+        (0..size.y.max(0))
+            .flat_map(move |y| (0..size.x.max(0)).map(move |x| pos2(x, y)))
+            .map(move |pos| {
+                let t = self.axis.main::<i32>(pos) as f32 / last;
+                (pos, Pixel::new(' ').bg(self.gradient.as_rgba(t)))
+            })
+    }
+}
+
+impl Drawable for GradientFill {
+    fn draw(&self, size: Vec2, put: &mut dyn FnMut(Pos2, Pixel)) {
+        for (pos, pixel) in self.pixels(size) {
+            put(pos, pixel);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    math::{pos2, vec2, Pos2, Vec2},
+    renderer::{Pixel, Rgba},
+};
+
+use super::Drawable;
+
+/// A canvas that uses half-blocks (`▀` `▄`) to double the vertical resolution of a cell
+///
+/// Each cell holds 2 sub-pixels stacked vertically, so a canvas of `w x h` cells has a resolution of `w x h*2`
+///
+/// ```rust
+/// # use too::{renderer::{HalfBlockCanvas, Pixel, Rgba}, math::{pos2, vec2}};
+/// let red = Rgba::hex("#F00");
+/// let blue = Rgba::hex("#00F");
+///
+/// let mut canvas = HalfBlockCanvas::new(vec2(2, 1));
+/// canvas.set_pixel(0, 0, red);
+/// canvas.set_pixel(0, 1, blue);
+/// canvas.set_pixel(1, 1, blue);
+///
+/// assert_eq!(canvas.cell(pos2(0, 0)), Some(Pixel::new('▀').fg(red).bg(blue)));
+/// assert_eq!(canvas.cell(pos2(1, 0)), Some(Pixel::new('▄').fg(blue)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HalfBlockCanvas {
+    pixels: Vec<Option<Rgba>>,
+    size: Vec2,
+}
+
+impl HalfBlockCanvas {
+    const UPPER: char = '▀';
+    const LOWER: char = '▄';
+    const FULL: char = '█';
+
+    /// Create a new canvas that covers `size` cells
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        let size = size.into();
+        Self {
+            pixels: vec![None; size.x.max(0) as usize * size.y.max(0) as usize * 2],
+            size,
+        }
+    }
+
+    /// The size of the canvas, in cells
+    pub const fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// The size of the sub-pixel grid
+    pub const fn resolution(&self) -> Vec2 {
+        vec2(self.size.x, self.size.y * 2)
+    }
+
+    /// Set a sub-pixel to a color
+    ///
+    /// Positions outside of the canvas are ignored
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: impl Into<Rgba>) {
+        if let Some(index) = self.index(x, y) {
+            self.pixels[index] = Some(color.into())
+        }
+    }
+
+    /// Clear a sub-pixel
+    pub fn unset_pixel(&mut self, x: i32, y: i32) {
+        if let Some(index) = self.index(x, y) {
+            self.pixels[index] = None
+        }
+    }
+
+    /// Get the color of a sub-pixel, if its set
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<Rgba> {
+        self.index(x, y).and_then(|index| self.pixels[index])
+    }
+
+    /// Clear all of the sub-pixels
+    pub fn clear(&mut self) {
+        self.pixels.fill(None);
+    }
+
+    /// Get the [`Pixel`] that represents the cell at this position
+    ///
+    /// This returns `None` if neither half of the cell is set
+    pub fn cell(&self, pos: Pos2) -> Option<Pixel> {
+        let top = self.get_pixel(pos.x, pos.y * 2);
+        let bottom = self.get_pixel(pos.x, pos.y * 2 + 1);
+
+        let pixel = match (top, bottom) {
+            (Some(top), Some(bottom)) if top == bottom => Pixel::new(Self::FULL).fg(top),
+            (Some(top), Some(bottom)) => Pixel::new(Self::UPPER).fg(top).bg(bottom),
+            (Some(top), None) => Pixel::new(Self::UPPER).fg(top),
+            (None, Some(bottom)) => Pixel::new(Self::LOWER).fg(bottom),
+            (None, None) => return None,
+        };
+        Some(pixel)
+    }
+
+    /// Get all of the cells that have something set, and their local positions
+    pub fn pixels(&self) -> impl Iterator<Item = (Pos2, Pixel)> + '_ {
+        (0..self.size.y)
+            .flat_map(|y| (0..self.size.x).map(move |x| pos2(x, y)))
+            .filter_map(|pos| self.cell(pos).map(|pixel| (pos, pixel)))
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        let size = self.resolution();
+        if x < 0 || y < 0 || x >= size.x || y >= size.y {
+            return None;
+        }
+        Some(y as usize * size.x as usize + x as usize)
+    }
+}
+
+// the canvas has its own size, anything outside of the region is cropped when its drawn
+impl Drawable for HalfBlockCanvas {
+    fn draw(&self, _: Vec2, put: &mut dyn FnMut(Pos2, Pixel)) {
+        for (pos, pixel) in self.pixels() {
+            put(pos, pixel);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Higher-resolution drawing using sub-cell glyphs, lines, and gradient fills
+use crate::{
+    math::{Pos2, Vec2},
+    renderer::Pixel,
+};
+
+mod half_block;
+pub use half_block::HalfBlockCanvas;
+
+mod braille;
+pub use braille::BrailleCanvas;
+
+mod gradient_fill;
+pub use gradient_fill::GradientFill;
+
+mod polyline;
+pub use polyline::{LineStyle, Polyline};
+
+/// Something that can be drawn into a region of cells
+///
+/// Its drawn with [`Render::shape`](crate::view::Render::shape), which crops it to the view, or behind some views with [`views::background`](crate::views::background).
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2, Pos2, Vec2}, renderer::{shapes::Drawable, Color, Pixel, Rgba, Surface}, view::{CroppedSurface, State}, views::canvas};
+/// // a checkerboard, that fills whatever region its given
+/// struct Checkers(Rgba);
+///
+/// impl Drawable for Checkers {
+///     fn draw(&self, size: Vec2, put: &mut dyn FnMut(Pos2, Pixel)) {
+///         for y in 0..size.y {
+///             for x in (y % 2..size.x).step_by(2) {
+///                 put(pos2(x, y), Pixel::new(' ').bg(self.0));
+///             }
+///         }
+///     }
+/// }
+///
+/// let red = Rgba::hex("#F00");
+/// let mut state = State::default();
+/// let mut surface = Surface::new(vec2(4, 2));
+/// state.build(surface.rect(), |ui| {
+///     ui.show(canvas(move |_, render| _ = render.shape(&Checkers(red))));
+/// });
+/// state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+///
+/// assert_eq!(surface.get(pos2(0, 0)).unwrap().bg(), Color::Set(red));
+/// assert_ne!(surface.get(pos2(1, 0)).unwrap().bg(), Color::Set(red));
+/// assert_eq!(surface.get(pos2(1, 1)).unwrap().bg(), Color::Set(red));
+/// ```
+pub trait Drawable {
+    /// Draw the shape into a region of `size` cells, by calling `put` with each cell's local position and its [`Pixel`]
+    fn draw(&self, size: Vec2, put: &mut dyn FnMut(Pos2, Pixel));
+}
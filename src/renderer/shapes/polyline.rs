@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::{
+    math::{pos2, Pos2, Vec2},
+    renderer::{Pixel, Rgba},
+    view::Elements,
+};
+
+use super::Drawable;
+
+/// The glyphs a [`Polyline`] is drawn with
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LineStyle {
+    /// `─` `│` with `┌` `┐` `└` `┘` corners
+    #[default]
+    Thin,
+    /// `━` `┃` with `┏` `┓` `┗` `┛` corners
+    Thick,
+    /// `╌` `╎` with thin corners
+    Dashed,
+    /// `╍` `╏` with thick corners
+    ThickDashed,
+    /// `═` `║` with `╔` `╗` `╚` `╝` corners
+    Double,
+}
+
+impl LineStyle {
+    const UP: u8 = 1 << 0;
+    const DOWN: u8 = 1 << 1;
+    const LEFT: u8 = 1 << 2;
+    const RIGHT: u8 = 1 << 3;
+    const HORIZONTAL: u8 = Self::LEFT | Self::RIGHT;
+    const VERTICAL: u8 = Self::UP | Self::DOWN;
+
+    // indexed by `mask - 5`, skipping the straight lines: ┘ ┐ ┤ _ └ ┌ ├ _ ┴ ┬ ┼
+    const THIN: [char; 11] = ['┘', '┐', '┤', '─', '└', '┌', '├', '─', '┴', '┬', '┼'];
+    const THICK: [char; 11] = ['┛', '┓', '┫', '━', '┗', '┏', '┣', '━', '┻', '┳', '╋'];
+    const DOUBLE: [char; 11] = ['╝', '╗', '╣', '═', '╚', '╔', '╠', '═', '╩', '╦', '╬'];
+
+    const fn straight(self) -> (char, char) {
+        match self {
+            Self::Thin => (Elements::HORIZONTAL_LINE, Elements::VERTICAL_LINE),
+            Self::Thick => (
+                Elements::THICK_HORIZONTAL_LINE,
+                Elements::THICK_VERTICAL_LINE,
+            ),
+            Self::Dashed => (Elements::DASH_HORIZONTAL_LINE, Elements::DASH_VERTICAL_LINE),
+            Self::ThickDashed => (
+                Elements::THICK_DASH_HORIZONTAL_LINE,
+                Elements::THICK_DASH_VERTICAL_LINE,
+            ),
+            Self::Double => (
+                Elements::DOUBLE_HORIZONATAL_LINE,
+                Elements::DOUBLE_VERTICAL_LINE,
+            ),
+        }
+    }
+
+    const fn joints(self) -> &'static [char; 11] {
+        match self {
+            Self::Thin | Self::Dashed => &Self::THIN,
+            Self::Thick | Self::ThickDashed => &Self::THICK,
+            Self::Double => &Self::DOUBLE,
+        }
+    }
+
+    /// The glyph for a cell that connects to its neighbors in these directions
+    const fn glyph(self, mask: u8) -> char {
+        let (horizontal, vertical) = self.straight();
+        match mask {
+            0 | Self::LEFT | Self::RIGHT | Self::HORIZONTAL => horizontal,
+            Self::UP | Self::DOWN | Self::VERTICAL => vertical,
+            _ => self.joints()[(mask - 5) as usize],
+        }
+    }
+}
+
+/// A line that connects a series of points with box-drawing glyphs
+///
+/// Each segment is stepped one cell at a time (horizontally or vertically, never diagonally),
+/// so a diagonal segment becomes a staircase. Cells where the line changes direction, or crosses itself, get a corner or junction glyph.
+///
+/// The positions are in cells. Drawing the [`pixels`](Self::pixels) with [`Render::pixels`](crate::view::Render::pixels) clips them to the view.
+///
+/// For a finer line, use [`BrailleCanvas::polyline`](crate::renderer::BrailleCanvas::polyline)
+///
+/// ```rust
+/// # use too::{renderer::{LineStyle, Polyline}, view::test::render_to_string, math::pos2, views::canvas};
+/// let out = render_to_string(|ui| {
+///     ui.show(canvas(|_, render| {
+///         let line = Polyline::new([pos2(0, 0), pos2(3, 0), pos2(3, 2), pos2(0, 2)]);
+///         render.pixels(line.pixels());
+///     }));
+/// });
+/// assert_eq!(out, "───┐\n   │\n───┘");
+///
+/// // anything off of the surface is clipped
+/// let out = render_to_string(|ui| {
+///     ui.show(canvas(|_, render| {
+///         let line = Polyline::new([pos2(-5, 1), pos2(2, 1), pos2(2, -3)]).style(LineStyle::Thick);
+///         render.pixels(line.pixels());
+///     }));
+/// });
+/// assert_eq!(out, "  ┃\n━━┛");
+///
+/// // a diagonal is a staircase
+/// let out = render_to_string(|ui| {
+///     ui.show(canvas(|_, render| {
+///         render.pixels(Polyline::new([pos2(0, 0), pos2(2, 2)]).pixels());
+///     }));
+/// });
+/// assert_eq!(out, "│\n└┐\n └─");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline {
+    points: Vec<Pos2>,
+    style: LineStyle,
+    color: Option<Rgba>,
+}
+
+impl Polyline {
+    /// Create a line through these points, in order
+    pub fn new(points: impl IntoIterator<Item = Pos2>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: LineStyle::Thin,
+            color: None,
+        }
+    }
+
+    /// The glyphs to use for the line
+    pub const fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The color of the line
+    pub fn fg(mut self, color: impl Into<Rgba>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Get every cell the line passes through, and the [`Pixel`] for it
+    ///
+    /// Cells are produced in the order that the line first reaches them
+    pub fn pixels(&self) -> impl Iterator<Item = (Pos2, Pixel)> + '_ {
+        let mut cells: Vec<(Pos2, u8)> = Vec::new();
+        let mut index = HashMap::new();
+        let mut connect = |pos: Pos2, mask: u8| {
+            let i = *index.entry(pos).or_insert_with(|| {
+                cells.push((pos, 0));
+                cells.len() - 1
+            });
+            cells[i].1 |= mask;
+        };
+
+        if let Some(&first) = self.points.first() {
+            connect(first, 0);
+        }
+
+        for segment in self.points.windows(2) {
+            let (Pos2 { mut x, mut y }, end) = (segment[0], segment[1]);
+
+            let (dx, dy) = ((end.x - x).abs(), -(end.y - y).abs());
+            let (sx, sy) = ((end.x - x).signum(), (end.y - y).signum());
+            let mut err = dx + dy;
+
+            while pos2(x, y) != end {
+                let from = pos2(x, y);
+                let e2 = 2 * err;
+                let (out, into) = if e2 - dy > dx - e2 {
+                    err += dy;
+                    x += sx;
+                    Self::directions(sx < 0, LineStyle::LEFT, LineStyle::RIGHT)
+                } else {
+                    err += dx;
+                    y += sy;
+                    Self::directions(sy < 0, LineStyle::UP, LineStyle::DOWN)
+                };
+                connect(from, out);
+                connect(pos2(x, y), into);
+            }
+        }
+
+        let (style, color) = (self.style, self.color);
+        cells.into_iter().map(move |(pos, mask)| {
+            let mut pixel = Pixel::new(style.glyph(mask));
+            if let Some(color) = color {
+                pixel = pixel.fg(color)
+            }
+            (pos, pixel)
+        })
+    }
+
+    // the direction we leave the cell in, and the direction we enter the next one from
+    const fn directions(negative: bool, back: u8, forward: u8) -> (u8, u8) {
+        if negative {
+            (back, forward)
+        } else {
+            (forward, back)
+        }
+    }
+}
+
+// the points are already in local positions, anything outside of the region is cropped when its drawn
+impl Drawable for Polyline {
+    fn draw(&self, _: Vec2, put: &mut dyn FnMut(Pos2, Pixel)) {
+        for (pos, pixel) in self.pixels() {
+            put(pos, pixel);
+        }
+    }
+}
@@ -1,7 +1,9 @@
+use std::{ops::Range, sync::Arc};
+
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::{
-    cell::{Attribute, Cell, Color},
+    cell::{Attribute, Cell, Color, Underline},
     Pixel, Renderer,
 };
 
@@ -11,9 +13,12 @@ use crate::{
 };
 
 /// An owned view of a rect region that allows drawing
+#[derive(Clone)]
 pub struct Surface {
     front: Vec<Cell>,
     back: Vec<Cell>,
+    // rows that were touched since the last render
+    dirty: Vec<bool>,
     size: Vec2,
 }
 
@@ -21,20 +26,55 @@ impl Surface {
     pub fn update(&mut self, event: &Event) {
         match event {
             &Event::Resize(size) => self.resize(size),
-            Event::SwitchAltScreen => Self::reset(&mut self.front, Cell::Empty),
+            Event::SwitchAltScreen => self.mark_all_dirty(),
             _ => {}
         }
     }
 
+    pub fn get(&self, pos: Pos2) -> Option<&Cell> {
+        if !self.rect().contains(pos) {
+            return None;
+        }
+
+        let index = Self::pos_to_index(pos, self.size.x);
+        self.back.get(index)
+    }
+
     pub fn get_mut(&mut self, pos: Pos2) -> Option<&mut Cell> {
         if !self.rect().contains(pos) {
             return None;
         }
 
         let index = Self::pos_to_index(pos, self.size.x);
+        self.dirty[pos.y as usize] = true;
         self.back.get_mut(index)
     }
 
+    /// Set the cell at this position, merging it with what is already there
+    ///
+    /// A wide cell (e.g. CJK or an emoji) takes up the cells after it, they are marked as continuations and aren't rendered.
+    /// Writing over any cell of a wide cell clears the rest of it to a space, so half of a character is never left behind.
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, Cell, Grapheme, Pixel, Rgba}, math::{pos2, vec2}};
+    /// let blue = Rgba::hex("#00F");
+    /// let mut surface = Surface::new(vec2(4, 1));
+    /// surface.fill(surface.rect(), Pixel::new(' ').bg(blue));
+    ///
+    /// surface.set(pos2(0, 0), Grapheme::new("日"));
+    /// assert_eq!(surface.get(pos2(1, 0)), Some(&Cell::Continuation));
+    ///
+    /// // writing over the second half also clears the first half
+    /// surface.set(pos2(1, 0), Pixel::new('a'));
+    /// assert_eq!(surface.get(pos2(0, 0)), Some(&Cell::Pixel(Pixel::new(' ').bg(blue))));
+    /// assert_eq!(surface.get(pos2(1, 0)), Some(&Cell::Pixel(Pixel::new('a').bg(blue))));
+    ///
+    /// // and a wide cell over the first half of another one clears its second half
+    /// surface.set(pos2(2, 0), Grapheme::new("本"));
+    /// surface.set(pos2(1, 0), Grapheme::new("日"));
+    /// assert_eq!(surface.get(pos2(2, 0)), Some(&Cell::Continuation));
+    /// assert_eq!(surface.get(pos2(3, 0)), Some(&Cell::Pixel(Pixel::new(' ').bg(blue))));
+    /// ```
     pub fn set(&mut self, pos: Pos2, cell: impl Into<Cell>) {
         // implictly clip cell
         if !self.rect().contains(pos) {
@@ -43,33 +83,48 @@ impl Surface {
 
         let (width, x) = (self.size.x as usize, pos.x as usize);
         let index = Self::pos_to_index(pos, self.size.x);
+        let row = index - x..index - x + width;
+        self.dirty[pos.y as usize] = true;
+
+        let cell = cell.into();
+        let cell_width = cell.width().clamp(1, width - x);
 
-        let empty = self
-            .back
-            .get(index)
-            .map(Cell::width)
-            .unwrap_or(0)
-            .min(width - x);
+        // any wide cell that this one covers (even partially) goes away
+        for i in index..index + cell_width {
+            if self.back[i].is_continuation() || self.back[i].width() > 1 {
+                self.clear_wide(i, row.clone());
+            }
+        }
 
         // take the old one so we can merge it with the new one
         let mut old = std::mem::take(&mut self.back[index]);
+        Cell::merge(&mut old, cell);
+        self.back[index] = old;
 
-        for i in 0..empty {
-            if index + i < self.back.len() {
-                self.back[index + i] = Cell::Empty
-            }
+        for cell in &mut self.back[index + 1..index + cell_width] {
+            *cell = Cell::Continuation
         }
+    }
 
-        let cell = cell.into();
-        let width = cell.width().min(width - x);
+    // replace every cell of the wide cell at `index` with a space
+    fn clear_wide(&mut self, index: usize, row: Range<usize>) {
+        let mut start = index;
+        while start > row.start && self.back[start].is_continuation() {
+            start -= 1;
+        }
 
-        Cell::merge(&mut old, cell);
-        self.back[index] = old;
+        let end = (start + self.back[start].width().max(1)).min(row.end);
+        let blank = self.back[start].blank();
+        for cell in &mut self.back[start..end] {
+            *cell = blank.clone();
+        }
 
-        for i in 1..width {
-            if index + i < self.back.len() {
-                self.back[index + i] = Cell::Continuation
+        // anything left over belonged to it as well
+        for cell in self.back[end..row.end].iter_mut() {
+            if !cell.is_continuation() {
+                break;
             }
+            *cell = blank.clone();
         }
     }
 
@@ -79,6 +134,7 @@ impl Surface {
         let y = Self::pos_to_index(pos2(0, line), self.size.x);
         let (start, end) = (start as usize + y, end as usize + y);
         self.back[start..end].fill(Cell::Pixel(pixel));
+        self.dirty[line as usize] = true;
     }
 
     pub fn patch(&mut self, rect: Rect, patch: impl Fn(&mut Cell)) {
@@ -92,11 +148,27 @@ impl Surface {
         }
     }
 
+    /// Fill a region of the surface with a pixel
+    ///
+    /// If the pixel has a translucent background it is composited over what is already there
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, Rgba, Color}, math::{pos2, vec2, rect}};
+    /// let mut surface = Surface::new(vec2(2, 1));
+    /// surface.fill(rect(vec2(2, 1)), Rgba::hex("#00F"));
+    /// surface.fill(rect(vec2(2, 1)), Rgba::hex("#F008"));
+    ///
+    /// let cell = surface.get_mut(pos2(0, 0)).unwrap();
+    /// assert_eq!(cell.bg(), Color::Set(Rgba::new(136, 0, 119, 255)));
+    /// ```
     // PERF we can use 'set_line' if we patch any cells afterward
     pub fn fill(&mut self, rect: Rect, pixel: impl Into<Pixel>) {
         let pixel = pixel.into();
-        if rect == self.rect() {
+        // translucent pixels have to be merged with what is already there
+        let opaque = !matches!(pixel.bg, Color::Set(bg) if bg.is_transparent());
+        if opaque && rect == self.rect() {
             self.back.fill(Cell::Pixel(pixel));
+            self.dirty.fill(true);
             return;
         }
 
@@ -110,9 +182,159 @@ impl Surface {
         }
     }
 
+    /// Fill a region of the surface with a pixel computed for each cell
+    ///
+    /// The closure is given the position of each cell, in the surface. The region is clipped to the surface first,
+    /// so the closure is only called for cells that exist. The pixels are merged with what is already there, like [`fill`](Self::fill).
+    ///
+    /// Each pixel should take up a single cell
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, Cell, Color, Grapheme, Pixel, Rgba}, math::{pos2, vec2, rect, Rect}};
+    /// let (dark, light, blue) = (Rgba::hex("#000"), Rgba::hex("#FFF"), Rgba::hex("#00F"));
+    ///
+    /// let mut surface = Surface::new(vec2(4, 2));
+    /// surface.fill(surface.rect(), blue);
+    /// // a checkerboard, that is partially off of the surface
+    /// surface.fill_region(Rect::from_min_size(pos2(-1, 0), vec2(4, 4)), |pos| {
+    ///     Pixel::new(' ').bg(if (pos.x + pos.y) % 2 == 0 { dark } else { light })
+    /// });
+    ///
+    /// assert_eq!(surface.get(pos2(0, 0)).unwrap().bg(), Color::Set(dark));
+    /// assert_eq!(surface.get(pos2(1, 0)).unwrap().bg(), Color::Set(light));
+    /// assert_eq!(surface.get(pos2(0, 1)).unwrap().bg(), Color::Set(light));
+    /// // the last column was outside of the region
+    /// assert_eq!(surface.get(pos2(3, 0)).unwrap().bg(), Color::Set(blue));
+    ///
+    /// // a wide cell cut off by the region leaves a space with its background behind
+    /// surface.set(pos2(1, 1), Grapheme::new("日"));
+    /// surface.fill_region(Rect::from_min_size(pos2(0, 1), vec2(2, 1)), |_| Pixel::new('a'));
+    /// assert_eq!(surface.get(pos2(2, 1)), Some(&Cell::Pixel(Pixel::new(' ').bg(dark))));
+    /// ```
+    pub fn fill_region(&mut self, rect: Rect, mut pixel: impl FnMut(Pos2) -> Pixel) {
+        let rect = self.rect().intersection(rect);
+        if rect.is_empty() {
+            return;
+        }
+
+        let width = rect.width() as usize;
+        for y in rect.top()..rect.bottom() {
+            let start = Self::pos_to_index(pos2(rect.left(), y), self.size.x);
+            // the region starts in the middle of a wide cell
+            if self.back[start].is_continuation() {
+                let row = start - rect.left() as usize
+                    ..start - rect.left() as usize + self.size.x as usize;
+                self.clear_wide(start, row);
+            }
+
+            let (row, rest) = self.back[start..].split_at_mut(width);
+
+            // a wide cell at the end of the region would leave its continuations behind
+            let spill = row[width - 1].width().saturating_sub(1);
+            let blank = row[width - 1].blank();
+            for (x, cell) in (rect.left()..).zip(row) {
+                Cell::merge(cell, Cell::Pixel(pixel(pos2(x, y))));
+            }
+
+            let remaining = (self.size.x - rect.right()) as usize;
+            for cell in rest.iter_mut().take(spill.min(remaining)) {
+                if cell.is_continuation() {
+                    *cell = blank.clone()
+                }
+            }
+
+            self.dirty[y as usize] = true;
+        }
+    }
+
+    /// Copy the cells of another surface into this one, with its top-left corner at `at`
+    ///
+    /// The cells are merged with what is already there, and anything outside of this surface is clipped
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, Cell, Color, Pixel, Rgba}, math::{pos2, vec2, rect}};
+    /// let blue = Rgba::hex("#00F");
+    ///
+    /// // render something once, off-screen
+    /// let mut panel = Surface::new(vec2(3, 1));
+    /// for (x, ch) in (0..).zip(['a', 'b', 'c']) {
+    ///     panel.set(pos2(x, 0), Pixel::new(ch));
+    /// }
+    ///
+    /// let mut surface = Surface::new(vec2(2, 2));
+    /// surface.fill(rect(vec2(2, 2)), blue);
+    /// surface.blit(pos2(1, 1), &panel);
+    ///
+    /// // only the part that fits was copied, and it kept the background
+    /// let cell = surface.get(pos2(1, 1)).unwrap();
+    /// assert_eq!(cell, &Cell::Pixel(Pixel::new('a').bg(blue)));
+    /// assert_eq!(surface.get(pos2(0, 1)).unwrap().bg(), Color::Set(blue));
+    /// assert_eq!(surface.get(pos2(0, 0)).unwrap().bg(), Color::Set(blue));
+    /// ```
+    pub fn blit(&mut self, at: Pos2, src: &Surface) {
+        let rect = self.rect().intersection(src.rect().translate(at.to_vec2()));
+        for y in rect.top()..rect.bottom() {
+            for x in rect.left()..rect.right() {
+                let pos = pos2(x, y);
+                let index = Self::pos_to_index(pos - at, src.size.x);
+                match &src.back[index] {
+                    Cell::Empty | Cell::Continuation => {}
+                    cell => self.set(pos, cell.clone()),
+                }
+            }
+        }
+    }
+
     pub const fn rect(&self) -> Rect {
         rect(self.size)
     }
+
+    /// Was this row changed since the last [`render`](Self::render)?
+    ///
+    /// Rows that weren't changed are skipped entirely when rendering
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, DebugRenderer, Pixel, Rgba}, math::{pos2, vec2}};
+    /// let mut surface = Surface::new(vec2(200, 60));
+    /// surface.fill(surface.rect(), Pixel::new('.'));
+    /// surface.render(&mut DebugRenderer::new()).unwrap();
+    /// assert!(!surface.is_dirty(0));
+    ///
+    /// surface.set(pos2(3, 10), Pixel::new('a'));
+    /// assert!(surface.is_dirty(10) && !surface.is_dirty(11));
+    ///
+    /// // only that cell is written, then the cursor goes back home
+    /// let mut renderer = DebugRenderer::new();
+    /// surface.render(&mut renderer).unwrap();
+    /// assert_eq!(renderer.to_string().matches("move to").count(), 2);
+    /// assert!(!surface.is_dirty(10));
+    ///
+    /// // changing just the color is still rendered
+    /// surface.set(pos2(3, 10), Pixel::new('a').fg(Rgba::hex("#F00")));
+    /// let mut renderer = DebugRenderer::new();
+    /// surface.render(&mut renderer).unwrap();
+    /// assert!(renderer.to_string().contains("set_fg"));
+    ///
+    /// // and everything can be rendered again
+    /// surface.mark_all_dirty();
+    /// let mut renderer = DebugRenderer::new();
+    /// surface.render(&mut renderer).unwrap();
+    /// assert_eq!(renderer.to_string().matches("move to").count(), 61);
+    /// ```
+    pub fn is_dirty(&self, row: i32) -> bool {
+        usize::try_from(row)
+            .ok()
+            .and_then(|row| self.dirty.get(row).copied())
+            .unwrap_or(false)
+    }
+
+    /// Render the whole surface again on the next [`render`](Self::render)
+    ///
+    /// This is useful when the screen no longer matches what was last rendered, e.g. after the alt screen was switched
+    pub fn mark_all_dirty(&mut self) {
+        Self::reset(&mut self.front, Cell::Empty);
+        self.dirty.fill(true);
+    }
 }
 
 impl Surface {
@@ -121,49 +343,105 @@ impl Surface {
         Self {
             front: vec![Cell::Empty; size.x as usize * size.y as usize],
             back: vec![Cell::Empty; size.x as usize * size.y as usize],
+            dirty: vec![true; size.y as usize],
             size,
         }
     }
 
+    /// Resize this surface, keeping the part of it that still fits
+    ///
+    /// The overlapping top-left region is copied over and any new area is filled with the default cell.
+    ///
+    /// The whole surface is rendered again on the next [`render`](Self::render)
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, Cell, DebugRenderer, Pixel}, math::{pos2, vec2}};
+    /// let mut surface = Surface::new(vec2(3, 1));
+    /// for (x, ch) in (0..).zip(['a', 'b', 'c']) {
+    ///     surface.set(pos2(x, 0), Pixel::new(ch));
+    /// }
+    /// surface.render(&mut DebugRenderer::new()).unwrap();
+    ///
+    /// surface.resize(vec2(2, 2));
+    /// assert_eq!(surface.get(pos2(0, 0)), Some(&Cell::Pixel(Pixel::new('a'))));
+    /// assert_eq!(surface.get(pos2(1, 0)), Some(&Cell::Pixel(Pixel::new('b'))));
+    /// assert_eq!(surface.get(pos2(0, 1)), Some(&Cell::Pixel(Pixel::default())));
+    /// assert_eq!(surface.get(pos2(2, 0)), None);
+    ///
+    /// // nothing is assumed to still be on the screen
+    /// let mut renderer = DebugRenderer::new();
+    /// surface.render(&mut renderer).unwrap();
+    /// assert!(renderer.to_string().contains("ab"));
+    /// ```
     #[cfg_attr(feature = "profile", profiling::function)]
     pub fn resize(&mut self, size: Vec2) {
         if self.size == size {
             return;
         }
 
-        let new = size.x as usize * size.y as usize;
-        // self.front = vec![Cell::Empty; new];
-        // self.back = vec![Cell::Pixel(Pixel::DEFAULT); new];
-
-        self.front.resize(new, Cell::Empty);
-        self.front.fill(Cell::Empty);
-
-        self.back.resize(new, Cell::Pixel(Pixel::DEFAULT));
-        self.back.fill(Cell::Pixel(Pixel::DEFAULT));
-
-        // let old = self.size.x as usize * self.size.y as usize;
-        // let diff = old.saturating_sub(new.abs_diff(old));
+        let new = size.x.max(0) as usize * size.y.max(0) as usize;
+        let mut back = vec![Cell::Pixel(Pixel::DEFAULT); new];
 
-        // self.front.resize(new, Cell::Empty);
-        // self.back.resize(new, Cell::Pixel(Pixel::DEFAULT));
+        let overlap = self.size.min(size);
+        for y in 0..overlap.y {
+            for x in 0..overlap.x {
+                let cell = &mut self.back[Self::pos_to_index(pos2(x, y), self.size.x)];
+                // a wide cell that no longer fits would spill into the next row
+                if cell.width() > (overlap.x - x) as usize {
+                    continue;
+                }
+                back[Self::pos_to_index(pos2(x, y), size.x)] = std::mem::take(cell);
+            }
+        }
 
-        // if old != diff {
-        //     self.front[..diff].fill(Cell::Empty);
-        //     self.back[..diff].fill(Cell::Pixel(Pixel::DEFAULT));
-        // }
+        // the old front buffer no longer matches the screen, so everything gets rendered again
+        self.front.clear();
+        self.front.resize(new, Cell::Empty);
+        self.dirty.clear();
+        self.dirty.resize(size.y.max(0) as usize, true);
 
+        self.back = back;
         self.size = size;
     }
 
-    // TODO `force invalidate` (rather than a lazy invalidate)
     #[cfg_attr(feature = "profile", profiling::function)]
     pub fn render(&mut self, renderer: &mut impl Renderer) -> std::io::Result<()> {
+        self.render_at(renderer, Pos2::ZERO)
+    }
+
+    /// Render this surface with its top-left corner at `origin`, rather than at the top-left of the screen
+    ///
+    /// This lets the surface be drawn into a region of a screen that something else manages.
+    /// Every cursor movement is offset by `origin`, and the cursor is left at `origin` afterwards, rather than at the top-left of the screen.
+    ///
+    /// The changes are tracked the same way as [`Surface::render`], so this should be rendered at the same origin each time.
+    /// If the origin changes, [`mark_all_dirty`](Self::mark_all_dirty) to render it all again
+    ///
+    /// ```rust
+    /// # use too::{renderer::{Surface, Pixel, TermRenderer}, math::{pos2, vec2}};
+    /// let mut surface = Surface::new(vec2(3, 2));
+    /// surface.set(pos2(0, 0), Pixel::new('a'));
+    /// surface.set(pos2(2, 1), Pixel::new('b'));
+    ///
+    /// let mut out = Vec::new();
+    /// surface.render_at(&mut TermRenderer::new(&mut out), pos2(10, 5)).unwrap();
+    /// let out = String::from_utf8(out).unwrap();
+    ///
+    /// // terminals are 1-based
+    /// assert!(out.contains("\x1b[6;11;H"));
+    /// assert!(out.contains("\x1b[7;13;H"));
+    /// // and the cursor doesn't go back to the top-left of the screen
+    /// assert!(!out.contains("\x1b[1;1;H"));
+    /// ```
+    #[cfg_attr(feature = "profile", profiling::function)]
+    pub fn render_at(&mut self, renderer: &mut impl Renderer, origin: Pos2) -> std::io::Result<()> {
         let mut state = CursorState::default();
         let mut seen = false;
         let mut wrote_reset = false;
         let mut buf = [0u8; 4];
 
-        for (pos, change) in Self::diff(&mut self.front, &mut self.back, self.size.x) {
+        let (front, back, dirty) = (&mut self.front, &mut self.back, &mut self.dirty);
+        for (pos, change) in Self::diff(front, back, dirty, self.size.x) {
             if change.is_empty() || change.is_continuation() {
                 continue;
             }
@@ -174,16 +452,28 @@ impl Surface {
             }
 
             if state.maybe_move(pos, change.width() as i32) {
-                renderer.move_to(pos)?;
+                renderer.move_to(pos + origin.to_vec2())?;
+            }
+
+            let underline = change.underline();
+            if underline.is_none() && state.underline.take().is_some() {
+                renderer.reset_underline()?;
+                // this also clears a plain underline, so the attributes have to be written again
+                state.attr = None;
             }
 
             match state.maybe_attr(change.attribute()) {
                 Some(attr) if attr == Attribute::RESET => {
                     wrote_reset = true;
+                    state.underline = None;
                     renderer.reset_attr()?;
                 }
                 Some(attr) => {
                     wrote_reset = false;
+                    // a plain underline replaces the style, so it has to be written again
+                    if attr.is_underline() {
+                        state.underline = None;
+                    }
                     renderer.set_attr(attr)?;
                 }
                 _ => {}
@@ -201,8 +491,22 @@ impl Surface {
                 _ => {}
             }
 
+            if let Some(underline) = state.maybe_underline(underline) {
+                renderer.set_underline(underline)?;
+            }
+
             wrote_reset = false;
 
+            if let Some(link) = state.maybe_link(change) {
+                if state.link.is_some() {
+                    renderer.reset_hyperlink()?;
+                }
+                state.link = link;
+                if let Some(url) = &state.link {
+                    renderer.set_hyperlink(url)?;
+                }
+            }
+
             match change {
                 Cell::Grapheme(grapheme) => {
                     use unicode_width::UnicodeWidthStr as _;
@@ -223,11 +527,17 @@ impl Surface {
         }
 
         if seen {
+            if state.link.is_some() {
+                renderer.reset_hyperlink()?;
+            }
             if state.maybe_move(Pos2::ZERO, 0) {
-                renderer.move_to(Pos2::ZERO)?;
+                renderer.move_to(origin)?;
             }
             renderer.reset_bg()?;
             renderer.reset_fg()?;
+            if state.underline.is_some() {
+                renderer.reset_underline()?;
+            }
             renderer.reset_attr()?;
             renderer.end()?;
         }
@@ -238,19 +548,26 @@ impl Surface {
     fn diff<'a>(
         front: &'a mut [Cell],
         back: &'a mut [Cell],
+        dirty: &'a mut [bool],
         width: i32,
     ) -> impl Iterator<Item = (Pos2, &'a Cell)> {
-        front
-            .iter_mut()
-            .zip(back.iter_mut())
-            .enumerate()
-            .filter_map(move |(i, (front, back))| {
-                if front.is_same(back) {
-                    return None;
-                }
-                *front = back.clone();
-                // assert!(!matches!(*front, Cell::Empty));
-                Some((Self::index_to_pos(i, width), &*front))
+        let width = width.max(1) as usize;
+        (0..)
+            .zip(front.chunks_mut(width).zip(back.chunks_mut(width)))
+            .zip(dirty)
+            // rows that weren't touched can't have changed
+            .filter_map(|(row, dirty)| std::mem::take(dirty).then_some(row))
+            .flat_map(|(y, (front, back))| {
+                (0..).zip(front.iter_mut().zip(back.iter_mut())).filter_map(
+                    move |(x, (front, back))| {
+                        if front.is_same(back) {
+                            return None;
+                        }
+                        *front = back.clone();
+                        // assert!(!matches!(*front, Cell::Empty));
+                        Some((pos2(x, y), &*front))
+                    },
+                )
             })
     }
 
@@ -265,11 +582,6 @@ impl Surface {
     const fn pos_to_index(pos: Pos2, w: i32) -> usize {
         pos.y as usize * w as usize + pos.x as usize
     }
-
-    const fn index_to_pos(index: usize, w: i32) -> Pos2 {
-        let index = index as i32;
-        pos2(index % w, index / w)
-    }
 }
 
 #[derive(Default)]
@@ -278,9 +590,20 @@ struct CursorState {
     fg: Option<Color>,
     bg: Option<Color>,
     attr: Option<Attribute>,
+    underline: Option<Underline>,
+    link: Option<Arc<str>>,
 }
 
 impl CursorState {
+    // a cell without a link closes the one that is open, so it doesn't spill into its neighbors
+    fn maybe_link(&self, cell: &Cell) -> Option<Option<Arc<str>>> {
+        let link = match cell {
+            Cell::Grapheme(grapheme) => grapheme.link.as_ref(),
+            _ => None,
+        };
+        (link != self.link.as_ref()).then(|| link.cloned())
+    }
+
     fn maybe_move(&mut self, pos: Pos2, width: i32) -> bool {
         let should_move = match self.last {
             Some(last) if last.y != pos.y || last.x != pos.x - width => true,
@@ -320,6 +643,11 @@ impl CursorState {
         }
     }
 
+    fn maybe_underline(&mut self, underline: Option<Underline>) -> Option<Underline> {
+        let underline = underline?;
+        (self.underline.replace(underline) != Some(underline)).then_some(underline)
+    }
+
     fn maybe_attr(&mut self, attr: Attribute) -> Option<Attribute> {
         match (attr, self.attr) {
             (a, None) if a == Attribute::RESET => {
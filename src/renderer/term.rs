@@ -3,17 +3,120 @@ use std::io::Write;
 use super::Renderer;
 use crate::{
     math::Pos2,
-    renderer::{Attribute, Rgba},
+    renderer::{Attribute, Rgba, Underline},
 };
 
 /// Renders to a [`Backend`](crate::backend::Backend) using ANSI escape sequences
+///
+/// Each frame is wrapped in a synchronized update (`DEC 2026`), so terminals that support it present the whole frame at once, rather than as it arrives.
+/// Terminals that don't support it ignore the sequence.
 pub struct TermRenderer<W: Write> {
     out: W,
+    synchronized_output: bool,
+    styled_underlines: bool,
 }
 
 impl<W: Write> TermRenderer<W> {
     pub const fn new(out: W) -> Self {
-        Self { out }
+        Self {
+            out,
+            synchronized_output: true,
+            styled_underlines: true,
+        }
+    }
+
+    /// Should each frame be wrapped in a synchronized update?
+    ///
+    /// This is `true` by default
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2}, renderer::{Pixel, Surface, TermRenderer}};
+    /// let render = |synchronized| {
+    ///     let mut surface = Surface::new(vec2(3, 1));
+    ///     for x in 0..3 {
+    ///         surface.set(pos2(x, 0), Pixel::new('a'));
+    ///     }
+    ///     let mut out = Vec::new();
+    ///     surface.render(&mut TermRenderer::new(&mut out).synchronized_output(synchronized)).unwrap();
+    ///     String::from_utf8(out).unwrap()
+    /// };
+    ///
+    /// // the whole frame is wrapped once
+    /// let out = render(true);
+    /// assert!(out.starts_with("\x1b[?2026h"));
+    /// assert!(out.ends_with("\x1b[?2026l"));
+    /// assert_eq!(out.matches("\x1b[?2026h").count(), 1);
+    ///
+    /// let out = render(false);
+    /// assert!(!out.contains("\x1b[?2026"));
+    /// ```
+    pub const fn synchronized_output(mut self, synchronized_output: bool) -> Self {
+        self.synchronized_output = synchronized_output;
+        self
+    }
+
+    /// Should [`Underline`]s be written with their style and color?
+    ///
+    /// This is `true` by default. When `false`, they are written as a plain underline, for terminals that don't understand `SGR 4:N` and `SGR 58`
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2}, renderer::{Pixel, Rgba, Surface, TermRenderer, Underline, UnderlineStyle}};
+    /// let render = |styled| {
+    ///     let mut surface = Surface::new(vec2(1, 1));
+    ///     let squiggle = Underline::new(UnderlineStyle::Curly).color(Rgba::hex("#F00"));
+    ///     surface.set(pos2(0, 0), Pixel::new('a').underline(squiggle));
+    ///     let mut out = Vec::new();
+    ///     surface.render(&mut TermRenderer::new(&mut out).styled_underlines(styled)).unwrap();
+    ///     String::from_utf8(out).unwrap()
+    /// };
+    ///
+    /// assert!(render(true).contains("\x1b[4:3m"));
+    ///
+    /// let out = render(false);
+    /// assert!(out.contains("\x1b[4m"));
+    /// assert!(!out.contains("\x1b[4:"));
+    /// assert!(!out.contains("\x1b[58:"));
+    /// ```
+    pub const fn styled_underlines(mut self, styled_underlines: bool) -> Self {
+        self.styled_underlines = styled_underlines;
+        self
+    }
+
+    /// Get mutable access to the writer this renders to
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    /// The most base64 that is sent to the clipboard, terminals commonly drop longer `OSC 52` sequences
+    ///
+    /// Text that would encode to more than this is truncated, at a character boundary
+    pub const CLIPBOARD_LIMIT: usize = 100_000;
+
+    // the base64 is written a chunk at a time, rather than building the whole sequence up front
+    fn write_base64(&mut self, data: &[u8]) -> std::io::Result<()> {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        let mut buf = [0; 4096];
+        for chunk in data.chunks(buf.len() / 4 * 3) {
+            let mut len = 0;
+            for group in chunk.chunks(3) {
+                let n = group
+                    .iter()
+                    .enumerate()
+                    .fold(0_u32, |n, (i, &b)| n | (b as u32) << (16 - i * 8));
+                for i in 0..4 {
+                    buf[len + i] = if i <= group.len() {
+                        ALPHABET[(n >> (18 - i * 6)) as usize & 0x3F]
+                    } else {
+                        b'='
+                    };
+                }
+                len += 4;
+            }
+            self.out.write_all(&buf[..len])?;
+        }
+        Ok(())
     }
 }
 
@@ -26,13 +129,18 @@ macro_rules! csi {
 impl<W: Write> Renderer for TermRenderer<W> {
     #[inline(always)]
     fn begin(&mut self) -> std::io::Result<()> {
+        if !self.synchronized_output {
+            return Ok(());
+        }
         self.out.write_all(csi!("?2026h"))
     }
 
     #[inline(always)]
     #[cfg_attr(feature = "profile", profiling::function)]
     fn end(&mut self) -> std::io::Result<()> {
-        self.out.write_all(csi!("?2026l"))?;
+        if self.synchronized_output {
+            self.out.write_all(csi!("?2026l"))?;
+        }
         self.out.flush()
     }
 
@@ -44,35 +152,6 @@ impl<W: Write> Renderer for TermRenderer<W> {
         write!(self.out, "\x1b[{y};{x};H")
     }
 
-    // #[inline(always)]
-    // fn write_underline_color(
-    //     &mut self,
-    //     underline: super::Underline,
-    //     color: Rgba,
-    // ) -> std::io::Result<()> {
-    //     fn iter(data: u8) -> impl Iterator<Item = u8> {
-    //         let mut pos = 0;
-    //         std::iter::from_fn(move || loop {
-    //             if pos >= u8::BITS {
-    //                 return None;
-    //             }
-
-    //             let set = (data & (1 << pos)) != 0;
-    //             pos += 1;
-    //             if set {
-    //                 return Some(pos as _);
-    //             }
-    //         })
-    //     }
-
-    //     for mode in iter(underline.0) {
-    //         write!(self.out, "\x1b[4:{mode}m")?;
-    //     }
-
-    //     let Rgba(r, g, b, ..) = color;
-    //     write!(self.out, "\x1b[58:2::{r}:{g}:{b}m")
-    // }
-
     #[inline(always)]
     fn write_str(&mut self, data: &str) -> std::io::Result<()> {
         write!(self.out, "{data}")
@@ -135,10 +214,81 @@ impl<W: Write> Renderer for TermRenderer<W> {
         self.out.write_all(csi!("0m"))
     }
 
+    #[inline(always)]
+    fn set_underline(&mut self, underline: Underline) -> std::io::Result<()> {
+        if !self.styled_underlines {
+            return self.out.write_all(csi!("4m"));
+        }
+
+        write!(self.out, "\x1b[4:{}m", underline.style.code())?;
+        match underline.color {
+            Some(Rgba(r, g, b, ..)) => write!(self.out, "\x1b[58:2::{r}:{g}:{b}m"),
+            None => self.out.write_all(csi!("59m")),
+        }
+    }
+
+    #[inline(always)]
+    fn reset_underline(&mut self) -> std::io::Result<()> {
+        if !self.styled_underlines {
+            return self.out.write_all(csi!("24m"));
+        }
+        self.out.write_all(csi!("4:0m", "59m"))
+    }
+
+    /// Start an `OSC 8` hyperlink
+    ///
+    /// Control characters in the url are dropped, so it can't end the sequence early and write its own
+    ///
+    /// ```rust
+    /// # use too::renderer::{Renderer as _, TermRenderer};
+    /// let mut out = Vec::new();
+    /// TermRenderer::new(&mut out).set_hyperlink("https://example.com/\x1b\\\x1b[2J\x07").unwrap();
+    /// assert_eq!(out, b"\x1b]8;;https://example.com/\\[2J\x1b\\");
+    /// ```
+    fn set_hyperlink(&mut self, url: &str) -> std::io::Result<()> {
+        self.out.write_all(b"\x1b]8;;")?;
+        for part in url.split(char::is_control) {
+            self.out.write_all(part.as_bytes())?;
+        }
+        self.out.write_all(b"\x1b\\")
+    }
+
+    fn reset_hyperlink(&mut self) -> std::io::Result<()> {
+        self.out.write_all(b"\x1b]8;;\x1b\\")
+    }
+
     fn set_title(&mut self, title: &str) -> std::io::Result<()> {
         write!(self.out, "\x1b]2;{title}\x07")
     }
 
+    /// Put this text on the clipboard with `OSC 52`, which also works over ssh
+    ///
+    /// See [`TermRenderer::CLIPBOARD_LIMIT`] for how much text can be sent
+    ///
+    /// ```rust
+    /// # use too::renderer::{Renderer as _, TermRenderer};
+    /// let mut out = Vec::new();
+    /// TermRenderer::new(&mut out).set_clipboard("hello").unwrap();
+    /// assert_eq!(out, b"\x1b]52;c;aGVsbG8=\x07");
+    ///
+    /// let mut out = Vec::new();
+    /// let text = "é".repeat(TermRenderer::<Vec<u8>>::CLIPBOARD_LIMIT);
+    /// TermRenderer::new(&mut out).set_clipboard(&text).unwrap();
+    /// assert!(out.len() <= TermRenderer::<Vec<u8>>::CLIPBOARD_LIMIT + b"\x1b]52;c;\x07".len());
+    /// ```
+    fn set_clipboard(&mut self, data: &str) -> std::io::Result<()> {
+        // every 3 bytes become 4 characters
+        let mut end = data.len().min(Self::CLIPBOARD_LIMIT / 4 * 3);
+        while !data.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.out.write_all(b"\x1b]52;c;")?;
+        self.write_base64(&data.as_bytes()[..end])?;
+        self.out.write_all(b"\x07")?;
+        self.out.flush()
+    }
+
     fn switch_to_alt_screen(&mut self) -> std::io::Result<()> {
         self.out.write_all(csi!("?1049h"))?;
         self.out.flush()
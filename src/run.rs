@@ -4,7 +4,7 @@
 use crate::{
     animation::Animations,
     layout::Anchor2,
-    view::{DebugMode, Palette},
+    view::{ClipboardHandle, DebugMode, Palette},
 };
 
 /// Configuration for an [`application`]
@@ -16,9 +16,15 @@ use crate::{
 /// | [`debug`](Self::debug) | [`DebugMode::PerFrame`] |
 /// | [`debug_anchor`](Self::debug_anchor) | [`Anchor2::RIGHT_TOP`] |
 /// | [`fps`](Self::fps) | `60.0` (e.g. 60 fps) |
+/// | [`fixed_ups`](Self::fixed_ups) | `None` |
+/// | [`repaint_mode`](Self::repaint_mode) | [`RepaintMode::Continuous`] |
 /// | [`ctrl_c_quits`](Self::ctrl_c_quits) | `true` |
 /// | [`ctrl_z_switches`](Self::ctrl_z_switches) | `false` |
 /// | [`hook_panics`](Self::hook_panics) | `false` |
+/// | [`coalesce_resize`](Self::coalesce_resize) | `true` |
+/// | [`synchronized_output`](Self::synchronized_output) | `true` |
+/// | [`drag_threshold`](Self::drag_threshold) | `1` |
+/// | [`clipboard`](Self::clipboard) | [`NoClipboard`](crate::backend::NoClipboard) |
 
 pub struct RunConfig {
     /// The palette to initially use
@@ -39,8 +45,23 @@ pub struct RunConfig {
     ///
     /// Default: `60.0` (e.g. 60 fps)
     pub fps: f32,
+    /// Run at a fixed rate, with a constant frame delta
+    ///
+    /// When set, this replaces [`fps`](Self::fps) and every update advances animations by exactly `1.0 / fixed_ups` seconds,
+    /// regardless of how long the frame actually took. This gives deterministic timing, e.g. for recording demos.
+    ///
+    /// Frames that run late are not caught up, the application just runs slower than real time until it recovers.
+    ///
+    /// Default: `None`
+    pub fixed_ups: Option<f32>,
+    /// When the application should run a frame
+    ///
+    /// Default: [`RepaintMode::Continuous`]
+    pub repaint_mode: RepaintMode,
     /// Should pressing Ctrl-C quit the application?
     ///
+    /// When this is enabled, Ctrl-C never reaches the application, so a [`TextInput`](crate::views::TextInput) can't use it to copy.
+    ///
     /// Default: `true`
     pub ctrl_c_quits: bool,
     /// Should pressing Ctrl-Z switch to the non-displayed screen?
@@ -51,6 +72,31 @@ pub struct RunConfig {
     ///
     /// Default: `false`
     pub hook_panics: bool,
+    /// Should consecutive resize events be merged into the last one?
+    ///
+    /// Resizing a terminal quickly produces a lot of resize events. When this is enabled, a run of them is only applied once, with the final size.
+    /// Any other event between two resizes still sees the size from before it, and is handled in order.
+    ///
+    /// Default: `true`
+    pub coalesce_resize: bool,
+    /// Should each frame be drawn as a single synchronized update, to prevent tearing?
+    ///
+    /// See [`Config::synchronized_output`](crate::term::Config::synchronized_output)
+    ///
+    /// Default: `true`
+    pub synchronized_output: bool,
+    /// How far, in cells, the mouse has to move while a button is held before it starts a drag
+    ///
+    /// See [`State::set_drag_threshold`](crate::view::State::set_drag_threshold)
+    ///
+    /// Default: `1`
+    pub drag_threshold: u32,
+    /// The clipboard views copy to and paste from
+    ///
+    /// Default: [`NoClipboard`](crate::backend::NoClipboard)
+    ///
+    /// With the `clipboard` feature, `ClipboardHandle::new(SystemClipboard::new())` uses the system clipboard
+    pub clipboard: ClipboardHandle,
 }
 
 impl Default for RunConfig {
@@ -61,13 +107,32 @@ impl Default for RunConfig {
             debug_anchor: Anchor2::RIGHT_TOP,
             animation: Animations::default(),
             fps: 60.0,
+            fixed_ups: None,
+            repaint_mode: RepaintMode::Continuous,
             ctrl_c_quits: true,
             ctrl_z_switches: false,
             hook_panics: false,
+            coalesce_resize: true,
+            synchronized_output: true,
+            drag_threshold: 1,
+            clipboard: ClipboardHandle::default(),
         }
     }
 }
 
+/// When the event loop runs a frame
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RepaintMode {
+    /// Run a frame at the [`fps`](RunConfig::fps), even if nothing happened
+    #[default]
+    Continuous,
+    /// Wait for an event before running a frame, unless something is [animating](crate::view::State::is_animating)
+    ///
+    /// While something is animating, frames run at the [`fps`](RunConfig::fps). Otherwise the loop blocks until an event arrives,
+    /// but it still wakes up once a second, so changes that didn't come from an event are eventually shown.
+    Reactive,
+}
+
 // TODO more description on how the closure can be used
 /// Run an application with the default [`RunConfig`]
 ///
@@ -85,6 +150,58 @@ pub fn application<R: 'static>(
     config: RunConfig,
     mut app: impl FnMut(&crate::view::Ui) -> R,
 ) -> std::io::Result<()> {
+    event_loop(config, &mut app, |app, ui| app(ui), |_| ())
+}
+
+/// Run an application with the provided [`RunConfig`], and some state it is built from
+///
+/// When the application quits, `on_quit` is called once with the state, and what it returns is returned from this.
+/// This is a good place to save the state, or to pull a result out of it.
+///
+/// The terminal is reset before `on_quit` is called, so it can print, and a panic in it doesn't leave the terminal in a bad state.
+/// It isn't called if the application exits with an error.
+///
+/// This will block the current thread until the application exits.
+///
+/// ```no_run
+/// # use too::RunConfig;
+/// #[derive(Default)]
+/// struct App {
+///     clicks: usize,
+/// }
+///
+/// fn main() -> std::io::Result<()> {
+///     let clicks = too::application_with(
+///         RunConfig::default(),
+///         App::default(),
+///         |app, ui| {
+///             if ui.button("click me").clicked() {
+///                 app.clicks += 1;
+///             }
+///         },
+///         |app| app.clicks,
+///     )?;
+///     println!("clicked {clicks} times");
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "terminal")]
+pub fn application_with<T, R: 'static, Q>(
+    config: RunConfig,
+    mut state: T,
+    app: impl FnMut(&mut T, &crate::view::Ui) -> R,
+    on_quit: impl FnOnce(&mut T) -> Q,
+) -> std::io::Result<Q> {
+    event_loop(config, &mut state, app, on_quit)
+}
+
+#[cfg(feature = "terminal")]
+fn event_loop<T, R: 'static, Q>(
+    config: RunConfig,
+    app_state: &mut T,
+    mut app: impl FnMut(&mut T, &crate::view::Ui) -> R,
+    on_quit: impl FnOnce(&mut T) -> Q,
+) -> std::io::Result<Q> {
     use std::time::{Duration, Instant};
 
     use crate::{
@@ -98,18 +215,27 @@ pub fn application<R: 'static>(
         TermConfig::default()
             .hook_panics(config.hook_panics)
             .ctrl_c_quits(config.ctrl_c_quits)
-            .ctrl_z_switches(config.ctrl_z_switches),
+            .ctrl_z_switches(config.ctrl_z_switches)
+            .synchronized_output(config.synchronized_output),
     )?;
     let mut surface = Surface::new(term.size());
 
     let mut state = State::new(config.palette, config.animation);
+    state.set_clipboard(config.clipboard);
+    state.set_drag_threshold(config.drag_threshold);
     Debug::set_debug_mode(config.debug);
     Debug::set_debug_anchor(config.debug_anchor);
 
-    let target = Duration::from_secs_f32(1.0 / config.fps.max(1.0));
+    let fixed = config.fixed_ups.map(|ups| 1.0 / ups.max(1.0));
+    let target = Duration::from_secs_f32(fixed.unwrap_or(1.0 / config.fps.max(1.0)));
     let max_budget = (target / 2).max(Duration::from_millis(1));
 
+    // how long a reactive application waits for an event, before it runs a frame anyway
+    const IDLE_WAKE: Duration = Duration::from_secs(1);
+    let reactive = matches!(config.repaint_mode, RepaintMode::Reactive);
+
     let mut prev = Instant::now();
+    let mut first = true;
 
     'outer: loop {
         #[cfg(feature = "profile")]
@@ -118,21 +244,38 @@ pub fn application<R: 'static>(
         }
 
         let mut should_render = false;
-        let mut last_resize = None;
+        let mut pending_resize = None;
+
+        // the first frame always has to be drawn
+        let idle = reactive && !first && !state.is_animating();
+        first = false;
+        let mut next = None;
+        if idle {
+            // time spent waiting for an event isn't a frame, so its not part of the next delta
+            let waiting = Instant::now();
+            next = term.wait_for_event(IDLE_WAKE);
+            prev += waiting.elapsed();
+        }
 
         let start = Instant::now();
-        while let Some(ev) = term.try_read_event() {
+        while start.elapsed() < max_budget {
+            let Some(ev) = next.take().or_else(|| term.try_read_event()) else {
+                break;
+            };
+
             if ev.is_quit() {
                 break 'outer;
             }
 
-            if start.elapsed() >= max_budget {
-                break;
-            }
-
             if let Event::Resize(size) = ev {
-                last_resize = Some(size);
-                continue;
+                if config.coalesce_resize {
+                    pending_resize = Some(size);
+                    continue;
+                }
+            } else if let Some(size) = pending_resize.take() {
+                // the resize has to happen first, so this event sees the new size
+                surface.resize(size);
+                state.event(&Event::Resize(size));
             }
 
             surface.update(&ev);
@@ -140,17 +283,19 @@ pub fn application<R: 'static>(
             should_render = true;
         }
 
-        if let Some(size) = last_resize {
-            let ev = Event::Resize(size);
-            surface.update(&ev);
-            state.event(&ev);
+        if let Some(size) = pending_resize {
+            surface.resize(size);
+            state.event(&Event::Resize(size));
             should_render = true;
         }
 
         let now = Instant::now();
         let dt = prev.elapsed();
-        state.update(dt.as_secs_f32());
-        state.build(surface.rect(), |ui| app(ui));
+        state.update(fixed.unwrap_or(dt.as_secs_f32()));
+        state.build(surface.rect(), |ui| app(app_state, ui));
+        for command in state.take_commands() {
+            term.command(command);
+        }
 
         if should_render || dt >= target {
             let mut rasterizer = CroppedSurface {
@@ -168,5 +313,7 @@ pub fn application<R: 'static>(
         }
     }
 
-    Ok(())
+    // the terminal is reset first, so the hook runs in a normal terminal
+    drop(term);
+    Ok(on_quit(app_state))
 }
@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::{borrow::Borrow, ops::Deref};
 
 use compact_str::{CompactString, ToCompactString};
 
@@ -16,10 +16,42 @@ pub struct Str(CompactString);
 
 impl Str {
     /// Create a new [`Str`] at compile time
+    ///
+    /// This borrows the static string, rather than copying it
     pub const fn new(str: &'static str) -> Self {
         Self(CompactString::const_new(str))
     }
 
+    /// Concatenate these parts into a single [`Str`], with one allocation (if any)
+    ///
+    /// ```rust
+    /// # use too::Str;
+    /// let count = 42.to_string();
+    /// let label = Str::joined(&["count: ", &count]);
+    /// assert_eq!(label, "count: 42");
+    /// assert_eq!(label, Str::from("count: 42"));
+    ///
+    /// // it hashes like a `str`, so it can be looked up with one
+    /// let labels = std::collections::HashSet::from([label]);
+    /// assert!(labels.contains("count: 42"));
+    /// ```
+    pub fn joined(parts: &[&str]) -> Self {
+        let len = parts.iter().map(|part| part.len()).sum();
+        let mut out = CompactString::with_capacity(len);
+        for part in parts {
+            out.push_str(part);
+        }
+        Self(out)
+    }
+
+    /// Get this as a string slice
+    ///
+    /// [`Str`] can't implement [`Display`](std::fmt::Display) (anything that does can already be turned into one),
+    /// so use this (or deref it) to display it
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
     /// Turn this [`Str`] into a [`CompactString`]
     pub fn into_inner(self) -> CompactString {
         self.0
@@ -32,6 +64,31 @@ impl AsRef<str> for Str {
     }
 }
 
+// this is the same as the hash and equality of a `str`, so a `Str` can be looked up with one
+impl Borrow<str> for Str {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq<str> for Str {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Str {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<Str> for CompactString {
+    fn from(value: Str) -> Self {
+        value.0
+    }
+}
+
 impl Deref for Str {
     type Target = str;
     fn deref(&self) -> &Self::Target {
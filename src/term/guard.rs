@@ -0,0 +1,231 @@
+use std::io::{Stdout, Write};
+
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
+    terminal::{DisableLineWrap, EnableLineWrap, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use super::Config;
+
+/// Terminal features that a [`TerminalGuard`] can toggle
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Features(pub u8);
+
+impl Features {
+    pub const NONE: Self = Self(0);
+    pub const RAW_MODE: Self = Self(1 << 0);
+    pub const ALT_SCREEN: Self = Self(1 << 1);
+    pub const LINE_WRAP_DISABLED: Self = Self(1 << 2);
+    pub const CURSOR_HIDDEN: Self = Self(1 << 3);
+    pub const MOUSE_CAPTURE: Self = Self(1 << 4);
+    pub const BRACKETED_PASTE: Self = Self(1 << 5);
+    pub const FOCUS_CHANGE: Self = Self(1 << 6);
+    pub const KEYBOARD_ENHANCEMENT: Self = Self(1 << 7);
+}
+
+impl Features {
+    pub const fn is_none(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn remove(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl std::ops::BitOr for Features {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+impl std::ops::BitOrAssign for Features {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs
+    }
+}
+
+impl std::fmt::Debug for Features {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const FIELDS: [&str; 8] = [
+            "RAW_MODE",
+            "ALT_SCREEN",
+            "LINE_WRAP_DISABLED",
+            "CURSOR_HIDDEN",
+            "MOUSE_CAPTURE",
+            "BRACKETED_PASTE",
+            "FOCUS_CHANGE",
+            "KEYBOARD_ENHANCEMENT",
+        ];
+
+        let mut seen = false;
+        for (flag, repr) in (0..).zip(FIELDS) {
+            if (self.0 >> flag) & 1 == 1 {
+                if seen {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(repr)?;
+                seen |= true
+            }
+        }
+
+        if !seen {
+            f.write_str("NONE")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A guard that configures the terminal, and restores it when dropped
+///
+/// Only the [`Features`] that were actually enabled get restored, so if setting up fails midway the terminal is put back the way it was.
+///
+/// ```rust
+/// # use too::term::{Config, Features, TerminalGuard};
+/// let mut out = Vec::new();
+/// {
+///     // this doesn't touch raw mode, so it can be used without a terminal
+///     let guard = TerminalGuard::headless(Config::default(), &mut out).unwrap();
+///     assert!(guard.enabled().contains(Features::ALT_SCREEN | Features::MOUSE_CAPTURE));
+///     assert!(!guard.enabled().contains(Features::RAW_MODE));
+/// }
+///
+/// let out = String::from_utf8(out).unwrap();
+/// assert!(out.contains("\x1b[?1049h")); // entered the alt screen
+/// assert!(out.contains("\x1b[?1049l")); // and left it
+/// assert!(out.contains("\x1b[?2004h")); // enabled bracketed paste
+/// assert!(out.contains("\x1b[?2004l")); // and disabled it
+/// assert!(out.contains("\x1b[?1004h")); // enabled focus reporting
+/// assert!(out.contains("\x1b[?1004l")); // and disabled it
+/// assert!(out.ends_with("\x1b[?25h")); // the cursor was shown again
+/// ```
+pub struct TerminalGuard<W: Write = Stdout> {
+    out: W,
+    enabled: Features,
+}
+
+impl TerminalGuard {
+    /// Configure the terminal on `stdout`, enabling raw mode
+    pub fn new(config: Config) -> std::io::Result<Self> {
+        Self::apply(config, std::io::stdout(), true)
+    }
+}
+
+impl<W: Write> TerminalGuard<W> {
+    /// Configure the terminal on this writer, without enabling raw mode
+    pub fn headless(config: Config, out: W) -> std::io::Result<Self> {
+        Self::apply(config, out, false)
+    }
+
+    /// The features this guard has enabled, and will restore
+    pub const fn enabled(&self) -> Features {
+        self.enabled
+    }
+
+    /// Restore everything this guard enabled
+    ///
+    /// This is done automatically when the guard is dropped
+    pub fn restore(&mut self) -> std::io::Result<()> {
+        let enabled = std::mem::take(&mut self.enabled);
+        let out = &mut self.out;
+
+        if enabled.contains(Features::KEYBOARD_ENHANCEMENT) {
+            crossterm::execute!(out, PopKeyboardEnhancementFlags)?;
+        }
+
+        if enabled.contains(Features::FOCUS_CHANGE) {
+            crossterm::execute!(out, DisableFocusChange)?;
+        }
+
+        if enabled.contains(Features::BRACKETED_PASTE) {
+            crossterm::execute!(out, DisableBracketedPaste)?;
+        }
+
+        if enabled.contains(Features::MOUSE_CAPTURE) {
+            crossterm::execute!(out, DisableMouseCapture)?;
+        }
+
+        if enabled.contains(Features::ALT_SCREEN) {
+            crossterm::execute!(out, LeaveAlternateScreen)?;
+        }
+
+        if enabled.contains(Features::LINE_WRAP_DISABLED) {
+            crossterm::execute!(out, EnableLineWrap)?;
+        }
+
+        if enabled.contains(Features::CURSOR_HIDDEN) {
+            crossterm::execute!(out, Show)?;
+        }
+
+        if enabled.contains(Features::RAW_MODE) {
+            crossterm::terminal::disable_raw_mode()?;
+        }
+
+        Ok(())
+    }
+
+    fn apply(config: Config, out: W, raw_mode: bool) -> std::io::Result<Self> {
+        // if any of these fail, the guard is dropped and restores what was enabled so far
+        let mut this = Self {
+            out,
+            enabled: Features::NONE,
+        };
+
+        if raw_mode {
+            crossterm::terminal::enable_raw_mode()?;
+            this.enabled |= Features::RAW_MODE;
+        }
+
+        if config.use_alt_screen {
+            crossterm::execute!(&mut this.out, EnterAlternateScreen)?;
+            this.enabled |= Features::ALT_SCREEN;
+        }
+
+        crossterm::execute!(&mut this.out, DisableLineWrap)?;
+        this.enabled |= Features::LINE_WRAP_DISABLED;
+
+        if config.hide_cursor {
+            crossterm::execute!(&mut this.out, Hide)?;
+            this.enabled |= Features::CURSOR_HIDDEN;
+        }
+
+        if config.mouse_capture {
+            crossterm::execute!(&mut this.out, EnableMouseCapture)?;
+            this.enabled |= Features::MOUSE_CAPTURE;
+        }
+
+        if config.bracketed_paste {
+            crossterm::execute!(&mut this.out, EnableBracketedPaste)?;
+            this.enabled |= Features::BRACKETED_PASTE;
+        }
+
+        if config.focus_change {
+            crossterm::execute!(&mut this.out, EnableFocusChange)?;
+            this.enabled |= Features::FOCUS_CHANGE;
+        }
+
+        if config.keyboard_enhancement {
+            let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES;
+            crossterm::execute!(&mut this.out, PushKeyboardEnhancementFlags(flags))?;
+            this.enabled |= Features::KEYBOARD_ENHANCEMENT;
+        }
+
+        Ok(this)
+    }
+}
+
+impl<W: Write> Drop for TerminalGuard<W> {
+    fn drop(&mut self) {
+        _ = self.restore();
+    }
+}
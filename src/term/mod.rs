@@ -8,11 +8,16 @@ use std::{
 };
 
 use crossterm::{
-    cursor::{Hide, Show},
-    event::{DisableMouseCapture, EnableMouseCapture},
-    terminal::{DisableLineWrap, EnableLineWrap, EnterAlternateScreen, LeaveAlternateScreen},
+    cursor::Show,
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, PopKeyboardEnhancementFlags,
+    },
+    terminal::{EnableLineWrap, LeaveAlternateScreen},
 };
 
+mod guard;
+pub use guard::{Features, TerminalGuard};
+
 use crate::{
     backend::{Backend, Command, CurrentScreen, Event, EventReader},
     backend::{Key, Keybind, Modifiers, MouseButton},
@@ -32,6 +37,11 @@ use crate::{
 /// |[`ctrl_z_switches`](Self::ctrl_z_switches)|false|
 /// |[`use_alt_screen`](Self::use_alt_screen)|true|
 /// |[`hook_panics`](Self::hook_panics)|false|
+/// |[`bracketed_paste`](Self::bracketed_paste)|true|
+/// |[`focus_change`](Self::focus_change)|true|
+/// |[`keyboard_enhancement`](Self::keyboard_enhancement)|false|
+/// |[`synchronized_output`](Self::synchronized_output)|true|
+/// |[`styled_underlines`](Self::styled_underlines)|true|
 ///
 /// # When using [`too`](https://crates.io/too)
 /// You'll likely want to keep most of the defaults.
@@ -48,6 +58,11 @@ pub struct Config {
     pub(crate) ctrl_z_switches: bool,
     pub(crate) use_alt_screen: bool,
     pub(crate) hook_panics: bool,
+    pub(crate) bracketed_paste: bool,
+    pub(crate) focus_change: bool,
+    pub(crate) keyboard_enhancement: bool,
+    pub(crate) synchronized_output: bool,
+    pub(crate) styled_underlines: bool,
 
     current_screen: CurrentScreen,
 }
@@ -66,6 +81,8 @@ impl Config {
     }
 
     /// Should pressing `Ctrl-C` signal a quit?
+    ///
+    /// When this is enabled, the key is never sent as an event, so a [`TextInput`](crate::views::TextInput) can't use it to copy
     pub fn ctrl_c_quits(mut self, ctrl_c_quits: bool) -> Self {
         self.ctrl_c_quits = ctrl_c_quits;
         self
@@ -89,6 +106,45 @@ impl Config {
         self.hook_panics = hook_panics;
         self
     }
+
+    /// Should pasted text arrive as a single [`Event::Paste`], rather than as a key press per character?
+    pub fn bracketed_paste(mut self, bracketed_paste: bool) -> Self {
+        self.bracketed_paste = bracketed_paste;
+        self
+    }
+
+    /// Should the terminal report when its window gains or loses focus?
+    ///
+    /// These arrive as [`Event::FocusGained`] and [`Event::FocusLost`], and are what [`is_window_focused`](crate::view::EventCtx::is_window_focused) is based on
+    pub fn focus_change(mut self, focus_change: bool) -> Self {
+        self.focus_change = focus_change;
+        self
+    }
+
+    /// Should the terminal report keys with the [kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/)?
+    ///
+    /// This is what lets terminals report [`Modifiers::SUPER`] and [`Modifiers::META`], without it they can't be pressed.
+    /// Terminals that don't support it ignore it, but the legacy Windows console fails to set up with it enabled
+    pub fn keyboard_enhancement(mut self, keyboard_enhancement: bool) -> Self {
+        self.keyboard_enhancement = keyboard_enhancement;
+        self
+    }
+
+    /// Should each frame be drawn as a single synchronized update, to prevent tearing?
+    ///
+    /// This is skipped for terminals that are known to not support it (e.g. `TERM=dumb` or the linux console)
+    pub fn synchronized_output(mut self, synchronized_output: bool) -> Self {
+        self.synchronized_output = synchronized_output;
+        self
+    }
+
+    /// Should underlines be drawn with their [style and color](crate::renderer::Underline)?
+    ///
+    /// When this is `false`, or the terminal is known to not support them (e.g. `TERM=dumb` or the linux console), a plain underline is drawn instead
+    pub fn styled_underlines(mut self, styled_underlines: bool) -> Self {
+        self.styled_underlines = styled_underlines;
+        self
+    }
 }
 
 impl Default for Config {
@@ -100,6 +156,11 @@ impl Default for Config {
             ctrl_z_switches: false,
             use_alt_screen: true,
             hook_panics: false,
+            bracketed_paste: true,
+            focus_change: true,
+            keyboard_enhancement: false,
+            synchronized_output: true,
+            styled_underlines: true,
             current_screen: CurrentScreen::Alt,
         }
     }
@@ -112,9 +173,25 @@ struct Output {
 }
 
 impl Output {
-    fn resize(&mut self, hint: usize) {
-        let fd = self.out.get_ref().try_clone().unwrap();
-        let _ = std::mem::replace(&mut self.out, BufWriter::with_capacity(hint * 21, fd));
+    // roughly how many bytes a cell takes, with its colors and attributes
+    const BYTES_PER_CELL: usize = 21;
+
+    // the buffer only grows, so resizing back and forth doesn't keep reallocating it
+    fn resize(&mut self, cells: usize) {
+        let capacity = cells.saturating_mul(Self::BYTES_PER_CELL);
+        if capacity <= self.out.capacity() {
+            return;
+        }
+
+        // anything still buffered has to be written before the buffer is replaced.
+        // if it can't be, the old buffer is kept so nothing is lost
+        if std::io::Write::flush(&mut self.out).is_err() {
+            return;
+        }
+        let Ok(fd) = self.out.get_ref().try_clone() else {
+            return;
+        };
+        self.out = BufWriter::with_capacity(capacity, fd);
     }
 }
 
@@ -164,6 +241,9 @@ impl Output {
 }
 
 /// A terminal handle
+///
+/// Everything written to the terminal is buffered. A frame from [`Backend::writer`] is flushed once, when it [ends](crate::renderer::Renderer::end),
+/// and anything written directly through [`std::io::Write`] is sent when you [`flush`](std::io::Write::flush) it
 pub struct Term {
     _handle: JoinHandle<()>,
     events: flume::Receiver<Event>,
@@ -172,26 +252,13 @@ pub struct Term {
     _stdout: std::io::StdoutLock<'static>,
     size: Vec2,
     commands: VecDeque<Command>,
+    _guard: TerminalGuard,
 }
 
 impl Term {
     pub fn setup(config: Config) -> std::io::Result<Self> {
-        let mut out = std::io::stdout();
-        crossterm::terminal::enable_raw_mode()?;
-
-        if config.use_alt_screen {
-            crossterm::execute!(&mut out, EnterAlternateScreen)?;
-        }
-
-        crossterm::execute!(&mut out, DisableLineWrap)?;
-
-        if config.hide_cursor {
-            crossterm::execute!(&mut out, Hide)?;
-        }
-
-        if config.mouse_capture {
-            crossterm::execute!(&mut out, EnableMouseCapture)?;
-        }
+        let out = std::io::stdout();
+        let guard = TerminalGuard::new(config)?;
 
         let size = crossterm::terminal::size().map(|(w, h)| vec2(w as _, h as _))?;
 
@@ -199,18 +266,42 @@ impl Term {
             Self::init_panic_hook();
         }
 
+        let mut config = config;
+        config.synchronized_output &= Self::supports_synchronized_output();
+        config.styled_underlines &= Self::supports_styled_underlines();
+
+        let mut output = Output::new(out.lock());
+        output.resize(size.x as usize * size.y as usize);
+
         let (tx, events) = flume::unbounded();
         Ok(Self {
             _handle: std::thread::spawn(move || read_event(tx)),
             events,
             config,
-            output: Output::new(out.lock()),
+            output,
             _stdout: out.lock(),
             size,
             commands: VecDeque::new(),
+            _guard: guard,
         })
     }
 
+    fn supports_synchronized_output() -> bool {
+        !Self::is_legacy_terminal()
+    }
+
+    fn supports_styled_underlines() -> bool {
+        !Self::is_legacy_terminal()
+    }
+
+    // there isn't a way to ask the terminal without racing the event reader, so this just rules out the ones known to not support newer sequences
+    fn is_legacy_terminal() -> bool {
+        matches!(
+            std::env::var("TERM").as_deref(),
+            Ok("dumb" | "linux" | "vt100" | "vt220")
+        )
+    }
+
     fn resize(&mut self, size: Vec2) {
         self.size = size;
         self.output.resize(size.x as usize * size.y as usize);
@@ -219,10 +310,15 @@ impl Term {
     pub fn reset() -> std::io::Result<()> {
         let mut out = std::io::stdout();
 
+        // this isn't supported everywhere, so it can't stop the others
+        _ = crossterm::execute!(&mut out, PopKeyboardEnhancementFlags);
+
         // always do these
         crossterm::execute!(&mut out, LeaveAlternateScreen)?;
         crossterm::execute!(&mut out, EnableLineWrap)?;
         crossterm::execute!(&mut out, DisableMouseCapture)?;
+        crossterm::execute!(&mut out, DisableBracketedPaste)?;
+        crossterm::execute!(&mut out, DisableFocusChange)?;
         crossterm::execute!(&mut out, Show)?;
 
         crossterm::terminal::disable_raw_mode()
@@ -254,6 +350,8 @@ impl Backend for Term {
 
     fn writer(&mut self) -> Self::Renderer<'_> {
         TermRenderer::new(&mut self.output.out)
+            .synchronized_output(self.config.synchronized_output)
+            .styled_underlines(self.config.styled_underlines)
     }
 }
 
@@ -271,6 +369,17 @@ impl std::io::Write for Term {
 
 impl EventReader for Term {
     fn try_read_event(&mut self) -> Option<Event> {
+        self.next_event(None)
+    }
+
+    fn wait_for_event(&mut self, timeout: std::time::Duration) -> Option<Event> {
+        self.next_event(Some(timeout))
+    }
+}
+
+impl Term {
+    // commands are applied first, then this waits for an event if there is a timeout
+    fn next_event(&mut self, timeout: Option<std::time::Duration>) -> Option<Event> {
         const CTRL_C: Keybind = Keybind::from_char('c').ctrl();
         const CTRL_Z: Keybind = Keybind::from_char('z').ctrl();
 
@@ -292,6 +401,9 @@ impl EventReader for Term {
                     inplace.replace(Event::SwitchAltScreen);
                 }
                 Command::RequestQuit => return Some(Event::Quit),
+                Command::SetClipboard(data) => {
+                    let _ = TermRenderer::new(&mut *self).set_clipboard(&data);
+                }
                 _ => {}
             }
         }
@@ -300,10 +412,17 @@ impl EventReader for Term {
             return Some(ev);
         }
 
-        let ev = match self.events.try_recv() {
-            Ok(ev) => ev,
-            Err(flume::TryRecvError::Disconnected) => return Some(Event::Quit),
-            _ => return None,
+        let ev = match timeout {
+            Some(timeout) => match self.events.recv_timeout(timeout) {
+                Ok(ev) => ev,
+                Err(flume::RecvTimeoutError::Disconnected) => return Some(Event::Quit),
+                Err(flume::RecvTimeoutError::Timeout) => return None,
+            },
+            None => match self.events.try_recv() {
+                Ok(ev) => ev,
+                Err(flume::TryRecvError::Disconnected) => return Some(Event::Quit),
+                Err(flume::TryRecvError::Empty) => return None,
+            },
         };
 
         if let Event::Resize(size) = ev {
@@ -333,12 +452,6 @@ impl EventReader for Term {
     }
 }
 
-impl Drop for Term {
-    fn drop(&mut self) {
-        _ = Self::reset();
-    }
-}
-
 fn translate(ev: crossterm::event::Event) -> Option<Event> {
     use crossterm::event::{Event as E, KeyCode as K, KeyEventKind, MouseEventKind as M};
 
@@ -369,7 +482,16 @@ fn translate(ev: crossterm::event::Event) -> Option<Event> {
                     modifiers |= Modifiers::SHIFT;
                     Key::Tab
                 }
-                _ => return None,
+                K::Null => Key::Null,
+                K::CapsLock => Key::CapsLock,
+                K::ScrollLock => Key::ScrollLock,
+                K::NumLock => Key::NumLock,
+                K::PrintScreen => Key::PrintScreen,
+                K::Pause => Key::Pause,
+                K::Menu => Key::Menu,
+                K::KeypadBegin => Key::KeypadBegin,
+                // these aren't supported. this runs on the event thread, so it can't report them with `view::debug`
+                K::Media(..) | K::Modifier(..) => return None,
             };
 
             match ev.kind {
@@ -400,25 +522,14 @@ fn translate(ev: crossterm::event::Event) -> Option<Event> {
                     modifiers,
                 },
                 M::Moved => Event::MouseMove { pos },
-                M::ScrollDown => Event::MouseScroll {
-                    delta: vec2(0, 1),
-                    modifiers,
-                },
-                M::ScrollUp => Event::MouseScroll {
-                    delta: vec2(0, -1),
-                    modifiers,
-                },
-                M::ScrollLeft => Event::MouseScroll {
-                    delta: vec2(-1, 0),
-                    modifiers,
-                },
-                M::ScrollRight => Event::MouseScroll {
-                    delta: vec2(1, 0),
-                    modifiers,
-                },
+                M::ScrollDown => translate_scroll(vec2(0, 1), modifiers),
+                M::ScrollUp => translate_scroll(vec2(0, -1), modifiers),
+                M::ScrollLeft => translate_scroll(vec2(-1, 0), modifiers),
+                M::ScrollRight => translate_scroll(vec2(1, 0), modifiers),
             }
         }
         E::Resize(w, h) => Event::Resize(vec2(w as _, h as _)),
+        E::Paste(data) => Event::Paste(data),
     };
 
     Some(ev)
@@ -438,6 +549,7 @@ fn read_event(tx: flume::Sender<Event>) {
     let _ = tx.send(Event::Quit);
 }
 
+// crossterm doesn't report the back and forward buttons, so they are never produced here
 fn translate_button(value: crossterm::event::MouseButton) -> MouseButton {
     match value {
         crossterm::event::MouseButton::Left => MouseButton::Primary,
@@ -446,14 +558,28 @@ fn translate_button(value: crossterm::event::MouseButton) -> MouseButton {
     }
 }
 
+// most mice can't scroll horizontally, so by convention shift turns a vertical scroll into a horizontal one.
+// shift is removed, so views don't also treat it as a modified scroll
+fn translate_scroll(delta: Vec2, modifiers: Modifiers) -> Event {
+    if delta.x == 0 && modifiers.is_shift() {
+        return Event::MouseScroll {
+            delta: vec2(delta.y, 0),
+            modifiers: modifiers.remove(Modifiers::SHIFT),
+        };
+    }
+    Event::MouseScroll { delta, modifiers }
+}
+
 fn translate_modifiers(value: crossterm::event::KeyModifiers) -> Modifiers {
+    use crossterm::event::KeyModifiers as M;
     [
-        crossterm::event::KeyModifiers::SHIFT,
-        crossterm::event::KeyModifiers::CONTROL,
-        crossterm::event::KeyModifiers::ALT,
+        (M::SHIFT, Modifiers::SHIFT),
+        (M::CONTROL, Modifiers::CTRL),
+        (M::ALT, Modifiers::ALT),
+        (M::SUPER, Modifiers::SUPER),
+        (M::META, Modifiers::META),
     ]
     .into_iter()
-    .fold(Modifiers::NONE, |this, m| {
-        this | Modifiers((value & m).bits())
-    })
+    .filter(|&(m, _)| value.contains(m))
+    .fold(Modifiers::NONE, |this, (_, m)| this | m)
 }
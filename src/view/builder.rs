@@ -1,6 +1,8 @@
 use crate::{
     layout::{Axis, Flex},
     math::{Size, Space},
+    views::{Constrain, Constrained, Enabled, OverflowVisible, Tooltip},
+    Str,
 };
 
 use super::{EventCtx, Handled, Interest, IntrinsicSize, Layout, Render, Response, Ui, ViewEvent};
@@ -83,15 +85,184 @@ pub trait ViewExt<'v>: Builder<'v> {
     {
         ui.show_children(self, show)
     }
+
+    /// Give this view at least this much space
+    ///
+    /// ```rust
+    /// # use too::{view::{State, Ui, ViewExt as _}, layout::Flex, math::{pos2, vec2, Rect, Size, Vec2}, views::{label, Fill, Flexible}};
+    /// fn size_of(show: impl Fn(&Ui) -> too::view::ViewId) -> Vec2 {
+    ///     let mut state = State::default();
+    ///     let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 5));
+    ///     state.build(rect, |ui| _ = show(ui));
+    ///     state.build(rect, |ui| ui.rect_of(show(ui)).unwrap().size())
+    /// }
+    ///
+    /// // a single column label still takes up 10 columns
+    /// let size = size_of(|ui| ui.show(label("x").min_size(Size::new(10.0, 1.0))).id());
+    /// assert_eq!(size, vec2(10, 1));
+    ///
+    /// // but it can't be larger than its maximum
+    /// let size = size_of(|ui| ui.show(label("hello world").max_size(Size::new(5.0, 1.0))).id());
+    /// assert_eq!(size, vec2(5, 1));
+    ///
+    /// // or it can be exactly some size
+    /// let size = size_of(|ui| ui.show(label("x").exact_size(Size::new(3.0, 2.0))).id());
+    /// assert_eq!(size, vec2(3, 2));
+    ///
+    /// // it keeps the flex of the view, and an infinite maximum leaves that axis unbounded
+    /// let size = size_of(|ui| {
+    ///     ui.horizontal(|ui| {
+    ///         ui.label("a");
+    ///         let flexible = Flexible::new(Flex::Tight(1.0)).max_size(Size::new(f32::INFINITY, 1.0));
+    ///         ui.show_children(flexible, |ui| _ = ui.show(Fill::fill_with("#F00"))).id()
+    ///     }).into_inner()
+    /// });
+    /// assert_eq!(size, vec2(18, 1)); // the rest of the row, after the label and the gap
+    /// ```
+    fn min_size(self, size: impl Into<Size>) -> Constrained<Self> {
+        Constrained::new(Constrain::min_size(size), self)
+    }
+
+    /// Give this view at most this much space
+    ///
+    /// An infinite size leaves that axis unbounded
+    fn max_size(self, size: impl Into<Size>) -> Constrained<Self> {
+        Constrained::new(Constrain::max_size(size), self)
+    }
+
+    /// Give this view exactly this much space
+    fn exact_size(self, size: impl Into<Size>) -> Constrained<Self> {
+        Constrained::new(Constrain::exact_size(size), self)
+    }
+
+    /// Show a tooltip near the mouse when this view is hovered
+    ///
+    /// The tooltip is shown once the mouse rests on the view for a [`delay`](Tooltip::delay), and moving the mouse hides it again.
+    ///
+    /// It floats above everything else and doesn't take part in hit-testing, so it never steals input from the views under it
+    ///
+    /// ```rust
+    /// # use too::{backend::Event, math::{pos2, vec2}, renderer::{Color, Surface}, view::{CroppedSurface, Palette, State, ViewExt as _}, views::button};
+    /// let mut state = State::default();
+    /// let mut surface = Surface::new(vec2(20, 3));
+    ///
+    /// // is the tooltip's background just below and to the right of the mouse?
+    /// let mut frame = |state: &mut State, surface: &mut Surface| {
+    ///     state.build(surface.rect(), |ui| _ = ui.show(button("save").tooltip("Ctrl+S")));
+    ///     state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut *surface });
+    ///     surface.get(pos2(3, 1)).unwrap().bg() == Color::Set(Palette::dark().surface)
+    /// };
+    ///
+    /// // views are only updated from their second frame
+    /// frame(&mut state, &mut surface);
+    /// frame(&mut state, &mut surface);
+    /// state.event(&Event::MouseMove { pos: pos2(2, 0) });
+    ///
+    /// // it isn't shown until the mouse rests for a bit
+    /// state.update(0.25);
+    /// assert!(!frame(&mut state, &mut surface));
+    /// state.update(0.25);
+    /// assert!(frame(&mut state, &mut surface));
+    ///
+    /// // and moving the mouse hides it
+    /// state.event(&Event::MouseMove { pos: pos2(2, 0) });
+    /// state.update(0.0);
+    /// assert!(!frame(&mut state, &mut surface));
+    /// ```
+    fn tooltip(self, text: impl Into<Str>) -> Tooltip<Self> {
+        Tooltip::new(text, self)
+    }
+
+    /// Let this view draw outside of its rect
+    ///
+    /// It can crop its render context out to the nearest clipping ancestor, but the mouse still only hits its own rect
+    ///
+    /// ```rust
+    /// # use too::{view::{test::render_to_string, Ui, ViewExt as _}, math::{pos2, vec2, Rect, Size}, views::canvas};
+    /// let app = |overflow: bool| {
+    ///     move |ui: &Ui| {
+    ///         ui.horizontal(|ui| {
+    ///             ui.label("ab");
+    ///             // this view is only a single cell, but it tries to draw over its neighbors
+    ///             let marker = canvas(|_, render| {
+    ///                 render.crop(Rect::from_min_size(pos2(-2, 0), vec2(4, 1)), |render| {
+    ///                     render.fill_with('*');
+    ///                 });
+    ///             })
+    ///             .size(Size::new(1.0, 1.0));
+    ///
+    ///             if overflow {
+    ///                 ui.show(marker.overflow_visible());
+    ///             } else {
+    ///                 ui.show(marker);
+    ///             }
+    ///         });
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(render_to_string(app(false)), "ab *");
+    /// assert_eq!(render_to_string(app(true)), "a****");
+    /// ```
+    fn overflow_visible(self) -> OverflowVisible<Self> {
+        OverflowVisible::new(self)
+    }
+
+    /// Enable or disable this view, and all of its children
+    ///
+    /// A disabled subtree doesn't get any input, can't be focused and is drawn with the palette's `outline` color.
+    /// If something in it was focused, it loses focus. Its views keep their state, so enabling it again is all it takes to use it again
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Modifiers, MouseButton}, math::{pos2, vec2, Rect}, view::{State, ViewExt as _}, views::slider};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+    ///
+    /// let mut value = 0.0;
+    /// let mut build = |state: &mut State, enabled: bool, value: &mut f32| {
+    ///     state.build(rect, |ui| _ = ui.show(slider(value).enabled(enabled)));
+    /// };
+    /// let click = |state: &mut State| {
+    ///     let pos = pos2(10, 0);
+    ///     state.event(&Event::MouseMove { pos });
+    ///     for down in [true, false] {
+    ///         state.event(&Event::MouseButtonChanged { pos, button: MouseButton::Primary, down, modifiers: Modifiers::NONE });
+    ///     }
+    /// };
+    ///
+    /// build(&mut state, false, &mut value);
+    /// click(&mut state);
+    /// build(&mut state, false, &mut value);
+    /// assert_eq!(value, 0.0);
+    /// assert!(state.focus().is_none());
+    ///
+    /// build(&mut state, true, &mut value);
+    /// click(&mut state);
+    /// build(&mut state, true, &mut value);
+    /// assert!(value > 0.0);
+    /// assert!(state.focus().is_some());
+    ///
+    /// // disabling it removes the focus
+    /// build(&mut state, false, &mut value);
+    /// assert!(state.focus().is_none());
+    /// ```
+    fn enabled(self, enabled: bool) -> Enabled<Self> {
+        Enabled::new(enabled, self)
+    }
 }
 
 impl<'v, T> ViewExt<'v> for T where T: Builder<'v> {}
 
+/// A marker for things that can be stored in a view
+///
+/// This is implemented for everything, or everything that is `Send + Sync` when the `sync` feature is enabled
 #[cfg(not(feature = "sync"))]
 pub trait ViewMarker {}
 #[cfg(not(feature = "sync"))]
 impl<T> ViewMarker for T {}
 
+/// A marker for things that can be stored in a view
+///
+/// This is implemented for everything, or everything that is `Send + Sync` when the `sync` feature is enabled
 #[cfg(feature = "sync")]
 pub trait ViewMarker: Send + Sync {}
 #[cfg(feature = "sync")]
@@ -213,6 +384,17 @@ pub trait View: Sized + 'static + std::fmt::Debug + ViewMarker {
         size
     }
 
+    /// A hash of the state of your view that affects its layout
+    ///
+    /// If this (and the hashes of all of your children) and the [`Space`] you're given haven't changed since the last frame, your previous layout is reused and [`View::layout`] isn't called.
+    ///
+    /// If your layout depends on anything else (like the input state) you shouldn't provide this.
+    ///
+    /// By default, views don't provide a hash so they are always laid out
+    fn layout_hash(&self) -> Option<u64> {
+        None
+    }
+
     /// Lay out your view.
     ///
     /// This gives you a [`Space`] provided by your parent that you can use.
@@ -261,7 +443,7 @@ pub trait View: Sized + 'static + std::fmt::Debug + ViewMarker {
         let node = ctx.nodes.get_current();
         let mut resp = Handled::Bubble;
         for &child in &node.children {
-            let new = ctx.send_event(child, event);
+            let new = ctx.send_event(child, event.clone());
             if new.is_sink() {
                 return new;
             }
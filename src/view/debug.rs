@@ -815,9 +815,9 @@ pub fn compact_tree_sizes<R: 'static>(app: impl FnMut(&Ui) -> R) -> TreeOutput {
 /// ```
 /// produces:
 /// ```text,no_run
-/// ViewId(3v1): Text { rect: { x: 35, y: 12, w: 11, h: 1 }, shape: TextShape { label: "hello world", fg: Set(rgb(255, 255, 255, 255)), bg: Reuse, attribute: None } }
+/// ViewId(3v1): Text { rect: { x: 35, y: 12, w: 11, h: 1 }, shape: TextShape { label: "hello world", fg: Set(rgb(255, 255, 255, 255)), bg: Reuse, attribute: None, tab_width: 4, control_chars: Replace, link: None } }
 /// ViewId(5v1): FillBg { rect: { x: 70, y: 0, w: 10, h: 1 }, color: rgb(77, 77, 77, 255) }
-/// ViewId(5v1): Text { rect: { x: 71, y: 0, w: 8, h: 1 }, shape: TextShape { label: "click me", fg: Set(rgb(255, 255, 255, 255)), bg: Reuse, attribute: None } }
+/// ViewId(5v1): Text { rect: { x: 71, y: 0, w: 8, h: 1 }, shape: TextShape { label: "click me", fg: Set(rgb(255, 255, 255, 255)), bg: Reuse, attribute: None, tab_width: 4, control_chars: Replace, link: None } }
 /// ViewId(6v1): FillBg { rect: { x: 0, y: 0, w: 10, h: 10 }, color: rgb(255, 0, 255, 255) }
 /// ```
 pub fn render_tree<R: 'static>(app: impl FnMut(&Ui) -> R) -> Vec<(ViewId, Shape)> {
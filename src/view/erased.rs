@@ -16,6 +16,7 @@ pub trait Erased: std::any::Any + std::fmt::Debug + ViewMarker {
 
     fn size(&self, size: IntrinsicSize, axis: Axis, extent: f32) -> f32;
     fn primary_axis(&self) -> Axis;
+    fn layout_hash(&self) -> Option<u64>;
 
     fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled;
     fn layout(&mut self, layout: Layout, space: Space) -> Size;
@@ -57,6 +58,11 @@ impl<T: View + ViewMarker> Erased for T {
         T::primary_axis(self)
     }
 
+    #[inline(always)]
+    fn layout_hash(&self) -> Option<u64> {
+        T::layout_hash(self)
+    }
+
     #[inline(always)]
     fn layout(&mut self, layout: Layout, space: Space) -> Size {
         T::layout(self, layout, space)
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+/// Statistics about the recent frame times
+///
+/// These are computed over the last [`FrameStats::WINDOW`] updates, by [`State::update`](crate::view::State::update)
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// The moving average of the frame time, this is the same as [`Ui::frame_time`](crate::view::Ui::frame_time)
+    pub average: Duration,
+    /// The shortest frame time
+    pub min: Duration,
+    /// The longest frame time
+    pub max: Duration,
+    /// 95% of the frames were at most this long
+    pub p95: Duration,
+    /// How many frame times these were computed from
+    pub samples: usize,
+}
+
+impl FrameStats {
+    /// How many of the most recent frame times are kept
+    pub const WINDOW: usize = 120;
+}
+
+/// Formats as `avg / p95 / max`, in milliseconds
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use too::view::FrameStats;
+/// let stats = FrameStats {
+///     average: Duration::from_micros(16_600),
+///     p95: Duration::from_millis(20),
+///     max: Duration::from_micros(33_250),
+///     ..FrameStats::default()
+/// };
+/// assert_eq!(stats.to_string(), "16.6 / 20.0 / 33.2 ms");
+/// ```
+impl std::fmt::Display for FrameStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        write!(
+            f,
+            "{:.1} / {:.1} / {:.1} ms",
+            ms(self.average),
+            ms(self.p95),
+            ms(self.max)
+        )
+    }
+}
+
+// a ring buffer of the most recent frame times
+#[derive(Debug)]
+pub(in crate::view) struct FrameTimes {
+    samples: [f32; FrameStats::WINDOW],
+    len: usize,
+    next: usize,
+}
+
+impl Default for FrameTimes {
+    fn default() -> Self {
+        Self {
+            samples: [0.0; FrameStats::WINDOW],
+            len: 0,
+            next: 0,
+        }
+    }
+}
+
+impl FrameTimes {
+    pub(in crate::view) fn push(&mut self, dt: f32) {
+        self.samples[self.next] = dt.max(0.0);
+        self.next = (self.next + 1) % FrameStats::WINDOW;
+        self.len = (self.len + 1).min(FrameStats::WINDOW);
+    }
+
+    pub(in crate::view) fn stats(&self, average: f32) -> FrameStats {
+        if self.len == 0 {
+            return FrameStats::default();
+        }
+
+        // the order of the samples doesn't matter, so this sorts a copy of them in place
+        let mut sorted = self.samples;
+        let sorted = &mut sorted[..self.len];
+        sorted.sort_unstable_by(f32::total_cmp);
+
+        // nearest-rank
+        let rank = (self.len as f32 * 0.95).ceil() as usize;
+        // a frame too long for a duration is clamped to the longest one
+        let secs = |secs: f32| Duration::try_from_secs_f32(secs).unwrap_or(Duration::MAX);
+        FrameStats {
+            average: secs(average.max(0.0)),
+            min: secs(sorted[0]),
+            max: secs(sorted[self.len - 1]),
+            p95: secs(sorted[rank.saturating_sub(1)]),
+            samples: self.len,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    backend::{Clipboard, NoClipboard},
+    lock::{Lock, Shared},
+    view::ViewMarker,
+};
+
+#[cfg(not(feature = "sync"))]
+type BoxedClipboard = Box<dyn Clipboard>;
+
+#[cfg(feature = "sync")]
+type BoxedClipboard = Box<dyn Clipboard + Send + Sync>;
+
+/// A shared handle to a [`Clipboard`]
+///
+/// Cloning this gives another handle to the same clipboard
+pub struct ClipboardHandle {
+    inner: Shared<Lock<BoxedClipboard>>,
+}
+
+impl ClipboardHandle {
+    /// Create a handle for this clipboard
+    pub fn new(clipboard: impl Clipboard + ViewMarker + 'static) -> Self {
+        let clipboard: BoxedClipboard = Box::new(clipboard);
+        Self {
+            inner: Shared::new(Lock::new(clipboard)),
+        }
+    }
+
+    /// Get the text currently on the clipboard, if there is any
+    pub fn get(&self) -> Option<String> {
+        self.inner.borrow_mut().get()
+    }
+
+    /// Put this text on the clipboard
+    pub fn set(&self, data: &str) {
+        self.inner.borrow_mut().set(data)
+    }
+}
+
+impl Clone for ClipboardHandle {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Shared::clone(&self.inner),
+        }
+    }
+}
+
+impl Default for ClipboardHandle {
+    fn default() -> Self {
+        Self::new(NoClipboard)
+    }
+}
+
+impl std::fmt::Debug for ClipboardHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipboardHandle").finish_non_exhaustive()
+    }
+}
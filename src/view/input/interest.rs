@@ -1,16 +1,18 @@
 /// Interests for events that a view want to recieve
-#[derive(Copy, Clone, Default, PartialEq)]
+#[derive(Copy, Clone, Default, PartialEq, Hash)]
 pub struct Interest(u8);
 
 impl std::fmt::Debug for Interest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        const FIELDS: [&str; 6] = [
+        const FIELDS: [&str; 8] = [
             "MOUSE_INSIDE",
             "MOUSE_OUTSIDE",
             "MOUSE_MOVE",
             "FOCUS",
             "FOCUS_INPUT",
             "SELECTION_CHANGE",
+            "KEY_GLOBAL",
+            "ANIMATE",
         ];
 
         let mut seen = false;
@@ -54,6 +56,63 @@ impl Interest {
     /// A view wants to get [`ViewEvent::SelectionAdded`](crate::view::ViewEvent::SelectionAdded) and [`ViewEvent::SelectionRemoved`](crate::view::ViewEvent::SelectionRemoved) events
     pub const SELECTION_CHANGE: Self = Self(1 << 5);
 
+    /// A view wants to get every key input, even when its not focused
+    ///
+    /// These views get the key before the focused view does, in layout order. If one of them sinks it, nothing else gets it
+    pub const KEY_GLOBAL: Self = Self(1 << 6);
+
+    /// A view wants to get a [`ViewEvent::Tick`](crate::view::ViewEvent::Tick) every frame, from [`State::update`](crate::view::State::update)
+    ///
+    /// The views are the ones from the last layout, in tree order. A view gets its first tick on the frame after it was added,
+    /// and views removed since the last layout don't get one
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, view::{Builder, EventCtx, Handled, Interest, State, Ui, View, ViewEvent}};
+    /// #[derive(Debug, Default)]
+    /// struct Stopwatch(f32);
+    ///
+    /// impl<'v> Builder<'v> for Stopwatch {
+    ///     type View = Self;
+    /// }
+    ///
+    /// impl View for Stopwatch {
+    ///     type Args<'v> = Self;
+    ///     type Response = f32;
+    ///
+    ///     fn create(args: Self::Args<'_>) -> Self {
+    ///         args
+    ///     }
+    ///
+    ///     fn update(&mut self, _: Self::Args<'_>, _: &Ui) -> Self::Response {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn interests(&self) -> Interest {
+    ///         Interest::ANIMATE
+    ///     }
+    ///
+    ///     fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
+    ///         if let ViewEvent::Tick { dt } = event {
+    ///             self.0 += dt;
+    ///         }
+    ///         Handled::Bubble
+    ///     }
+    /// }
+    ///
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    /// let mut frame = |state: &mut State, dt| {
+    ///     state.update(dt);
+    ///     state.build(rect, |ui| ui.show(Stopwatch::default()).into_inner())
+    /// };
+    ///
+    /// // it didn't exist for the first update
+    /// assert_eq!(frame(&mut state, 0.5), 0.0);
+    /// assert_eq!(frame(&mut state, 0.25), 0.25);
+    /// assert_eq!(frame(&mut state, 0.25), 0.5);
+    /// ```
+    pub const ANIMATE: Self = Self(1 << 7);
+
     /// A view wants all mouse events
     pub const MOUSE: Self = Self(1 << 0 | 1 << 1 | 1 << 2);
 }
@@ -94,6 +153,14 @@ impl Interest {
     pub const fn is_selection_change(&self) -> bool {
         self.0 & (1 << 5) != 0
     }
+
+    pub const fn is_key_global(&self) -> bool {
+        self.0 & (1 << 6) != 0
+    }
+
+    pub const fn is_animate(&self) -> bool {
+        self.0 & (1 << 7) != 0
+    }
 }
 
 impl std::ops::BitAnd for Interest {
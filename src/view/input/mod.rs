@@ -1,13 +1,17 @@
-use std::{cell::Cell, collections::HashMap};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use crate::{
     animation::Animations,
     backend::Event as TooEvent,
-    backend::{Key, Keybind, Modifiers, MouseButton},
+    backend::{Keybind, Modifiers, MouseButton},
     math::{Pos2, Rect, Vec2},
 };
 
-use super::{Erased, Filter, Filterable, LayoutNodes, ViewId, ViewNodes};
+use super::{Erased, Filter, Filterable, LayoutNode, LayoutNodes, ViewId, ViewNodes};
 
 mod interest;
 pub use interest::Interest;
@@ -15,6 +19,9 @@ pub use interest::Interest;
 mod view_event;
 pub use view_event::ViewEvent;
 
+mod clipboard;
+pub use clipboard::ClipboardHandle;
+
 /// A response to an event
 ///
 /// If a view consumes the event, it should return `Sink`
@@ -74,13 +81,83 @@ impl Intersections {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 struct Mouse {
     pos: Pos2,
+    // where the button was pressed
+    press: Option<Pos2>,
     drag_start: Option<Pos2>,
+    // how far, in cells, the mouse has to move from where it was pressed to start a drag
+    drag_threshold: u32,
     buttons: HashMap<MouseButton, ButtonState>,
 }
 
+impl Default for Mouse {
+    fn default() -> Self {
+        Self {
+            pos: Pos2::ZERO,
+            press: None,
+            drag_start: None,
+            drag_threshold: Self::DEFAULT_DRAG_THRESHOLD,
+            buttons: HashMap::new(),
+        }
+    }
+}
+
+impl Mouse {
+    const DEFAULT_DRAG_THRESHOLD: u32 = 1;
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Click {
+    at: Instant,
+    pos: Pos2,
+    count: usize,
+}
+
+#[derive(Debug)]
+struct Clicks {
+    window: Duration,
+    last: HashMap<MouseButton, Click>,
+}
+
+impl Default for Clicks {
+    fn default() -> Self {
+        Self {
+            window: Self::DEFAULT_WINDOW,
+            last: HashMap::new(),
+        }
+    }
+}
+
+impl Clicks {
+    const DEFAULT_WINDOW: Duration = Duration::from_millis(300);
+    // how far the mouse can move between clicks and still have them count together
+    const SLOP: u32 = 2;
+    // after a triple click, the next click starts over
+    const MAX_COUNT: usize = 3;
+
+    fn click(&mut self, button: MouseButton, pos: Pos2) {
+        let at = Instant::now();
+        let count = match self.last.get(&button) {
+            Some(last)
+                if last.count < Self::MAX_COUNT
+                    && at.duration_since(last.at) < self.window
+                    && last.pos.x.abs_diff(pos.x) <= Self::SLOP
+                    && last.pos.y.abs_diff(pos.y) <= Self::SLOP =>
+            {
+                last.count + 1
+            }
+            _ => 1,
+        };
+        self.last.insert(button, Click { at, pos, count });
+    }
+
+    fn count(&self, button: MouseButton) -> usize {
+        self.last.get(&button).map_or(0, |click| click.count)
+    }
+}
+
 #[derive(Debug, Default)]
 struct Notify<T: Copy + PartialEq = ViewId> {
     current: Cell<Option<T>>,
@@ -115,11 +192,15 @@ pub struct InputState {
     mouse: Mouse,
     modifiers: Modifiers,
     intersections: Intersections,
+    clicks: Clicks,
 
     focus: Focus,
     selection: Selection,
 
     key_press: Option<Keybind>,
+    clipboard: ClipboardHandle,
+
+    window_unfocused: bool,
 }
 
 impl InputState {
@@ -149,6 +230,45 @@ impl InputState {
         self.mouse.pos
     }
 
+    /// How many times in a row this button was clicked, ending with its latest click
+    ///
+    /// This is `2` for a double click and `3` for a triple click, and starts over after that.
+    /// It is `0` if the button hasn't been clicked. A drag resets it.
+    pub fn click_count(&self, button: MouseButton) -> usize {
+        self.clicks.count(button)
+    }
+
+    /// Clicks closer together than this (and about the same place) count towards a double or triple click
+    ///
+    /// This defaults to 300 milliseconds.
+    pub(super) fn set_multi_click_window(&mut self, window: Duration) {
+        self.clicks.window = window;
+    }
+
+    pub(super) fn multi_click_window(&self) -> Duration {
+        self.clicks.window
+    }
+
+    /// How far, in cells, the mouse has to move from where a button was pressed before it starts a drag
+    ///
+    /// This defaults to 1 cell.
+    pub(super) fn set_drag_threshold(&mut self, cells: u32) {
+        self.mouse.drag_threshold = cells;
+    }
+
+    pub(super) fn drag_threshold(&self) -> u32 {
+        self.mouse.drag_threshold
+    }
+
+    pub(super) fn set_clipboard(&mut self, clipboard: ClipboardHandle) {
+        self.clipboard = clipboard;
+    }
+
+    /// Get the clipboard
+    pub fn clipboard(&self) -> &ClipboardHandle {
+        &self.clipboard
+    }
+
     /// Get the current button modifier state
     pub fn modifiers(&self) -> Modifiers {
         self.modifiers
@@ -164,6 +284,94 @@ impl InputState {
         self.focus.notify.set(id)
     }
 
+    /// Move the focus to the next view that wants key input, in layout order
+    ///
+    /// This wraps around from the last view to the first one.
+    ///
+    /// Returns `false` if there was nothing that could be focused
+    pub(super) fn focus_next(
+        &mut self,
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        animation: &mut Animations,
+    ) -> bool {
+        self.focus_step(true, nodes, layout, animation)
+    }
+
+    /// Move the focus to the previous view that wants key input, in layout order
+    ///
+    /// This wraps around from the first view to the last one.
+    ///
+    /// Returns `false` if there was nothing that could be focused
+    pub(super) fn focus_prev(
+        &mut self,
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        animation: &mut Animations,
+    ) -> bool {
+        self.focus_step(false, nodes, layout, animation)
+    }
+
+    fn focus_step(
+        &mut self,
+        forward: bool,
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        animation: &mut Animations,
+    ) -> bool {
+        let order = Self::focus_order(nodes, layout);
+        if order.is_empty() {
+            return false;
+        }
+
+        let len = order.len();
+        let current = self
+            .focus()
+            .and_then(|id| order.iter().position(|&c| c == id));
+
+        let next = match current {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+
+        self.set_focus(Some(order[next]));
+        self.notify_focus(nodes, layout, animation);
+        true
+    }
+
+    // views that want key input and are interactive (e.g. not disabled), in tree order
+    fn focus_order(nodes: &ViewNodes, layout: &LayoutNodes) -> Vec<ViewId> {
+        Self::in_tree_order(nodes, layout, |node| {
+            node.interest.is_focus_input() && node.interactive
+        })
+    }
+
+    // the views that were laid out and match this, in tree order
+    fn in_tree_order(
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        keep: fn(&LayoutNode) -> bool,
+    ) -> Vec<ViewId> {
+        let mut order = vec![];
+        let mut stack = vec![nodes.root()];
+        while let Some(id) = stack.pop() {
+            let Some(node) = nodes.get(id) else {
+                continue;
+            };
+
+            if let Some(layout) = layout.get(id) {
+                if keep(layout) {
+                    order.push(id);
+                }
+            }
+
+            stack.extend(node.children.iter().rev());
+        }
+        order
+    }
+
     /// Get the current selection id
     pub fn selection(&self) -> Option<ViewId> {
         self.selection.notify.get()
@@ -179,6 +387,35 @@ impl InputState {
         self.focus.notify.get() == Some(id)
     }
 
+    /// Is this id, or any of its descendants, focused?
+    ///
+    /// This walks up from the focused view through its parents, so its only as expensive as the focused view is deep
+    pub fn is_focus_within(&self, nodes: &ViewNodes, id: ViewId) -> bool {
+        let mut current = self.focus.notify.get();
+        while let Some(node) = current {
+            if node == id {
+                return true;
+            }
+            current = nodes.get(node).and_then(|node| node.parent);
+        }
+        false
+    }
+
+    /// Does the terminal window have focus?
+    ///
+    /// This is `true` until the terminal reports that it lost focus
+    pub const fn is_window_focused(&self) -> bool {
+        !self.window_unfocused
+    }
+
+    /// Is this mouse button held down?
+    pub fn is_mouse_down(&self, button: MouseButton) -> bool {
+        self.mouse
+            .buttons
+            .get(&button)
+            .is_some_and(ButtonState::is_down)
+    }
+
     /// Is this id hovered?
     pub fn is_hovered(&self, id: ViewId) -> bool {
         self.intersections.hit.contains(&id)
@@ -199,7 +436,22 @@ impl InputState {
         match *event {
             TooEvent::KeyPressed { key, .. } => {
                 self.key_press = Some(Keybind::new(key, self.modifiers));
-                self.update_key_event(key, nodes, layout, animation)
+                let event = ViewEvent::KeyInput {
+                    key,
+                    modifiers: self.modifiers,
+                };
+                if self
+                    .send_key_global(event.clone(), nodes, layout, animation)
+                    .is_sink()
+                {
+                    return Handled::Sink;
+                }
+                self.send_focus_input(event, nodes, layout, animation)
+            }
+
+            TooEvent::Paste(ref data) => {
+                let event = ViewEvent::Paste(data.clone());
+                self.send_focus_input(event, nodes, layout, animation)
             }
 
             TooEvent::MouseMove { pos } => self.mouse_moved(pos, nodes, layout, animation),
@@ -224,13 +476,20 @@ impl InputState {
             }
 
             TooEvent::MouseDrag { pos, button, .. } => {
-                let (start, delta) = {
-                    let previous = std::mem::replace(&mut self.mouse.pos, pos);
-                    let &mut start = self.mouse.drag_start.get_or_insert(pos);
-                    if previous == pos {
-                        return Handled::Bubble;
+                let previous = std::mem::replace(&mut self.mouse.pos, pos);
+                let (start, delta) = match self.mouse.drag_start {
+                    Some(_) if previous == pos => return Handled::Bubble,
+                    Some(start) => (start, pos - previous),
+                    None => {
+                        // small movements while pressed are still a click, so the first delta covers all of the movement
+                        let origin = self.mouse.press.unwrap_or(previous);
+                        let moved = origin.x.abs_diff(pos.x).max(origin.y.abs_diff(pos.y));
+                        if moved <= self.mouse.drag_threshold {
+                            return Handled::Bubble;
+                        }
+                        self.mouse.drag_start = Some(origin);
+                        (origin, pos - origin)
                     }
-                    (start, pos - previous)
                 };
                 let delta = delta.to_vec2();
                 self.send_mouse_drag(start, delta, button, nodes, layout, animation)
@@ -239,13 +498,61 @@ impl InputState {
             TooEvent::MouseScroll { delta, .. } => {
                 self.mouse_scrolled(delta, nodes, layout, animation)
             }
+
+            TooEvent::FocusGained | TooEvent::FocusLost => {
+                self.window_unfocused = matches!(event, TooEvent::FocusLost);
+                Handled::Bubble
+            }
             _ => Handled::Bubble,
         }
     }
 
-    fn update_key_event(
+    /// Send a [`ViewEvent::Tick`] to every view that is interested in it, in tree order
+    ///
+    /// The views are the ones from the last layout, views that were removed since then are skipped
+    pub(super) fn send_tick(
         &mut self,
-        key: Key,
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        animation: &mut Animations,
+        dt: f32,
+    ) {
+        let interested = Self::in_tree_order(nodes, layout, |node| node.interest.is_animate());
+        for id in interested {
+            self.dispatch(nodes, layout, animation, id, ViewEvent::Tick { dt });
+        }
+    }
+
+    // views that want every key get it first, unless they're focused and will get it anyway
+    fn send_key_global(
+        &mut self,
+        event: ViewEvent,
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        animation: &mut Animations,
+    ) -> Handled {
+        let focus = self.focus.notify.get();
+        let global = Self::in_tree_order(nodes, layout, |node| {
+            node.interest.is_key_global() && node.interactive
+        });
+        for id in global {
+            if Some(id) == focus && layout.get(id).is_some_and(|l| l.interest.is_focus_input()) {
+                continue;
+            }
+            if self
+                .dispatch(nodes, layout, animation, id, event.clone())
+                .is_sink()
+            {
+                return Handled::Sink;
+            }
+        }
+        Handled::Bubble
+    }
+
+    // keys and pastes only go to the focused view, if it wants them
+    fn send_focus_input(
+        &mut self,
+        event: ViewEvent,
         nodes: &ViewNodes,
         layout: &LayoutNodes,
         animation: &mut Animations,
@@ -262,10 +569,6 @@ impl InputState {
             return Handled::Bubble;
         }
 
-        let event = ViewEvent::KeyInput {
-            key,
-            modifiers: self.modifiers,
-        };
         self.dispatch(nodes, layout, animation, id, event)
     }
 
@@ -284,6 +587,17 @@ impl InputState {
         Handled::Bubble
     }
 
+    /// The mouse left the region this input state covers
+    pub(super) fn mouse_left(
+        &mut self,
+        nodes: &ViewNodes,
+        layout: &LayoutNodes,
+        animation: &mut Animations,
+    ) {
+        self.intersections.hit.clear();
+        self.send_mouse_leave(nodes, layout, animation);
+    }
+
     fn send_mouse_move(
         &mut self,
         nodes: &ViewNodes,
@@ -300,7 +614,7 @@ impl InputState {
                 continue;
             }
 
-            self.dispatch(nodes, layout, animation, id, event);
+            self.dispatch(nodes, layout, animation, id, event.clone());
         }
     }
 
@@ -350,14 +664,28 @@ impl InputState {
 
     fn mouse_button_changed(&mut self, button: MouseButton, down: bool) -> bool {
         let state = self.mouse.buttons.entry(button).or_insert(ButtonState::Up);
+        let released = state.is_down() && !down;
         match (state.is_down(), down) {
             (true, true) | (false, false) => {}
             (false, true) => *state = ButtonState::JustDown,
             (true, false) => *state = ButtonState::JustUp,
         };
 
+        if down {
+            self.mouse.press.get_or_insert(self.mouse.pos);
+        } else {
+            self.mouse.press = None;
+        }
+
         if !down {
-            return self.mouse.drag_start.take().is_none();
+            let clicked = self.mouse.drag_start.take().is_none();
+            if !clicked {
+                // a drag isn't a click, so it doesn't count towards a double click
+                self.clicks.last.remove(&button);
+            } else if released {
+                self.clicks.click(button, self.mouse.pos);
+            }
+            return clicked;
         }
         true
     }
@@ -384,7 +712,7 @@ impl InputState {
 
         for &hit in &self.intersections.hit {
             if self
-                .dispatch(nodes, layout, animation, hit, event)
+                .dispatch(nodes, layout, animation, hit, event.clone())
                 .is_sink()
             {
                 resp = Handled::Sink;
@@ -403,7 +731,7 @@ impl InputState {
 
         for (id, interest) in layout.interest.iter() {
             if interest.is_mouse_outside() && !self.intersections.hit.contains(&id) {
-                self.dispatch(nodes, layout, animation, id, event);
+                self.dispatch(nodes, layout, animation, id, event.clone());
             }
         }
 
@@ -438,7 +766,7 @@ impl InputState {
 
         for &hit in &self.intersections.hit {
             if self
-                .dispatch(nodes, layout, animation, hit, event)
+                .dispatch(nodes, layout, animation, hit, event.clone())
                 .is_sink()
             {
                 resp = Handled::Sink;
@@ -464,7 +792,7 @@ impl InputState {
 
         for (id, interest) in layout.interest.iter() {
             if interest.is_mouse_outside() && !self.intersections.hit.contains(&id) {
-                self.dispatch(nodes, layout, animation, id, event);
+                self.dispatch(nodes, layout, animation, id, event.clone());
             }
         }
 
@@ -484,7 +812,7 @@ impl InputState {
         };
         for &hit in &self.intersections.hit {
             if self
-                .dispatch(nodes, layout, animation, hit, event)
+                .dispatch(nodes, layout, animation, hit, event.clone())
                 .is_sink()
             {
                 return Handled::Sink;
@@ -515,7 +843,7 @@ impl InputState {
                 }
 
                 let resp = nodes.scoped(id, |node| {
-                    self.send_event(nodes, layout, animation, id, node, ev)
+                    self.send_event(nodes, layout, animation, id, node, ev.clone())
                 });
 
                 if let Some(Handled::Sink) = resp {
@@ -534,7 +862,10 @@ impl InputState {
                 if !interest.is_selection_change() {
                     continue;
                 }
-                if self.dispatch(nodes, layout, animation, id, ev).is_sink() {
+                if self
+                    .dispatch(nodes, layout, animation, id, ev.clone())
+                    .is_sink()
+                {
                     break;
                 }
             }
@@ -688,6 +1019,16 @@ impl<'a> EventCtx<'a> {
         self.input.mouse_pos()
     }
 
+    /// Get the clipboard
+    pub fn clipboard(&self) -> &ClipboardHandle {
+        self.input.clipboard()
+    }
+
+    /// Is this mouse button held down?
+    pub fn is_mouse_down(&self, button: MouseButton) -> bool {
+        self.input.is_mouse_down(button)
+    }
+
     /// Is the current view focused?
     pub fn is_focused(&self) -> bool {
         self.input.is_focused(self.current)
@@ -698,6 +1039,16 @@ impl<'a> EventCtx<'a> {
         self.input.is_hovered(self.current)
     }
 
+    /// Is the current view, or any of its descendants, focused?
+    pub fn is_focus_within(&self) -> bool {
+        self.input.is_focus_within(self.nodes, self.current)
+    }
+
+    /// Does the terminal window have focus?
+    pub fn is_window_focused(&self) -> bool {
+        self.input.is_window_focused()
+    }
+
     /// Is the current view's parent focused?
     pub fn is_parent_focused(&self) -> bool {
         self.input.is_focused(self.nodes.parent())
@@ -5,7 +5,7 @@ use crate::{
 };
 
 /// Events a view can receive
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ViewEvent {
     /// A key was pressed
     KeyInput {
@@ -14,6 +14,8 @@ pub enum ViewEvent {
         /// Any modifiers being held down while the key was pressed
         modifiers: Modifiers,
     },
+    /// Some text was pasted
+    Paste(String),
     /// The mouse was moved
     MouseMove {
         /// The current position of the mouse cursor
@@ -71,6 +73,8 @@ pub enum ViewEvent {
         /// - - Down or Right
         /// - Negative
         /// - - Up or Left
+        ///
+        /// The terminal backend turns `Shift` and a vertical scroll into a horizontal scroll, without the `Shift`
         delta: Vec2,
         /// Any modifiers being held down while the mouse was scrolling
         modifiers: Modifiers,
@@ -90,4 +94,14 @@ pub enum ViewEvent {
     SelectionAdded(ViewId),
     /// A view notified that it was unselected
     SelectionRemoved(ViewId),
+
+    /// A frame is starting, this is sent by [`State::update`](crate::view::State::update) before the Ui is built
+    ///
+    /// Views have to be interested in [`Interest::ANIMATE`](crate::view::Interest::ANIMATE) to get this
+    Tick {
+        /// The delta-time given to [`State::update`](crate::view::State::update), in seconds
+        ///
+        /// This isn't scaled or paused like the [`Animations`](crate::animation::Animations) are
+        dt: f32,
+    },
 }
@@ -1,4 +1,7 @@
-use std::collections::VecDeque;
+use std::{
+    collections::VecDeque,
+    hash::{DefaultHasher, Hash as _, Hasher as _},
+};
 
 use slotmap::{Key as _, SecondaryMap};
 
@@ -58,6 +61,25 @@ impl<'a> Layout<'a> {
         self.layout.compute(self.nodes, self.input, id, space)
     }
 
+    /// Compute the layout size of a view, without keeping its event interests
+    ///
+    /// Use this when a view is measured first and then computed again with its final space, otherwise its interests would be registered twice
+    pub fn measure(&mut self, id: ViewId, space: Space) -> Size {
+        let mark = self.interest_mark();
+        let size = self.compute(id, space);
+        self.rollback_interests(mark);
+        size
+    }
+
+    // where the next event interest would be registered, so a pass that turns out to be a measurement can be undone
+    pub(crate) fn interest_mark(&self) -> (usize, usize) {
+        self.layout.interest.mark()
+    }
+
+    pub(crate) fn rollback_interests(&mut self, mark: (usize, usize)) {
+        self.layout.interest.rollback(mark);
+    }
+
     /// Get the axis of your parent
     pub fn parent_axis(&self) -> Axis {
         self.layout.current_axis().unwrap()
@@ -98,10 +120,39 @@ impl<'a> Layout<'a> {
         self.layout.enable_clipping(self.nodes);
     }
 
-    /// Remove a view from the layout and input trees
+    /// Lets this view draw outside of its rect
+    ///
+    /// Cropping its render context can reach out to the rect of the nearest clipping ancestor (or the whole screen, if there isn't one).
+    ///
+    /// This only changes drawing. The view is still hit by the mouse only inside of its own rect
+    pub fn enable_overflow(&mut self) {
+        self.layout.nodes[self.current].overflow_visible = true;
+    }
+
+    /// Disables input for this view, and all of its descendants
+    ///
+    /// They won't register their interests, so they don't get any events and can't be focused.
+    /// If one of them was focused, the focus is removed.
+    pub fn disable_input(&mut self) {
+        self.layout.disable_input(self.nodes);
+    }
+
+    /// Remove a view, and all of its descendants, from the layout and input trees
+    ///
+    /// They won't get any events until they are laid out again. If one of them was focused, the focus is removed.
     pub fn remove(&mut self, id: ViewId) {
-        self.layout.remove(id);
-        self.input.remove(id);
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            if let Some(node) = self.nodes.get(id) {
+                stack.extend(node.children.iter().copied());
+            }
+
+            self.layout.remove(id);
+            self.input.remove(id);
+            if self.input.focus() == Some(id) {
+                self.input.set_focus(None);
+            }
+        }
     }
 
     /// Position a view in the layout tree.
@@ -193,6 +244,27 @@ impl EventInterest {
             .push((id, interest));
     }
 
+    // where the next interest would be inserted, so they can be rolled back to it
+    fn mark(&self) -> (usize, usize) {
+        let current = self
+            .stack
+            .last()
+            .and_then(|&(_, index)| self.layers.get(index))
+            .map_or(0, Vec::len);
+        (self.layers.len(), current)
+    }
+
+    fn rollback(&mut self, (layers, current): (usize, usize)) {
+        self.layers.truncate(layers);
+        if let Some(layer) = self
+            .stack
+            .last()
+            .and_then(|&(_, index)| self.layers.get_mut(index))
+        {
+            layer.truncate(current);
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (ViewId, Interest)> + '_ {
         self.layers
             .iter()
@@ -201,13 +273,27 @@ impl EventInterest {
     }
 }
 
+/// Counters for how often the layout cache was used
+///
+/// These are reset every time the layout is computed
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LayoutStats {
+    /// How many views had their previous layout reused
+    pub hits: usize,
+    /// How many views had to be measured
+    pub misses: usize,
+}
+
 /// The tree for the layouts of all of the views
 #[derive(Default)]
 pub struct LayoutNodes {
     pub(super) nodes: SecondaryMap<ViewId, LayoutNode>,
     clip_stack: Vec<ViewId>,
     axis_stack: Vec<Axis>,
+    disabled_stack: Vec<ViewId>,
     pub(super) interest: EventInterest,
+    hashes: SecondaryMap<ViewId, u64>,
+    stats: LayoutStats,
 }
 
 impl std::fmt::Debug for LayoutNodes {
@@ -237,6 +323,11 @@ impl LayoutNodes {
         self.nodes.get(id)
     }
 
+    /// How many views were reused or measured during the last layout
+    pub const fn stats(&self) -> LayoutStats {
+        self.stats
+    }
+
     /// Does this tree contain that id?
     pub fn contains(&self, id: ViewId) -> bool {
         self.nodes.contains_key(id)
@@ -282,6 +373,10 @@ impl LayoutNodes {
         self.clip_stack.push(nodes.current());
     }
 
+    pub(super) fn disable_input(&mut self, nodes: &ViewNodes) {
+        self.disabled_stack.push(nodes.current());
+    }
+
     pub(super) fn new_layer(&mut self, nodes: &ViewNodes) {
         let id = nodes.current();
         self.interest.push_layer(id);
@@ -295,12 +390,14 @@ impl LayoutNodes {
         if let Some(node) = self.nodes.get_mut(id) {
             let offset = pos.into().to_vec2();
             node.rect = node.rect.translate(offset);
+            node.local = node.rect;
         }
     }
 
     pub(super) fn set_size(&mut self, id: ViewId, size: impl Into<Vec2>) {
         if let Some(node) = self.nodes.get_mut(id) {
             node.rect.set_size(size);
+            node.local = node.rect;
         }
     }
 }
@@ -311,7 +408,10 @@ impl LayoutNodes {
             nodes: SecondaryMap::new(),
             clip_stack: Vec::new(),
             axis_stack: Vec::new(),
+            disabled_stack: Vec::new(),
             interest: EventInterest::new(),
+            hashes: SecondaryMap::new(),
+            stats: LayoutStats { hits: 0, misses: 0 },
         }
     }
 
@@ -323,10 +423,20 @@ impl LayoutNodes {
         id: ViewId,
         space: Space,
     ) -> Size {
+        if let Some(size) = self.reuse(nodes, input, id, space) {
+            self.stats.hits += 1;
+            return size;
+        }
+        self.stats.misses += 1;
+
         nodes.begin(id);
 
+        let parent_axis = self.axis_stack.last().copied();
+        let parent_clip = self.clip_stack.last().copied();
+        let parent_disabled = !self.disabled_stack.is_empty();
+
         self.nodes.insert(id, LayoutNode::new(id));
-        let (size, interest, interactive) = nodes
+        let (size, mut interest, mut interactive) = nodes
             .scoped(id, |node| {
                 self.axis_stack.push(node.primary_axis());
                 let layout = Layout {
@@ -341,6 +451,17 @@ impl LayoutNodes {
             })
             .unwrap();
 
+        let disabled = !self.disabled_stack.is_empty();
+        if disabled {
+            (interest, interactive) = (Interest::NONE, false);
+            if input.focus() == Some(id) {
+                input.set_focus(None);
+            }
+        }
+        if self.disabled_stack.last() == Some(&id) {
+            self.disabled_stack.pop();
+        }
+
         let new_layer = self.interest.current_layer_root() == Some(id);
         if !interest.is_none() {
             self.interest.insert(id, interest);
@@ -364,7 +485,17 @@ impl LayoutNodes {
             layout.clipped_by = clipped_by;
             layout.interest = interest;
             layout.interactive = interactive;
+            layout.disabled = disabled;
             layout.rect.set_size(size);
+            layout.local = layout.rect;
+            layout.cache = self.hashes.get(id).map(|&hash| LayoutCache {
+                hash,
+                space,
+                size,
+                parent_axis,
+                parent_clip,
+                parent_disabled,
+            });
         };
 
         if clipping_enabled {
@@ -376,6 +507,102 @@ impl LayoutNodes {
         size
     }
 
+    // if nothing that affects the layout of this subtree has changed, its previous layout is used
+    fn reuse(
+        &mut self,
+        nodes: &ViewNodes,
+        input: &mut InputState,
+        id: ViewId,
+        space: Space,
+    ) -> Option<Size> {
+        let hash = *self.hashes.get(id)?;
+        let cache = self.nodes.get(id)?.cache?;
+
+        let same = cache.hash == hash
+            && cache.space == space
+            && cache.parent_axis == self.axis_stack.last().copied()
+            && cache.parent_clip == self.clip_stack.last().copied()
+            && cache.parent_disabled != self.disabled_stack.is_empty();
+        if !same {
+            return None;
+        }
+
+        let node = &mut self.nodes[id];
+        node.rect = Rect::ZERO;
+        node.rect.set_size(cache.size);
+        node.local = node.rect;
+
+        self.replay(nodes, input, id);
+        Some(cache.size)
+    }
+
+    // restores the unresolved rects and the event interests for a reused subtree
+    fn replay(&mut self, nodes: &ViewNodes, input: &mut InputState, id: ViewId) {
+        let Some(node) = nodes.get(id) else {
+            return;
+        };
+        let Some(layout) = self.nodes.get(id) else {
+            return;
+        };
+
+        let (new_layer, interest) = (layout.new_layer, layout.interest);
+        // a disabled view can't keep the focus, even if it was focused after it was laid out
+        if layout.disabled && input.focus() == Some(id) {
+            input.set_focus(None);
+        }
+        if new_layer {
+            self.interest.push_layer(id);
+        }
+
+        for &child in &node.children {
+            if let Some(layout) = self.nodes.get_mut(child) {
+                layout.rect = layout.local;
+            }
+            self.replay(nodes, input, child);
+        }
+
+        if !interest.is_none() {
+            self.interest.insert(id, interest);
+        }
+        if new_layer {
+            self.interest.pop_layer();
+        }
+    }
+
+    // a subtree can only be cached if every view in it provides a layout hash
+    fn hash_subtree(&mut self, nodes: &ViewNodes, id: ViewId) -> Option<u64> {
+        let node = nodes.get(id)?;
+
+        let mut hasher = DefaultHasher::new();
+        let mut cacheable = true;
+        for &child in &node.children {
+            match self.hash_subtree(nodes, child) {
+                Some(hash) => {
+                    child.data().as_ffi().hash(&mut hasher);
+                    hash.hash(&mut hasher);
+                }
+                None => cacheable = false,
+            }
+        }
+
+        if !cacheable {
+            return None;
+        }
+
+        let view = node.view.borrow();
+        view.layout_hash()?.hash(&mut hasher);
+        view.interests().hash(&mut hasher);
+        view.interactive().hash(&mut hasher);
+        view.primary_axis().hash(&mut hasher);
+        let flex = view.flex();
+        flex.is_expand().hash(&mut hasher);
+        flex.factor().to_bits().hash(&mut hasher);
+
+        let hash = hasher.finish();
+        self.hashes.insert(id, hash);
+        Some(hash)
+    }
+
     pub(super) fn set_layer(&mut self, current: ViewId, layer: Layer) {
         self.nodes[current].layer = layer;
     }
@@ -383,6 +610,7 @@ impl LayoutNodes {
     pub(crate) fn begin(&mut self) {
         self.clip_stack.clear();
         self.axis_stack.clear();
+        self.disabled_stack.clear();
     }
 
     pub(super) fn end(&mut self) {
@@ -392,12 +620,19 @@ impl LayoutNodes {
     #[cfg_attr(feature = "profile", profiling::function)]
     pub(super) fn compute_all(&mut self, nodes: &ViewNodes, input: &mut InputState, rect: Rect) {
         let space = Space::from_size(rect.size().into()).loosen();
+
+        self.stats = LayoutStats::default();
+        self.hashes.clear();
+        self.hash_subtree(nodes, nodes.root());
+
         self.compute(nodes, input, nodes.root(), space);
         self.resolve(nodes, rect);
     }
 
     #[cfg_attr(feature = "profile", profiling::function)]
     pub(super) fn resolve(&mut self, nodes: &ViewNodes, rect: Rect) {
+        // rects are relative to their parent until they are resolved
+        let bounds = rect.translate(-rect.left_top().to_vec2());
         let mut queue = VecDeque::from([(nodes.root(), rect.left_top())]);
         while let Some((id, pos)) = queue.pop_front() {
             let Some(layout) = self.nodes.get_mut(id) else {
@@ -414,9 +649,9 @@ impl LayoutNodes {
 
             // we can't clamp for things -x or -y
             if !layout.rect.min.x.is_negative() && !layout.rect.min.y.is_negative() {
-                layout.rect.min = layout.rect.min.clamp(rect.min, rect.max);
+                layout.rect.min = layout.rect.min.clamp(bounds.min, bounds.max);
             }
-            layout.rect.max = layout.rect.max.clamp(rect.min, rect.max);
+            layout.rect.max = layout.rect.max.clamp(bounds.min, bounds.max);
             layout.rect = layout.rect.translate(pos.to_vec2());
 
             queue.extend(node.children.iter().map(|&id| (id, layout.rect.min)))
@@ -466,10 +701,26 @@ pub struct LayoutNode {
     pub clipping_enabled: bool,
     /// Who is clipping the view
     pub clipped_by: Option<ViewId>,
+    /// Can the view draw outside of its rect?
+    pub overflow_visible: bool,
     /// The event interests of the view
     pub interest: Interest,
     /// Is this node interactive?
     pub interactive: bool,
+    disabled: bool,
+    local: Rect,
+    cache: Option<LayoutCache>,
+}
+
+/// The inputs and output of the last time a view was measured
+#[derive(Copy, Clone, Debug)]
+struct LayoutCache {
+    hash: u64,
+    space: Space,
+    size: Size,
+    parent_axis: Option<Axis>,
+    parent_clip: Option<ViewId>,
+    parent_disabled: bool,
 }
 
 impl LayoutNode {
@@ -481,8 +732,12 @@ impl LayoutNode {
             new_layer: false,
             clipping_enabled: false,
             clipped_by: None,
+            overflow_visible: false,
             interest: Interest::NONE,
             interactive: false,
+            disabled: false,
+            local: Rect::ZERO,
+            cache: None,
         }
     }
 
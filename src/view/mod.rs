@@ -8,16 +8,19 @@ mod response;
 pub use response::Response;
 
 mod input;
-pub use input::{EventCtx, Handled, InputState, Interest, ViewEvent};
+pub use input::{ClipboardHandle, EventCtx, Handled, InputState, Interest, ViewEvent};
 
 mod filter;
 pub use filter::{Depth, Filter, Filterable};
 
+mod frame_stats;
+pub use frame_stats::FrameStats;
+
 mod ui;
 pub use ui::Ui;
 
 mod layout;
-pub use layout::{IntrinsicSize, Layer, Layout, LayoutNode, LayoutNodes};
+pub use layout::{IntrinsicSize, Layer, Layout, LayoutNode, LayoutNodes, LayoutStats};
 
 mod render;
 pub use render::{CroppedSurface, Render};
@@ -26,12 +29,12 @@ mod view_nodes;
 pub use view_nodes::{ViewNode, ViewNodes};
 
 mod style;
-pub use style::{Elements, Palette, StyleKind};
+pub use style::{Elements, Palette, PaletteParseError, StyleKind};
 
 mod internal_views;
 
 mod builder;
-pub use builder::{Builder, View, ViewExt};
+pub use builder::{Builder, View, ViewExt, ViewMarker};
 
 mod erased;
 use erased::Erased;
@@ -47,9 +50,19 @@ pub mod test;
 
 // TODO get rid of this
 use crate::math::Size;
+
+/// The size of this text, once its tabs are expanded and its control characters replaced
+///
+/// This is the same width as a default [`TextShape`](crate::renderer::TextShape) draws
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// # use too::{math::Size, renderer::TextShape, view::measure_text};
+/// assert_eq!(measure_text("a\tb\r"), Size::new(6.0, 1.0));
+/// assert_eq!(measure_text("a\tb\r").width as usize, TextShape::new("a\tb\r").width());
+/// ```
 #[inline(always)]
 #[deprecated(note = "don't use this, use Text when its implemented")]
 pub fn measure_text(data: &str) -> Size {
-    use unicode_width::UnicodeWidthStr as _;
-    Size::new(data.width() as f32, 1.0)
+    Size::new(crate::renderer::TextShape::new(data).width() as f32, 1.0)
 }
@@ -1,12 +1,15 @@
-use std::{collections::VecDeque, ops::RangeInclusive};
+use std::{collections::VecDeque, ops::RangeInclusive, sync::Arc};
 
 use unicode_segmentation::UnicodeSegmentation as _;
 
 use crate::{
-    animation::Animations,
+    animation::{Animation, Animations, Id},
     layout::Axis,
     math::{pos2, Pos2, Rect, Vec2},
-    renderer::{Attribute, Cell, Grapheme, Pixel, Rasterizer, Rgba, Surface, TextShape},
+    renderer::{
+        shapes::Drawable, Attribute, Cell, GradientFill, Grapheme, Pixel, Rasterizer, Rgba,
+        Surface, TextShape,
+    },
 };
 
 use super::{
@@ -33,6 +36,7 @@ pub struct Render<'a, 'b> {
     pub animation: &'a mut Animations,
 
     pub(super) rect: Rect,
+    pub(super) clip: Rect,
     pub(super) pending: &'a mut VecDeque<ViewId>,
     pub(super) rasterizer: &'b mut dyn Rasterizer,
     pub(super) render: &'a mut RenderNodes,
@@ -55,6 +59,23 @@ impl<'a, 'b> Render<'a, 'b> {
         );
     }
 
+    // a render context for the same view, so a wrapper can draw after its inner view
+    pub(crate) fn reborrow(&mut self) -> Render<'_, '_> {
+        Render {
+            current: self.current,
+            nodes: self.nodes,
+            layout: self.layout,
+            palette: self.palette,
+            animation: self.animation,
+            rect: self.rect,
+            clip: self.clip,
+            pending: self.pending,
+            rasterizer: self.rasterizer,
+            render: self.render,
+            input: self.input,
+        }
+    }
+
     /// Get the current mouse position
     pub fn mouse_pos(&self) -> Pos2 {
         self.input.mouse_pos()
@@ -85,6 +106,18 @@ impl<'a, 'b> Render<'a, 'b> {
         self.input.is_hovered(self.current)
     }
 
+    /// Is the current view, or any of its descendants, focused?
+    ///
+    /// Combined with [`Render::is_window_focused`], a view could dim itself when nothing inside of it has focus
+    pub fn is_focus_within(&self) -> bool {
+        self.input.is_focus_within(self.nodes, self.current)
+    }
+
+    /// Does the terminal window have focus?
+    pub fn is_window_focused(&self) -> bool {
+        self.input.is_window_focused()
+    }
+
     /// Is the current view's parent focused?
     pub fn is_parent_focused(&self) -> bool {
         self.input.is_focused(self.nodes.parent())
@@ -116,7 +149,8 @@ impl<'a, 'b> Render<'a, 'b> {
 
     /// Crop this render context to a new rect, giving you a closure to the new render context
     ///
-    /// The provided rect cannot exceed the rect given to you by the initial render context.
+    /// The provided rect cannot exceed the rect given to you by the initial render context,
+    /// unless the view [enabled overflow](crate::view::Layout::enable_overflow).
     ///
     /// The new render context will be localized to the rect. e.g. origin will be the top-left of this new rect
     ///
@@ -125,7 +159,7 @@ impl<'a, 'b> Render<'a, 'b> {
         let old = self.rasterizer.rect();
 
         let offset = self.rect.left_top().to_vec2();
-        let rect = self.rect.intersection(rect.translate(offset));
+        let rect = self.clip.intersection(rect.translate(offset));
         self.rasterizer.set_rect(rect);
         render(self);
 
@@ -135,6 +169,15 @@ impl<'a, 'b> Render<'a, 'b> {
     pub fn filter(&self) -> Filter<'_> {
         <Self as Filterable>::filter(self)
     }
+
+    /// Start an animation for this `id`, or reuse the one that is already running, returning its current value
+    ///
+    /// See [`Ui::animate`](crate::view::Ui::animate)
+    pub fn animate(&mut self, id: impl Into<Id>, animation: Animation) -> f32 {
+        let id = id.into();
+        self.animation.add_once(id, || animation);
+        *self.animation.value(id).value
+    }
 }
 
 impl<'a, 'b> Render<'a, 'b> {
@@ -253,6 +296,67 @@ impl<'a, 'b> Render<'a, 'b> {
         }
         self
     }
+
+    /// Copy the cells of a [`Surface`] into the view, with its top-left corner at `at`
+    ///
+    /// This is useful for drawing something expensive once, and reusing it each frame.
+    ///
+    /// Only the cells that land inside of the region being drawn are copied
+    ///
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use too::{math::{pos2, vec2}, renderer::{Pixel, Surface}, view::{test::render_to_string, Ui}, views::canvas};
+    /// let mut panel = Surface::new(vec2(100, 100));
+    /// for (x, ch) in (0..).zip("hello".chars()) {
+    ///     panel.set(pos2(x, 0), Pixel::new(ch));
+    /// }
+    /// let panel = Arc::new(panel);
+    ///
+    /// let out = render_to_string(|ui: &Ui| {
+    ///     let panel = Arc::clone(&panel);
+    ///     ui.exact_size(vec2(3, 1), |ui| {
+    ///         ui.show(canvas(move |_, render| _ = render.blit(pos2(-1, 0), &panel)));
+    ///     });
+    /// });
+    /// assert_eq!(out.lines().next().unwrap().trim_end(), "ell");
+    /// ```
+    pub fn blit(&mut self, at: impl Into<Pos2>, src: &Surface) -> &mut Self {
+        let at = at.into();
+        let region = Rect::from_min_size(Pos2::ZERO, self.rasterizer.rect().size());
+        let visible = region.intersection(src.rect().translate(at.to_vec2()));
+        for y in visible.top()..visible.bottom() {
+            for x in visible.left()..visible.right() {
+                let pos = pos2(x, y);
+                match src.get(pos - at) {
+                    None | Some(Cell::Empty | Cell::Continuation) => {}
+                    Some(cell) => _ = self.set(pos, cell.clone()),
+                }
+            }
+        }
+        self
+    }
+
+    /// Fill this render context with a [`GradientFill`]
+    pub fn fill_gradient(&mut self, fill: GradientFill) -> &mut Self {
+        self.shape(&fill)
+    }
+
+    /// Draw a [`Drawable`] into this render context, its given the size of the local rect
+    ///
+    /// This can draw a [`GradientFill`], a [`HalfBlockCanvas`](crate::renderer::HalfBlockCanvas), a [`BrailleCanvas`](crate::renderer::BrailleCanvas) or a [`Polyline`](crate::renderer::Polyline)
+    pub fn shape(&mut self, shape: &(impl Drawable + ?Sized)) -> &mut Self {
+        let size = self.local_rect().size();
+        shape.draw(size, &mut |pos, pixel| self.rasterizer.pixel(pos, pixel));
+        self
+    }
+
+    /// Sets many pixels at specific positions
+    pub fn pixels(&mut self, pixels: impl IntoIterator<Item = (Pos2, Pixel)>) -> &mut Self {
+        for (pos, pixel) in pixels {
+            self.rasterizer.pixel(pos, pixel);
+        }
+        self
+    }
 }
 
 #[derive(Default)]
@@ -317,6 +421,16 @@ impl RenderNodes {
             }
         }
 
+        // overflowing views are still placed at their rect, but can be cropped out to their clipping ancestor
+        let clip = if node.overflow_visible {
+            node.clipped_by
+                .or(Some(nodes.root()))
+                .and_then(|id| layout.nodes.get(id))
+                .map_or(rect, |node| node.rect)
+        } else {
+            rect
+        };
+
         rasterizer.begin(id);
         nodes.begin(id);
 
@@ -335,6 +449,7 @@ impl RenderNodes {
                     pending,
                     render: self,
                     rect,
+                    clip,
                 };
                 node.draw(render);
                 self.axis_stack.pop();
@@ -409,11 +524,15 @@ impl<'a> Rasterizer for CroppedSurface<'a> {
     }
 
     fn text(&mut self, shape: TextShape<'_>) {
-        for (x, g) in shape.label.graphemes(true).enumerate() {
+        let link = shape.link.as_deref().map(Arc::<str>::from);
+        for (x, g) in shape.sanitized().graphemes(true).enumerate() {
             let mut cell = Grapheme::new(g).fg(shape.fg).bg(shape.bg);
             if let Some(attr) = shape.attribute {
                 cell = cell.attribute(attr)
             }
+            if let Some(link) = &link {
+                cell = cell.link(Arc::clone(link))
+            }
             self.set(pos2(x as i32, 0), cell);
         }
     }
@@ -1,14 +1,14 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, time::Duration};
 
 use compact_str::{CompactString, ToCompactString};
 
 use crate::{
     animation::Animations,
-    backend::Event,
+    backend::{Command, Event, Key},
     helpers::Queue,
     layout::{Anchor2, LinearAllocator, LinearLayout},
     lock::{Lock, Ref},
-    math::{Rect, Vec2},
+    math::{lerp, Pos2, Rect, Vec2},
     renderer::{Rasterizer, TextShape},
     Str,
 };
@@ -17,8 +17,12 @@ use crate::{
 use super::measure_text;
 
 use super::{
-    input::InputState, render::RenderNodes, style::Palette, ui::Ui, Layer, LayoutNode, LayoutNodes,
-    ViewId, ViewNodes,
+    frame_stats::{FrameStats, FrameTimes},
+    input::{ClipboardHandle, Handled, InputState},
+    render::RenderNodes,
+    style::Palette,
+    ui::Ui,
+    Layer, LayoutNode, LayoutNodes, LayoutStats, ViewId, ViewNodes,
 };
 
 // TODO what of this should actually be public?
@@ -56,16 +60,38 @@ use super::{
 /// - frame time state
 /// - building the ui
 /// - rendering
+///
+/// ## Viewports
+/// A state can also host independent Ui trees, called viewports, with [`State::viewport`].
+///
+/// Each viewport has its own view tree, layout and input state, and is drawn ontop of the main Ui into its own [`Rect`].
+/// - mouse events are given to the viewport that contains the cursor
+/// - keyboard events are given to the viewport that was last clicked
 pub struct State {
     pub(in crate::view) nodes: ViewNodes,
     pub(in crate::view) layout: LayoutNodes,
     pub(in crate::view) render: RenderNodes,
     pub(in crate::view) input: InputState,
-    pub(in crate::view) animations: Animations,
+    pub(in crate::view) animations: Lock<Animations>,
     pub(in crate::view) palette: Lock<Palette>,
+    pub(in crate::view) commands: Lock<Vec<Command>>,
     pub(in crate::view) frame_count: u64,
     pub(in crate::view) dt: f32,
+    pub(in crate::view) frame_time: f32,
+    pub(in crate::view) frame_times: FrameTimes,
+    pub(in crate::view) frame_stats: FrameStats,
     pub(in crate::view) size_changed: Option<Vec2>,
+    pub(in crate::view) viewports: Vec<Viewport>,
+    pub(in crate::view) hovered_viewport: Option<Str>,
+    pub(in crate::view) focused_viewport: Option<Str>,
+}
+
+/// An independent Ui tree hosted by a [`State`]
+pub(in crate::view) struct Viewport {
+    name: Str,
+    rect: Rect,
+    state: State,
+    built: bool,
 }
 
 impl Default for State {
@@ -86,11 +112,18 @@ impl State {
             layout,
             render: RenderNodes::new(),
             input: InputState::default(),
-            animations,
+            animations: Lock::new(animations),
             palette: Lock::new(palette),
+            commands: Lock::new(Vec::new()),
             frame_count: 0,
             dt: 1.0,
+            frame_time: 0.0,
+            frame_times: FrameTimes::default(),
+            frame_stats: FrameStats::default(),
             size_changed: None,
+            viewports: Vec::new(),
+            hovered_viewport: None,
+            focused_viewport: None,
         }
     }
 
@@ -104,6 +137,60 @@ impl State {
         self.palette.borrow()
     }
 
+    /// How many views had their layout reused, or measured, during the last build
+    ///
+    /// ```rust
+    /// # use too::{view::{State, LayoutStats}, math::{pos2, vec2, Rect}, views::Padding, view::ViewExt as _};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(80, 25));
+    ///
+    /// let show = |ui: &too::view::Ui| {
+    ///     Padding::new(1).show_children(ui, |ui| ui.label("hello"));
+    /// };
+    ///
+    /// // the first frame measures everything (the root, the padding and the label)
+    /// state.build(rect, show);
+    /// assert_eq!(state.layout_stats(), LayoutStats { hits: 0, misses: 3 });
+    ///
+    /// // the padded label didn't change, so it isn't measured again
+    /// state.build(rect, show);
+    /// assert_eq!(state.layout_stats(), LayoutStats { hits: 1, misses: 1 });
+    /// ```
+    pub fn layout_stats(&self) -> LayoutStats {
+        self.layout.stats()
+    }
+
+    /// Is anything animating?
+    ///
+    /// This is any [animation](crate::animation::Animations::is_active) that's still moving, or a view interested in [`Interest::ANIMATE`](crate::view::Interest::ANIMATE)
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, view::{test::hover, State}};
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| _ = ui.button("hello");
+    /// state.build(Rect::from_min_size(pos2(0, 0), vec2(80, 25)), app);
+    /// assert!(!state.is_animating());
+    ///
+    /// // hovering the button fades its background
+    /// hover(&mut state, pos2(1, 0), app);
+    /// assert!(state.is_animating());
+    ///
+    /// state.update(1.0);
+    /// assert!(!state.is_animating());
+    /// ```
+    pub fn is_animating(&self) -> bool {
+        self.animations.borrow().is_active()
+            || self
+                .layout
+                .interest
+                .iter()
+                .any(|(_, interest)| interest.is_animate())
+            || self
+                .viewports
+                .iter()
+                .any(|viewport| viewport.state.is_animating())
+    }
+
     /// Get the root id for the current State Ui tree
     pub fn root(&self) -> ViewId {
         self.nodes.root()
@@ -120,19 +207,362 @@ impl State {
             Debug::resize(size.y as usize);
         }
 
+        if !self.viewports.is_empty() && self.route_viewport_event(event) {
+            return;
+        }
+
         // TODO debounce 'event'
-        let _resp = self.input.update(
+        let resp = self.input.update(
             &self.nodes, //
             &self.layout,
-            &mut self.animations,
+            self.animations.get_mut(),
             event,
         );
+
+        // if the focused view didn't want the tab, use it to move the focus
+        if let (
+            Handled::Bubble,
+            Event::KeyPressed {
+                key: Key::Tab,
+                modifiers,
+            },
+        ) = (resp, event)
+        {
+            if modifiers.is_shift() {
+                self.focus_prev();
+            } else {
+                self.focus_next();
+            }
+        }
+    }
+
+    /// Move the focus to the next view that wants key input
+    ///
+    /// Views are visited in layout order, wrapping around at the end. Disabled views are skipped.
+    ///
+    /// Pressing `Tab` does this, if the focused view doesn't handle it
+    ///
+    /// ```rust
+    /// # use too::{view::State, math::{pos2, vec2, Rect}, backend::{Event, Key, Modifiers}, views::text_input, view::ViewExt as _};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(80, 25));
+    ///
+    /// let mut build = |state: &mut State| {
+    ///     state.build(rect, |ui| {
+    ///         ui.vertical(|ui| {
+    ///             let a = ui.show(text_input()).id();
+    ///             let _disabled = ui.show(text_input().enabled(false));
+    ///             let b = ui.show(text_input()).id();
+    ///             (a, b)
+    ///         }).into_inner()
+    ///     })
+    /// };
+    ///
+    /// let tab = |state: &mut State, modifiers| {
+    ///     state.event(&Event::KeyPressed { key: Key::Tab, modifiers })
+    /// };
+    ///
+    /// let (a, b) = build(&mut state);
+    ///
+    /// tab(&mut state, Modifiers::NONE);
+    /// assert_eq!(state.focus(), Some(a));
+    ///
+    /// // the disabled one is skipped
+    /// tab(&mut state, Modifiers::NONE);
+    /// assert_eq!(state.focus(), Some(b));
+    ///
+    /// // and it wraps around
+    /// tab(&mut state, Modifiers::NONE);
+    /// assert_eq!(state.focus(), Some(a));
+    ///
+    /// tab(&mut state, Modifiers::SHIFT);
+    /// assert_eq!(state.focus(), Some(b));
+    /// ```
+    pub fn focus_next(&mut self) -> bool {
+        self.input.focus_next(
+            &self.nodes, //
+            &self.layout,
+            self.animations.get_mut(),
+        )
     }
 
-    /// Update any animations with the frame delta
+    /// Move the focus to the previous view that wants key input
+    ///
+    /// Views are visited in reverse layout order, wrapping around at the start. Disabled views are skipped.
+    ///
+    /// Pressing `Shift+Tab` does this, if the focused view doesn't handle it
+    pub fn focus_prev(&mut self) -> bool {
+        self.input.focus_prev(
+            &self.nodes, //
+            &self.layout,
+            self.animations.get_mut(),
+        )
+    }
+
+    /// Clicks closer together than this (and about the same place) count towards a double or triple click
+    ///
+    /// This defaults to 300 milliseconds, and is shared with any viewports.
+    pub fn set_multi_click_window(&mut self, window: Duration) {
+        self.input.set_multi_click_window(window);
+        for viewport in &mut self.viewports {
+            viewport.state.set_multi_click_window(window);
+        }
+    }
+
+    /// How far, in cells, the mouse has to move from where a button was pressed before it starts a drag
+    ///
+    /// Moving less than this and releasing the button is still a click.
+    /// Once the drag starts, its first delta includes the movement from where the button was pressed.
+    ///
+    /// This defaults to 1 cell, and is shared with any viewports.
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Modifiers, MouseButton}, math::{pos2, vec2, Rect}, view::State, views::mouse_area};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    /// state.set_drag_threshold(2);
+    ///
+    /// let mut build = |state: &mut State| {
+    ///     state.build(rect, |ui| ui.show_children(mouse_area(), |ui| _ = ui.label("hello world")).into_inner().0)
+    /// };
+    /// let (button, modifiers) = (MouseButton::Primary, Modifiers::NONE);
+    ///
+    /// build(&mut state);
+    /// state.event(&Event::MouseMove { pos: pos2(1, 0) });
+    /// state.event(&Event::MouseButtonChanged { pos: pos2(1, 0), button, down: true, modifiers });
+    ///
+    /// // a small movement isn't a drag, so releasing the button is a click
+    /// state.event(&Event::MouseDrag { pos: pos2(3, 0), button, modifiers });
+    /// state.event(&Event::MouseButtonChanged { pos: pos2(3, 0), button, down: false, modifiers });
+    /// let resp = build(&mut state);
+    /// assert!(resp.clicked() && resp.dragged().is_none());
+    ///
+    /// // moving further starts a drag, from where the button was pressed
+    /// state.event(&Event::MouseButtonChanged { pos: pos2(1, 0), button, down: true, modifiers });
+    /// state.event(&Event::MouseDrag { pos: pos2(2, 0), button, modifiers });
+    /// state.event(&Event::MouseDrag { pos: pos2(4, 0), button, modifiers });
+    /// let resp = build(&mut state);
+    /// let drag = resp.dragged().unwrap();
+    /// assert_eq!((drag.start(), drag.current()), (pos2(1, 0), pos2(4, 0)));
+    /// ```
+    pub fn set_drag_threshold(&mut self, cells: u32) {
+        self.input.set_drag_threshold(cells);
+        for viewport in &mut self.viewports {
+            viewport.state.set_drag_threshold(cells);
+        }
+    }
+
+    /// Use this clipboard for copying and pasting
+    ///
+    /// This defaults to [`NoClipboard`](crate::backend::NoClipboard), and is shared with any viewports.
+    pub fn set_clipboard(&mut self, clipboard: ClipboardHandle) {
+        self.input.set_clipboard(clipboard);
+    }
+
+    /// The view that currently receives key input, if any
+    pub fn focus(&self) -> Option<ViewId> {
+        self.input.focus()
+    }
+
+    // how much each frame contributes to the average frame time
+    const FRAME_TIME_SMOOTHING: f32 = 0.1;
+
+    /// Update any animations with the frame delta, scaled by [`Animations::set_time_scale`]
+    ///
+    /// This also updates the average frame time, which the [`Ui`] reports with [`Ui::frame_time`] and [`Ui::fps`],
+    /// and the [`FrameStats`] it reports with [`Ui::frame_stats`]
+    ///
+    /// Then views interested in [`Interest::ANIMATE`](crate::view::Interest::ANIMATE) get a [`ViewEvent::Tick`](crate::view::ViewEvent::Tick), with the unscaled delta
     pub fn update(&mut self, dt: f32) {
-        self.animations.update(dt);
+        self.animations.get_mut().update(dt);
+        self.input.send_tick(
+            &self.nodes, //
+            &self.layout,
+            self.animations.get_mut(),
+            dt,
+        );
         self.dt = dt;
+        self.frame_time = if self.frame_time == 0.0 {
+            dt
+        } else {
+            lerp(self.frame_time, dt, Self::FRAME_TIME_SMOOTHING)
+        };
+        self.frame_times.push(dt);
+        self.frame_stats = self.frame_times.stats(self.frame_time);
+        for viewport in &mut self.viewports {
+            viewport.state.update(dt);
+        }
+    }
+
+    /// Build an independent Ui tree, named `name`, contained in the provided [`Rect`]
+    ///
+    /// The viewport keeps its state across frames as long as it is built every frame.
+    ///
+    /// ```rust
+    /// # use too::{view::State, math::{pos2, vec2, Rect}, backend::{Event, Modifiers, MouseButton}};
+    /// let mut state = State::default();
+    /// let left = Rect::from_min_size(pos2(0, 0), vec2(40, 25));
+    /// let right = Rect::from_min_size(pos2(40, 0), vec2(40, 25));
+    ///
+    /// let mut build = |state: &mut State| {
+    ///     let a = state.viewport("left", left, |ui| ui.mouse_area(|ui| ui.label("hello")));
+    ///     let b = state.viewport("right", right, |ui| ui.mouse_area(|ui| ui.label("hello")));
+    ///     (a.flatten_left().hovered(), b.flatten_left().hovered())
+    /// };
+    ///
+    /// build(&mut state);
+    /// state.event(&Event::MouseMove { pos: pos2(41, 0) });
+    /// assert_eq!(build(&mut state), (false, true));
+    /// assert_eq!(state.hovered_viewport(), Some("right"));
+    ///
+    /// state.event(&Event::MouseMove { pos: pos2(1, 0) });
+    /// assert_eq!(build(&mut state), (true, false));
+    /// assert_eq!(state.hovered_viewport(), Some("left"));
+    ///
+    /// // clicking in a viewport gives it the keyboard
+    /// state.event(&Event::MouseButtonChanged {
+    ///     pos: pos2(1, 0),
+    ///     button: MouseButton::Primary,
+    ///     down: true,
+    ///     modifiers: Modifiers::NONE,
+    /// });
+    /// assert_eq!(state.focused_viewport(), Some("left"));
+    /// ```
+    pub fn viewport<R: 'static>(
+        &mut self,
+        name: impl Into<Str>,
+        rect: Rect,
+        show: impl FnMut(&Ui) -> R,
+    ) -> R {
+        let name = name.into();
+        let palette = *self.palette.get_mut();
+
+        let index = match self.viewports.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                self.viewports.push(Viewport {
+                    name,
+                    rect,
+                    state: State::new(palette, Animations::default()),
+                    built: false,
+                });
+                self.viewports.len() - 1
+            }
+        };
+
+        let viewport = &mut self.viewports[index];
+        viewport.rect = rect;
+        viewport.built = true;
+        viewport.state.set_palette(palette);
+        viewport
+            .state
+            .input
+            .set_multi_click_window(self.input.multi_click_window());
+        viewport
+            .state
+            .input
+            .set_drag_threshold(self.input.drag_threshold());
+        viewport
+            .state
+            .input
+            .set_clipboard(self.input.clipboard().clone());
+        viewport.state.build(rect, show)
+    }
+
+    /// Take the [`Command`]s that were sent with [`Ui::command`] since the last time this was called
+    ///
+    /// An event loop should forward these to its [`Backend`](crate::backend::Backend). This includes the commands sent from viewports
+    ///
+    /// ```rust
+    /// # use too::{backend::Command, view::State, math::{pos2, vec2, Rect}};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    ///
+    /// state.build(rect, |ui| ui.command(Command::set_title("hello")));
+    /// assert_eq!(state.take_commands(), vec![Command::set_title("hello")]);
+    /// assert!(state.take_commands().is_empty());
+    /// ```
+    pub fn take_commands(&mut self) -> Vec<Command> {
+        let mut commands = std::mem::take(self.commands.get_mut());
+        for viewport in &mut self.viewports {
+            commands.extend(viewport.state.take_commands());
+        }
+        commands
+    }
+
+    /// The name of the viewport that the mouse is currently over, if any
+    pub fn hovered_viewport(&self) -> Option<&str> {
+        self.hovered_viewport.as_deref()
+    }
+
+    /// The name of the viewport that receives keyboard input, if any
+    ///
+    /// If this is `None` then the main Ui receives keyboard input
+    pub fn focused_viewport(&self) -> Option<&str> {
+        self.focused_viewport.as_deref()
+    }
+
+    /// Sets (or unsets) the viewport that receives keyboard input
+    pub fn set_focused_viewport(&mut self, name: Option<&str>) {
+        self.focused_viewport = name.map(Str::from);
+    }
+
+    // returns true if a viewport consumed the event
+    fn route_viewport_event(&mut self, event: &Event) -> bool {
+        let target = match *event {
+            Event::MouseMove { pos } | Event::MouseButtonChanged { pos, .. } => {
+                let target = self.viewport_at(pos);
+                if target != self.hovered_viewport {
+                    self.viewport_mouse_left(self.hovered_viewport.clone());
+                    self.hovered_viewport = target.clone();
+                }
+                if let Event::MouseButtonChanged { down: true, .. } = event {
+                    self.focused_viewport = target.clone();
+                }
+                target
+            }
+            Event::MouseDrag { .. } | Event::MouseScroll { .. } => self.hovered_viewport.clone(),
+            Event::KeyPressed { .. } | Event::Paste(..) => self.focused_viewport.clone(),
+            _ => {
+                for viewport in &mut self.viewports {
+                    viewport.state.event(event);
+                }
+                return false;
+            }
+        };
+
+        let Some(viewport) =
+            target.and_then(|name| self.viewports.iter_mut().find(|c| c.name == name))
+        else {
+            return false;
+        };
+
+        viewport.state.event(event);
+        true
+    }
+
+    fn viewport_at(&self, pos: Pos2) -> Option<Str> {
+        self.viewports
+            .iter()
+            .rev()
+            .find(|c| c.rect.contains(pos))
+            .map(|c| c.name.clone())
+    }
+
+    fn viewport_mouse_left(&mut self, name: Option<Str>) {
+        let state = match name {
+            Some(name) => match self.viewports.iter_mut().find(|c| c.name == name) {
+                Some(viewport) => &mut viewport.state,
+                None => return,
+            },
+            None => self,
+        };
+
+        state.input.mouse_left(
+            &state.nodes, //
+            &state.layout,
+            state.animations.get_mut(),
+        );
     }
 
     /// Build the ui state contained in the provided [`Rect`]
@@ -158,6 +588,19 @@ impl State {
     /// Render the current state to a [`Rasterizer`]
     #[cfg_attr(feature = "profile", profiling::function)]
     pub fn render(&mut self, rasterizer: &mut impl Rasterizer) {
+        let rect = self.render_nodes(rasterizer);
+
+        // viewports that weren't built since the last render are gone
+        self.viewports.retain_mut(|c| std::mem::take(&mut c.built));
+        for viewport in &mut self.viewports {
+            rasterizer.set_rect(viewport.rect);
+            viewport.state.render_nodes(rasterizer);
+        }
+
+        self.render_debug(rect, rasterizer);
+    }
+
+    fn render_nodes(&mut self, rasterizer: &mut impl Rasterizer) -> Rect {
         self.frame_count += 1;
 
         let root = self.root();
@@ -173,7 +616,7 @@ impl State {
             &self.input,
             self.palette.get_mut(),
             &mut pending,
-            &mut self.animations,
+            self.animations.get_mut(),
             rasterizer,
         );
 
@@ -188,7 +631,7 @@ impl State {
                     &self.input,
                     self.palette.get_mut(),
                     &mut pending,
-                    &mut self.animations,
+                    self.animations.get_mut(),
                     rasterizer,
                 );
                 if pending.back() == Some(&id) {
@@ -197,22 +640,30 @@ impl State {
             }
         }
 
-        self.render_debug(rect, rasterizer);
+        rect
     }
 
     #[cfg_attr(feature = "profile", profiling::function)]
     fn render_debug(&self, rect: Rect, rasterizer: &mut impl Rasterizer) {
         Debug::with(|c| {
             let mut debug = c.queue.borrow_mut();
-            if debug.is_empty() {
+            let show_stats =
+                *c.layout_stats.borrow() && !matches!(*c.mode.borrow(), DebugMode::Off);
+            if debug.is_empty() && !show_stats {
                 return;
             }
 
             let mut layout = LinearLayout::vertical()
-                .wrap(false)
+                .grid_wrap(true)
                 .anchor(*c.anchor.borrow())
                 .layout(rect);
 
+            if show_stats {
+                let LayoutStats { hits, misses } = self.layout.stats();
+                let msg = crate::format_str!("layout: {hits} reused, {misses} measured");
+                Debug::render(rasterizer, &mut layout, &msg);
+            }
+
             match *c.mode.borrow() {
                 DebugMode::PerFrame => {
                     for msg in debug.drain() {
@@ -240,13 +691,15 @@ impl State {
         self.input.begin(
             &self.nodes, //
             &self.layout,
-            &mut self.animations,
+            self.animations.get_mut(),
         );
     }
 
     fn end(&mut self) {
         for id in self.nodes.finish() {
             self.layout.nodes.remove(id);
+            // a view's animations go away with it
+            self.animations.get_mut().remove(id);
         }
         self.input.end();
         self.layout.end();
@@ -274,6 +727,8 @@ pub struct Debug {
     queue: Lock<Queue<CompactString>>,
     mode: Lock<DebugMode>,
     anchor: Lock<Anchor2>,
+    layout_stats: Lock<bool>,
+    sink: Lock<Option<fn(&str)>>,
 }
 
 // TODO this should be conditionally in a LazyLock or a ThreadLocalKey
@@ -293,7 +748,7 @@ static DEBUG: std::sync::LazyLock<Debug> = std::sync::LazyLock::new(Debug::new);
 ///
 /// Depending on the [`DebugMode`] debug messages may be recycled every frame.
 ///
-/// You can change the behavior with [`Debug::set_debug_mode()`]
+/// You can change the behavior with [`Debug::set_debug_mode()`], and forward the messages elsewhere with [`Debug::set_sink()`]
 ///
 /// ### Performance
 /// You should favor [`crate::format_str!`] over [`std::format!`] for this
@@ -312,6 +767,8 @@ impl Debug {
             queue: Lock::new(Queue::new(25)),
             mode: Lock::new(DebugMode::Rolling),
             anchor: Lock::new(Anchor2::RIGHT_TOP),
+            layout_stats: Lock::new(false),
+            sink: Lock::new(None),
         }
     }
 
@@ -342,6 +799,34 @@ impl Debug {
         Self::with(|c| *c.mode.borrow_mut() = debug_mode);
     }
 
+    /// Forward each [`debug()`] message to this function, as its sent
+    ///
+    /// The overlay still gets the messages, unless its [`DebugMode::Off`]. The sink gets them either way.
+    ///
+    /// Use `None` to remove the sink
+    ///
+    /// ```rust
+    /// # use too::view::{debug, Debug, DebugMode};
+    /// use std::sync::Mutex;
+    /// static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    ///
+    /// Debug::set_sink(Some(|msg| LOG.lock().unwrap().push(msg.to_string())));
+    /// debug("hello");
+    ///
+    /// // the overlay is off, but the sink still gets it
+    /// Debug::set_debug_mode(DebugMode::Off);
+    /// debug("world");
+    ///
+    /// assert_eq!(*LOG.lock().unwrap(), ["hello", "world"]);
+    ///
+    /// let mut queue = vec![];
+    /// Debug::for_each(|msg| queue.push(msg.to_string()));
+    /// assert_eq!(queue, ["hello"]);
+    /// ```
+    pub fn set_sink(sink: Option<fn(&str)>) {
+        Self::with(|c| *c.sink.borrow_mut() = sink);
+    }
+
     /// Set where the debug overlay should be drawn.
     ///
     /// See [`Anchor2`] for options
@@ -349,6 +834,13 @@ impl Debug {
         Self::with(|c| *c.anchor.borrow_mut() = anchor);
     }
 
+    /// Show how many views had their layout reused, or measured, at the top of the overlay
+    ///
+    /// See [`State::layout_stats`]
+    pub fn show_layout_stats(show: bool) {
+        Self::with(|c| *c.layout_stats.borrow_mut() = show);
+    }
+
     /// Is the debug overlay enabled? (E.g. is it on?)
     pub fn is_enabled() -> bool {
         !matches!(Self::with(|c| *c.mode.borrow()), DebugMode::Off)
@@ -371,7 +863,8 @@ impl Debug {
     }
 
     fn push(&self, msg: impl ToCompactString) {
-        if matches!(*self.mode.borrow(), DebugMode::Off) {
+        let sink = *self.sink.borrow();
+        if sink.is_none() && matches!(*self.mode.borrow(), DebugMode::Off) {
             return;
         }
         let msg = msg.to_compact_string();
@@ -380,6 +873,13 @@ impl Debug {
             return;
         }
 
+        if let Some(sink) = sink {
+            sink(msg);
+        }
+        if matches!(*self.mode.borrow(), DebugMode::Off) {
+            return;
+        }
+
         let mut queue = self.queue.borrow_mut();
         if msg.find('\n').is_some() {
             queue.extend(msg.lines().map(CompactString::from));
@@ -195,6 +195,109 @@ impl Palette {
         !self.is_dark()
     }
 
+    /// Pick the foreground, contrast or background color, whichever is the most readable on `bg`
+    ///
+    /// This is useful for text on top of an accent color, see [`Rgba::best_contrast`]
+    ///
+    /// ```rust
+    /// # use too::view::Palette;
+    /// let palette = Palette::dark();
+    /// assert_eq!(palette.readable_on(palette.background), palette.foreground);
+    /// assert_eq!(palette.readable_on(palette.foreground), palette.background);
+    /// ```
+    pub fn readable_on(&self, bg: Rgba) -> Rgba {
+        bg.best_contrast(&[self.foreground, self.contrast, self.background])
+    }
+
+    /// Replace the colors named by these pairs of keys and colors, like `("background", "#131313")`
+    ///
+    /// The keys are the names of the fields on a palette. Colors that aren't in the pairs are kept as-is.
+    ///
+    /// This is useful for loading a palette from a config file that the application parses itself.
+    ///
+    /// ```rust
+    /// # use too::{renderer::Rgba, view::{Palette, PaletteParseError}};
+    /// let palette = Palette::dark()
+    ///     .with_pairs([("background", "#000"), ("primary", " #FF000080 ")])
+    ///     .unwrap();
+    /// assert_eq!(palette.background, Rgba::hex("#000"));
+    /// assert_eq!(palette.primary, Rgba::hex("#FF000080"));
+    /// assert_eq!(palette.foreground, Palette::dark().foreground);
+    ///
+    /// // it can be written out, and read back in
+    /// let pairs: Vec<_> = palette.to_pairs().collect();
+    /// assert_eq!(pairs[0], ("background", String::from("#000000")));
+    /// let pairs = pairs.iter().map(|(key, color)| (*key, color.as_str()));
+    /// assert_eq!(Palette::light().with_pairs(pairs), Ok(palette));
+    ///
+    /// assert_eq!(
+    ///     Palette::dark().with_pairs([("backgroud", "#000")]),
+    ///     Err(PaletteParseError::UnknownKey("backgroud".into()))
+    /// );
+    /// assert!(matches!(
+    ///     Palette::dark().with_pairs([("info", "#12345")]),
+    ///     Err(PaletteParseError::InvalidColor { key, .. }) if key == "info"
+    /// ));
+    /// ```
+    pub fn with_pairs<'a>(
+        mut self,
+        pairs: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, PaletteParseError> {
+        for (key, value) in pairs {
+            let key = key.trim();
+            let Some((_, color)) = self.colors_mut().into_iter().find(|(name, _)| *name == key)
+            else {
+                return Err(PaletteParseError::UnknownKey(key.to_string()));
+            };
+
+            *color = value
+                .trim()
+                .parse()
+                .map_err(|reason| PaletteParseError::InvalidColor {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    reason,
+                })?;
+        }
+        Ok(self)
+    }
+
+    /// Create a palette from pairs of keys and colors, using [`Palette::default`] for any colors that aren't in the pairs
+    ///
+    /// See [`Palette::with_pairs`]
+    pub fn from_pairs<'a>(
+        pairs: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, PaletteParseError> {
+        Self::default().with_pairs(pairs)
+    }
+
+    /// Get the name of each color, and the color as hex (e.g. `#RRGGBB`)
+    ///
+    /// These can be read back with [`Palette::with_pairs`]
+    pub fn to_pairs(&self) -> impl Iterator<Item = (&'static str, String)> {
+        let mut this = *self;
+        this.colors_mut()
+            .map(|(key, color)| (key, format!("{color:X}")))
+            .into_iter()
+    }
+
+    fn colors_mut(&mut self) -> [(&'static str, &mut Rgba); 12] {
+        [
+            ("background", &mut self.background),
+            ("foreground", &mut self.foreground),
+            ("surface", &mut self.surface),
+            ("outline", &mut self.outline),
+            ("contrast", &mut self.contrast),
+            ("primary", &mut self.primary),
+            ("secondary", &mut self.secondary),
+            ("accent", &mut self.accent),
+            ("danger", &mut self.danger),
+            ("success", &mut self.success),
+            ("warning", &mut self.warning),
+            ("info", &mut self.info),
+        ]
+    }
+
     /// A default "dark" palette
     ///
     /// # A visualization of this palette
@@ -266,6 +369,35 @@ impl Palette {
     }
 }
 
+/// An error produced by [`Palette::with_pairs`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaletteParseError {
+    /// This key isn't the name of a color in the palette
+    UnknownKey(String),
+    /// The color for this key couldn't be parsed
+    InvalidColor {
+        /// The key of the color
+        key: String,
+        /// The value that couldn't be parsed
+        value: String,
+        /// Why it couldn't be parsed
+        reason: &'static str,
+    },
+}
+
+impl std::fmt::Display for PaletteParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownKey(key) => write!(f, "unknown palette key: {key}"),
+            Self::InvalidColor { key, value, reason } => {
+                write!(f, "invalid color for {key}: {value:?}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PaletteParseError {}
+
 /// Useful elements for drawing a TUI
 pub struct Elements;
 impl Elements {
@@ -275,6 +407,7 @@ impl Elements {
 
     pub const CIRCLE: char = '●';
     pub const DIAMOND: char = '◆';
+    pub const CROSS: char = '×';
 
     pub const HORIZONTAL_LINE: char = '─';
     pub const THICK_HORIZONTAL_LINE: char = '━';
@@ -1,16 +1,21 @@
 //! A simplistic testing interface for your applications.
 //!
 //! This lets you assert how an application will render for specific inputs.
-use std::{borrow::Cow, ops::RangeInclusive};
+use std::{borrow::Cow, collections::VecDeque, ops::RangeInclusive};
+
+use unicode_width::UnicodeWidthStr as _;
 
 use crate::{
     animation::Animations,
     backend::Event,
-    backend::{Modifiers, MouseButton},
+    backend::{Command, Key, Modifiers, MouseButton},
     layout::Axis,
-    math::{vec2, Pos2, Rect, Vec2},
-    renderer::{Cell, Grapheme, Pixel, Rasterizer, Rgba, Shape, TextShape},
-    view::{Palette, State, ViewId},
+    math::{pos2, vec2, Pos2, Rect, Vec2},
+    renderer::{
+        Attribute, Cell, Grapheme, Pixel, Rasterizer, Renderer, Rgba, Shape, Surface, TermRenderer,
+        TextShape, Underline,
+    },
+    view::{CroppedSurface, Palette, State, ViewId},
 };
 
 use super::Ui;
@@ -79,6 +84,7 @@ impl Rasterizer for DebugRasterizer {
     fn text(&mut self, shape: TextShape<'_>) {
         let shape = TextShape {
             label: Cow::from(shape.label.to_string()),
+            link: shape.link.map(|link| Cow::from(link.into_owned())),
             ..shape
         };
 
@@ -185,3 +191,379 @@ where
         shapes: debug.paint_list,
     }
 }
+
+const TEST_SIZE: Vec2 = vec2(80, 25);
+
+// build the application at the test size, like the other helpers
+fn build<R: 'static>(state: &mut State, app: impl FnMut(&Ui) -> R) -> R {
+    state.build(Rect::from_min_size(Pos2::ZERO, TEST_SIZE), app)
+}
+
+fn mouse_button(state: &mut State, pos: Pos2, down: bool) {
+    state.event(&Event::MouseButtonChanged {
+        pos,
+        button: MouseButton::Primary,
+        down,
+        modifiers: Modifiers::NONE,
+    });
+}
+
+/// Move the mouse to `pos`, then build the application at 80x25, returning its response
+///
+/// The events go through [`State::event`], the same as they would in an application
+pub fn hover<R: 'static>(state: &mut State, pos: Pos2, app: impl FnMut(&Ui) -> R) -> R {
+    state.event(&Event::MouseMove { pos });
+    build(state, app)
+}
+
+/// Click the primary mouse button at `pos`, building the application at 80x25 after each event
+///
+/// This moves the mouse there, presses the button and releases it. The response is from the build after the release
+///
+/// ```rust
+/// # use too::{math::pos2, view::{test::click_at, State}};
+/// let mut state = State::default();
+/// let app = |ui: &too::view::Ui| ui.button("click me").clicked();
+///
+/// assert!(!click_at(&mut state, pos2(40, 10), app));
+/// assert!(click_at(&mut state, pos2(1, 0), app));
+/// ```
+pub fn click_at<R: 'static>(state: &mut State, pos: Pos2, mut app: impl FnMut(&Ui) -> R) -> R {
+    // the application has to be built once, so the views have a layout to hit
+    build(state, &mut app);
+    hover(state, pos, &mut app);
+    mouse_button(state, pos, true);
+    build(state, &mut app);
+    mouse_button(state, pos, false);
+    build(state, app)
+}
+
+/// Type each character of `text` as a key press, building the application at 80x25 after each one
+///
+/// `\n` is typed as [`Key::Enter`] and `\t` as [`Key::Tab`]. The response is from the last build
+///
+/// ```rust
+/// # use too::view::{test::type_text, State};
+/// let mut state = State::default();
+/// let app = |ui: &too::view::Ui| ui.text_input(true).into_inner().data().to_string();
+///
+/// assert_eq!(type_text(&mut state, "hello", app), "hello");
+/// ```
+pub fn type_text<R: 'static>(state: &mut State, text: &str, mut app: impl FnMut(&Ui) -> R) -> R {
+    let mut resp = build(state, &mut app);
+    for ch in text.chars() {
+        let key = match ch {
+            '\n' => Key::Enter,
+            '\t' => Key::Tab,
+            ch => Key::Char(ch),
+        };
+        state.event(&Event::KeyPressed {
+            key,
+            modifiers: Modifiers::NONE,
+        });
+        resp = build(state, &mut app);
+    }
+    resp
+}
+
+fn render_surface<R: 'static>(app: impl FnMut(&Ui) -> R) -> Surface {
+    let mut state = State::new(Palette::dark(), Animations::new());
+    state.build(Rect::from_min_size(Pos2::ZERO, TEST_SIZE), app);
+
+    let mut surface = Surface::new(TEST_SIZE);
+    state.render(&mut CroppedSurface {
+        clip_rect: surface.rect(),
+        surface: &mut surface,
+    });
+    surface
+}
+
+/// Render an application at 80x25 and return what it drew as lines of text
+///
+/// Colors and attributes are ignored. Trailing whitespace at the end of each line, and any empty lines at the end, are removed
+///
+/// ```rust
+/// use too::view::test::render_to_string;
+///
+/// let out = render_to_string(|ui| {
+///     ui.vertical(|ui| {
+///         ui.label("hello");
+///         ui.label("world");
+///     });
+/// });
+/// assert_eq!(out, "hello\nworld");
+///
+/// let out = render_to_string(|ui| {
+///     ui.horizontal(|ui| {
+///         ui.label("a");
+///         ui.label("b");
+///     });
+/// });
+/// assert_eq!(out, "a b");
+/// ```
+pub fn render_to_string<R: 'static>(app: impl FnMut(&Ui) -> R) -> String {
+    surface_to_string(&render_surface(app))
+}
+
+fn surface_to_string(surface: &Surface) -> String {
+    let size = surface.rect().size();
+
+    let mut out = String::new();
+    for y in 0..size.y {
+        let start = out.len();
+        for x in 0..size.x {
+            match surface.get(pos2(x, y)) {
+                Some(Cell::Grapheme(grapheme)) => out.push_str(&grapheme.cluster),
+                Some(Cell::Pixel(pixel)) if !pixel.char.is_control() => out.push(pixel.char),
+                Some(Cell::Continuation) => {}
+                _ => out.push(' '),
+            }
+        }
+
+        let end = start + out[start..].trim_end().len();
+        out.truncate(end);
+        out.push('\n');
+    }
+
+    let end = out.trim_end_matches('\n').len();
+    out.truncate(end);
+    out
+}
+
+/// Render an application at 80x25 and return what it drew as lines of text, with colors and attributes as ANSI escape sequences
+///
+/// Every line is written out in full, and the styles are reset at the end of the output
+///
+/// ```rust
+/// use too::view::test::render_to_ansi;
+///
+/// let out = render_to_ansi(|ui| ui.label("hello"));
+/// assert_eq!(out.lines().count(), 25);
+/// assert!(out.contains("hello"));
+/// assert!(out.contains("\x1b[48;2;")); // the background was set
+/// assert!(out.ends_with("\x1b[0m"));
+/// ```
+pub fn render_to_ansi<R: 'static>(app: impl FnMut(&Ui) -> R) -> String {
+    let mut surface = render_surface(app);
+
+    let mut renderer = LineRenderer {
+        inner: TermRenderer::new(Vec::new()),
+        pos: Pos2::ZERO,
+    };
+    // this can't fail, it is only writing to a Vec
+    _ = surface.render(&mut renderer);
+
+    // everything written was a str
+    String::from_utf8(std::mem::take(renderer.inner.get_mut())).unwrap()
+}
+
+// a renderer that writes lines rather than moving the cursor around, so the output can be diffed
+struct LineRenderer {
+    inner: TermRenderer<Vec<u8>>,
+    pos: Pos2,
+}
+
+impl Renderer for LineRenderer {
+    fn begin(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn end(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn move_to(&mut self, pos: Pos2) -> std::io::Result<()> {
+        // the renderer only ever moves forward, except to home the cursor at the end
+        while self.pos.y < pos.y {
+            self.inner.write_str("\n")?;
+            self.pos = pos2(0, self.pos.y + 1);
+        }
+
+        if self.pos.y == pos.y {
+            while self.pos.x < pos.x {
+                self.inner.write_str(" ")?;
+                self.pos.x += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_str(&mut self, data: &str) -> std::io::Result<()> {
+        self.pos.x += data.width() as i32;
+        self.inner.write_str(data)
+    }
+
+    fn set_fg(&mut self, rgb: Rgba) -> std::io::Result<()> {
+        self.inner.set_fg(rgb)
+    }
+
+    fn set_bg(&mut self, rgb: Rgba) -> std::io::Result<()> {
+        self.inner.set_bg(rgb)
+    }
+
+    fn set_attr(&mut self, attr: Attribute) -> std::io::Result<()> {
+        self.inner.set_attr(attr)
+    }
+
+    fn reset_fg(&mut self) -> std::io::Result<()> {
+        self.inner.reset_fg()
+    }
+
+    fn reset_bg(&mut self) -> std::io::Result<()> {
+        self.inner.reset_bg()
+    }
+
+    fn reset_attr(&mut self) -> std::io::Result<()> {
+        self.inner.reset_attr()
+    }
+
+    fn set_underline(&mut self, underline: Underline) -> std::io::Result<()> {
+        self.inner.set_underline(underline)
+    }
+
+    fn reset_underline(&mut self) -> std::io::Result<()> {
+        self.inner.reset_underline()
+    }
+
+    fn set_hyperlink(&mut self, url: &str) -> std::io::Result<()> {
+        self.inner.set_hyperlink(url)
+    }
+
+    fn reset_hyperlink(&mut self) -> std::io::Result<()> {
+        self.inner.reset_hyperlink()
+    }
+}
+
+/// A headless event loop for an application, with scripted events and simulated time
+///
+/// Each tick:
+/// - delivers the next scripted [`Event`], if there is one
+/// - advances time by a fixed [`dt`](Self::dt), without sleeping
+/// - builds and renders the application, keeping a snapshot of the [`Surface`]
+///
+/// The application is built once before the first tick, so its views have a layout when the first event arrives.
+///
+/// The run stops early if an [`Event::Quit`] is delivered or the application sends a [`Command::RequestQuit`]
+///
+/// ```rust
+/// # use too::{backend::{Command, Event, Modifiers, MouseButton}, math::{pos2, vec2}, view::test::Harness};
+/// let click = |down| Event::MouseButtonChanged {
+///     pos: pos2(1, 0),
+///     button: MouseButton::Primary,
+///     down,
+///     modifiers: Modifiers::NONE,
+/// };
+///
+/// let mut count = 0;
+/// let recording = Harness::new(vec2(20, 2))
+///     .events([Event::MouseMove { pos: pos2(1, 0) }, click(true), click(false)])
+///     .run(4, |ui| {
+///         ui.vertical(|ui| {
+///             if ui.button("add").clicked() {
+///                 count += 1;
+///                 ui.command(Command::set_title(count));
+///             }
+///             ui.label(count);
+///         });
+///     });
+///
+/// assert_eq!(recording.frames.len(), 4);
+/// assert_eq!(recording.text(0).unwrap(), " add\n0");
+/// assert_eq!(recording.text(3).unwrap(), " add\n1");
+/// assert_eq!(recording.commands, vec![Command::set_title(1)]);
+/// ```
+pub struct Harness {
+    size: Vec2,
+    dt: f32,
+    palette: Palette,
+    events: VecDeque<Event>,
+}
+
+impl Harness {
+    /// Create a harness with a fixed screen size
+    pub fn new(size: impl Into<Vec2>) -> Self {
+        Self {
+            size: size.into(),
+            dt: 1.0 / 60.0,
+            palette: Palette::dark(),
+            events: VecDeque::new(),
+        }
+    }
+
+    /// How much time passes each tick, in seconds
+    ///
+    /// By default this is `1.0 / 60.0`
+    pub fn dt(mut self, dt: f32) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    /// The palette to use
+    ///
+    /// By default this is [`Palette::dark()`]
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Events to deliver, one per tick
+    pub fn events(mut self, events: impl IntoIterator<Item = Event>) -> Self {
+        self.events.extend(events);
+        self
+    }
+
+    /// Run the application for this many ticks
+    pub fn run<R: 'static>(mut self, ticks: usize, mut app: impl FnMut(&Ui) -> R) -> Recording {
+        let mut state = State::new(self.palette, Animations::new());
+        let mut surface = Surface::new(self.size);
+        let mut recording = Recording {
+            frames: Vec::with_capacity(ticks),
+            commands: Vec::new(),
+        };
+
+        state.build(surface.rect(), &mut app);
+
+        for _ in 0..ticks {
+            if let Some(event) = self.events.pop_front() {
+                if event.is_quit() {
+                    break;
+                }
+                // this also resizes the surface
+                surface.update(&event);
+                state.event(&event);
+            }
+
+            state.update(self.dt);
+            state.build(surface.rect(), &mut app);
+            state.render(&mut CroppedSurface {
+                clip_rect: surface.rect(),
+                surface: &mut surface,
+            });
+            recording.frames.push(surface.clone());
+
+            let commands = state.take_commands();
+            let quit = commands.contains(&Command::RequestQuit);
+            recording.commands.extend(commands);
+            if quit {
+                break;
+            }
+        }
+
+        recording
+    }
+}
+
+/// What a [`Harness`] rendered, and the commands the application sent
+pub struct Recording {
+    /// A snapshot of the surface after each tick
+    pub frames: Vec<Surface>,
+    /// Every [`Command`] sent by the application, in order
+    pub commands: Vec<Command>,
+}
+
+impl Recording {
+    /// The text of a frame, like [`render_to_string`]
+    pub fn text(&self, frame: usize) -> Option<String> {
+        self.frames.get(frame).map(surface_to_string)
+    }
+}
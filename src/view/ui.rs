@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use crate::{
-    backend::Keybind,
-    layout::{Align2, Flex},
+    animation::{Animation, Animations, Id},
+    backend::{Command, KeySequence, Keybind},
+    layout::{Align2, Axis, Flex},
     lock::{Lock, Ref, RefMapped},
     math::{Margin, Pos2, Rect, Size, Vec2},
     renderer::{Border, Rgba},
@@ -11,7 +14,8 @@ use crate::{
 use super::{
     filter::{Filter, Filterable},
     input::InputState,
-    internal_views, Builder, LayoutNodes, Palette, Response, State, View, ViewId, ViewNodes,
+    internal_views, Builder, FrameStats, LayoutNodes, Palette, Render, Response, State, View,
+    ViewId, ViewMarker, ViewNodes,
 };
 
 impl<'a> Filterable for Ui<'a> {
@@ -25,11 +29,15 @@ pub struct Ui<'a> {
     layout: &'a LayoutNodes,
     input: &'a InputState,
     palette: &'a Lock<Palette>,
+    animations: &'a Lock<Animations>,
+    commands: &'a Lock<Vec<Command>>,
 
     client_rect: Rect,
     size_changed: Option<Vec2>,
     frame_count: u64,
     dt: f32,
+    frame_time: f32,
+    frame_stats: FrameStats,
 }
 
 impl<'a> Ui<'a> {
@@ -39,9 +47,13 @@ impl<'a> Ui<'a> {
             layout: &state.layout,
             input: &state.input,
             palette: &state.palette,
+            animations: &state.animations,
+            commands: &state.commands,
             client_rect,
             frame_count: state.frame_count,
             dt: state.dt,
+            frame_time: state.frame_time,
+            frame_stats: state.frame_stats,
             size_changed: state.size_changed,
         }
     }
@@ -116,17 +128,137 @@ impl<'a> Ui<'a> {
         self.dt
     }
 
+    /// The average time between frames
+    ///
+    /// This is smoothed over the last several calls to [`State::update`](crate::view::State::update), so it doesn't jitter like [`Ui::dt`]
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::{view::State, math::{pos2, vec2, Rect}};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    ///
+    /// state.update(0.125);
+    /// let (frame_time, fps) = state.build(rect, |ui| (ui.frame_time(), ui.fps()));
+    /// assert_eq!(frame_time, Duration::from_millis(125));
+    /// assert!((fps - 8.0).abs() < 0.01);
+    ///
+    /// // a single slow frame only moves the average a little
+    /// state.update(1.125);
+    /// let frame_time = state.build(rect, |ui| ui.frame_time());
+    /// assert!((frame_time.as_secs_f32() - 0.225).abs() < 0.001);
+    /// ```
+    pub fn frame_time(&self) -> Duration {
+        Duration::try_from_secs_f32(self.frame_time.max(0.0)).unwrap_or(Duration::MAX)
+    }
+
+    /// The average frames per second, from the [`frame_time`](Self::frame_time)
+    ///
+    /// This is `0.0` until the first update
+    pub fn fps(&self) -> f32 {
+        if self.frame_time <= 0.0 {
+            return 0.0;
+        }
+        self.frame_time.recip()
+    }
+
+    /// The min, max and 95th percentile of the recent frame times, along with their average
+    ///
+    /// The average smooths away the occasional slow frame, these make it easier to spot
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::{view::State, math::{pos2, vec2, Rect}};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    ///
+    /// for _ in 0..99 {
+    ///     state.update(0.125);
+    /// }
+    /// state.update(1.0);
+    ///
+    /// let stats = state.build(rect, |ui| ui.frame_stats());
+    /// assert_eq!(stats.samples, 100);
+    /// assert_eq!(stats.min, Duration::from_millis(125));
+    /// assert_eq!(stats.p95, Duration::from_millis(125));
+    /// assert_eq!(stats.max, Duration::from_secs(1));
+    /// assert!(stats.average < Duration::from_millis(250));
+    ///
+    /// // a frame too long to be a `Duration` is the longest one
+    /// state.update(f32::INFINITY);
+    /// let stats = state.build(rect, |ui| ui.frame_stats());
+    /// assert_eq!(stats.max, Duration::MAX);
+    /// ```
+    pub const fn frame_stats(&self) -> FrameStats {
+        self.frame_stats
+    }
+
     pub fn size_changed(&self) -> Option<Vec2> {
         self.size_changed
     }
 
+    /// Gets the current palette
     pub fn palette(&self) -> Ref<'_, Palette> {
         self.palette.borrow()
     }
 
+    /// Replace the current palette
+    ///
+    /// Views resolve their colors from the palette every frame, so this recolors the whole tree without touching any view state.
+    ///
+    /// Most views do this when they are drawn, but some, like a [`checkbox`](crate::views::checkbox), do it when they are updated. So set the palette before showing the views it should apply to
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, renderer::{Color, Surface}, view::{CroppedSurface, Palette, State}};
+    /// let mut state = State::default();
+    /// let mut surface = Surface::new(vec2(10, 1));
+    ///
+    /// let mut frame = |state: &mut State, palette: Palette| {
+    ///     state.build(surface.rect(), |ui| {
+    ///         ui.set_palette(palette);
+    ///         ui.button("hello");
+    ///     });
+    ///     state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+    ///     surface.get(pos2(0, 0)).unwrap().bg()
+    /// };
+    ///
+    /// assert_eq!(frame(&mut state, Palette::dark()), Color::Set(Palette::dark().outline));
+    /// assert_eq!(frame(&mut state, Palette::light()), Color::Set(Palette::light().outline));
+    /// ```
     pub fn set_palette(&self, palette: Palette) {
         *self.palette.borrow_mut() = palette
     }
+
+    /// Send a [`Command`] to the backend
+    ///
+    /// These are queued up and given to the backend by the event loop after the Ui is built, see [`State::take_commands`]
+    pub fn command(&self, command: Command) {
+        self.commands.borrow_mut().push(command)
+    }
+
+    /// Start an animation for this `id`, or reuse the one that is already running, returning its current value
+    ///
+    /// The animation is driven forward by [`State::update`]. A [`oneshot`](Animation::oneshot) animation is removed once it's done, so the next call will start it again
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::{view::State, animation::{easing, Animation, Id}, math::{pos2, vec2, Rect}};
+    /// const FADE: Id = Id::new("fade");
+    /// let fade = || Animation::new().with(easing::linear).schedule(Duration::from_secs(1)).unwrap();
+    ///
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    ///
+    /// assert_eq!(state.build(rect, |ui| ui.animate(FADE, fade())), 0.0);
+    /// state.update(0.5);
+    /// assert_eq!(state.build(rect, |ui| ui.animate(FADE, fade())), 0.5);
+    /// ```
+    pub fn animate(&self, id: impl Into<Id>, animation: Animation) -> f32 {
+        let id = id.into();
+        let mut animations = self.animations.borrow_mut();
+        animations.add_once(id, || animation);
+        *animations.value(id).value
+    }
 }
 
 impl<'a> Ui<'a> {
@@ -164,6 +296,45 @@ impl<'a> Ui<'a> {
         self.input.is_focused(self.nodes.parent())
     }
 
+    /// Is this id, or any of its descendants, focused?
+    ///
+    /// ```rust
+    /// # use too::{backend::Event, math::{pos2, vec2, Rect}, view::{State, Ui}, views::label};
+    /// let mut state = State::new(Default::default(), Default::default());
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 5));
+    /// let show = |ui: &Ui| {
+    ///     let outer = ui.vertical(|ui| ui.show(label("inner")).id());
+    ///     let other = ui.show(label("other")).id();
+    ///     (outer.id(), outer.into_inner(), other)
+    /// };
+    ///
+    /// let (_, inner, _) = state.build(rect, show);
+    /// state.build(rect, |ui| {
+    ///     ui.set_focus(inner);
+    ///     show(ui)
+    /// });
+    /// state.build(rect, |ui| {
+    ///     let (outer, inner, other) = show(ui);
+    ///     assert!(ui.is_focus_within(outer));
+    ///     assert!(ui.is_focus_within(inner));
+    ///     assert!(!ui.is_focus_within(other));
+    /// });
+    ///
+    /// state.event(&Event::FocusLost);
+    /// state.build(rect, |ui| {
+    ///     show(ui);
+    ///     assert!(!ui.is_window_focused());
+    /// });
+    /// ```
+    pub fn is_focus_within(&self, id: ViewId) -> bool {
+        self.input.is_focus_within(self.nodes, id)
+    }
+
+    /// Does the terminal window have focus?
+    pub fn is_window_focused(&self) -> bool {
+        self.input.is_window_focused()
+    }
+
     pub fn set_focus(&self, id: impl Into<Option<ViewId>>) {
         self.input.set_focus(id.into());
     }
@@ -226,6 +397,65 @@ impl<'a> Ui<'a> {
             .flatten_right()
     }
 
+    /// Stack views on top of each other in the same rect, each aligned on its own
+    ///
+    /// Every [`at`](views::OverlayLayout::at) is placed by its own size, and is drawn on top of (and gets the mouse before) the ones before it
+    ///
+    /// ```rust
+    /// # use too::{layout::Align2, math::pos2, view::{test::{click_at, render_to_string}, State}, views::Constrain};
+    /// let out = render_to_string(|ui| {
+    ///     ui.constrain(Constrain::exact_size((7, 3)), |ui| {
+    ///         ui.overlay_layout(|layout| {
+    ///             layout.at(Align2::LEFT_TOP, |ui| ui.label("a"));
+    ///             layout.at(Align2::CENTER_CENTER, |ui| ui.label("b"));
+    ///             layout.at(Align2::RIGHT_BOTTOM, |ui| ui.label("c"));
+    ///         });
+    ///     });
+    /// });
+    /// assert_eq!(out, "a\n   b\n      c");
+    ///
+    /// // the later one is on top, so it gets the click
+    /// let mut state = State::default();
+    /// let (first, second) = click_at(&mut state, pos2(1, 0), |ui| {
+    ///     ui.overlay_layout(|layout| {
+    ///         let first = layout.at(Align2::LEFT_TOP, |ui| ui.button("first").clicked());
+    ///         let second = layout.at(Align2::LEFT_TOP, |ui| ui.button("second").clicked());
+    ///         (first.into_inner(), second.into_inner())
+    ///     })
+    ///     .into_inner()
+    /// });
+    /// assert!(!first);
+    /// assert!(second);
+    /// ```
+    pub fn overlay_layout<R>(&self, show: impl FnOnce(&views::OverlayLayout) -> R) -> Response<R>
+    where
+        R: 'static,
+    {
+        self.show_children(views::overlay(), |ui| show(&views::OverlayLayout { ui }))
+            .flatten_right()
+    }
+
+    /// Lay out the children in the largest box with this aspect ratio (`width / height`) that fits, centered in the space
+    ///
+    /// Without any bounds, the children are as large as they want to be. A ratio that isn't positive is ignored
+    ///
+    /// ```rust
+    /// # use too::{renderer::Border, view::test::render_to_string, views::Constrain};
+    /// let out = render_to_string(|ui| {
+    ///     ui.constrain(Constrain::exact_size((12, 3)), |ui| {
+    ///         ui.aspect_ratio(2.0, |ui| ui.border(Border::THIN, |_| {}))
+    ///     });
+    /// });
+    /// assert_eq!(out, "   ┌────┐\n   │    │\n   └────┘");
+    /// ```
+    pub fn aspect_ratio<R>(&self, ratio: f32, show: impl FnOnce(&Ui) -> R) -> Response<R>
+    where
+        R: 'static,
+    {
+        self.show_children(views::aspect_ratio(ratio), show)
+            .flatten_right()
+    }
+
     pub fn margin<R>(&self, margin: impl Into<Margin>, show: impl FnOnce(&Ui) -> R) -> Response<R>
     where
         R: 'static,
@@ -242,6 +472,19 @@ impl<'a> Ui<'a> {
             .flatten_right()
     }
 
+    /// Paint behind some views with a closure, see [`background_with`](views::background_with)
+    pub fn background_with<R>(
+        &self,
+        paint: impl FnMut(Rect, &mut Render) + ViewMarker + 'static,
+        show: impl FnOnce(&Ui) -> R,
+    ) -> Response<R>
+    where
+        R: 'static,
+    {
+        self.show_children(views::background_with(paint), show)
+            .flatten_right()
+    }
+
     pub fn offset<R>(&self, offset: impl Into<Pos2>, show: impl FnOnce(&Ui) -> R) -> Response<R>
     where
         R: 'static,
@@ -320,6 +563,175 @@ impl<'a> Ui<'a> {
         self.show_children(views::key_area(), show)
     }
 
+    /// Wait for any of these key sequences to be pressed, while this view is focused
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Key, KeySequence, Modifiers}, view::State, math::{pos2, vec2, Rect}};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    ///
+    /// // these only have to be parsed once
+    /// let sequences = ["g g", "d d"].map(|seq| KeySequence::parse(seq).unwrap());
+    ///
+    /// let mut press = |state: &mut State, key: char, dt: f32| {
+    ///     state.event(&Event::KeyPressed { key: Key::Char(key), modifiers: Modifiers::NONE });
+    ///     state.update(dt);
+    ///     state.build(rect, |ui| {
+    ///         let resp = ui.key_sequence(&sequences, |_| {});
+    ///         ui.set_focus(resp.id());
+    ///         resp.into_inner().0.matched()
+    ///     })
+    /// };
+    ///
+    /// state.build(rect, |ui| ui.set_focus(ui.key_sequence(&sequences, |_| {}).id()));
+    ///
+    /// assert_eq!(press(&mut state, 'g', 0.1), None);
+    /// assert_eq!(press(&mut state, 'g', 0.1), Some(0));
+    ///
+    /// // a key that doesn't continue the sequence starts over
+    /// assert_eq!(press(&mut state, 'd', 0.1), None);
+    /// assert_eq!(press(&mut state, 'x', 0.1), None);
+    /// assert_eq!(press(&mut state, 'd', 0.1), None);
+    /// assert_eq!(press(&mut state, 'd', 0.1), Some(1));
+    ///
+    /// // waiting too long between keys also starts over
+    /// assert_eq!(press(&mut state, 'g', 2.0), None);
+    /// assert_eq!(press(&mut state, 'g', 0.1), None);
+    /// assert_eq!(press(&mut state, 'g', 0.1), Some(0));
+    /// ```
+    pub fn key_sequence<R>(
+        &self,
+        sequences: &[KeySequence],
+        show: impl FnOnce(&Ui) -> R,
+    ) -> Response<(views::KeySequenceResponse, R)>
+    where
+        R: 'static,
+    {
+        self.show_children(views::key_sequence(sequences), show)
+    }
+
+    pub fn scrollable<R>(
+        &self,
+        show: impl FnOnce(&Ui) -> R,
+    ) -> Response<(views::ScrollableResponse, R)>
+    where
+        R: 'static,
+    {
+        self.show_children(views::scrollable(), show)
+    }
+
+    /// Show a draggable [`Window`](views::Window), with a title bar, at `pos`
+    ///
+    /// Dragging the title bar moves the window and updates `pos`. The window is kept inside of the space it was shown in.
+    ///
+    /// Windows are stacked in the order they are shown, so to bring a [`raised`](views::WindowResponse::raised) window to the front,
+    /// show it after the others on the next frame.
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Modifiers, MouseButton}, math::{pos2, vec2, Rect}, view::State};
+    /// let mut state = State::new(Default::default(), Default::default());
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 10));
+    ///
+    /// let mut pos = pos2(2, 2);
+    /// let mut build = |state: &mut State, pos: &mut _| {
+    ///     state.build(rect, |ui| ui.window("hello", pos, |ui| _ = ui.label("world")).into_inner().0)
+    /// };
+    /// build(&mut state, &mut pos);
+    /// build(&mut state, &mut pos);
+    ///
+    /// let (button, modifiers) = (MouseButton::Primary, Modifiers::NONE);
+    /// state.event(&Event::MouseMove { pos: pos2(3, 2) });
+    /// state.event(&Event::MouseButtonChanged { pos: pos2(3, 2), button, down: true, modifiers });
+    /// state.event(&Event::MouseDrag { pos: pos2(6, 3), button, modifiers });
+    /// state.event(&Event::MouseButtonChanged { pos: pos2(6, 3), button, down: false, modifiers });
+    ///
+    /// let resp = build(&mut state, &mut pos);
+    /// assert!(resp.moved() && resp.raised());
+    /// assert_eq!(pos, pos2(5, 3));
+    ///
+    /// // the window is kept on the screen
+    /// pos = pos2(100, 100);
+    /// build(&mut state, &mut pos);
+    /// assert!(build(&mut state, &mut pos).moved());
+    /// assert_eq!(pos, pos2(13, 7));
+    /// ```
+    pub fn window<R>(
+        &self,
+        title: impl Into<Str>,
+        pos: &mut Pos2,
+        show: impl FnOnce(&Ui) -> R,
+    ) -> Response<(views::WindowResponse, R)>
+    where
+        R: 'static,
+    {
+        self.show_children(views::window(title, pos), show)
+    }
+
+    /// Show `total` rows, but only create views for the ones that are visible
+    ///
+    /// `show` is called with the index of each row that should be shown this frame.
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Key, Modifiers}, math::{pos2, vec2, Rect}, view::{State, Ui}};
+    /// let mut state = State::new(Default::default(), Default::default());
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 5));
+    ///
+    /// let show = |ui: &Ui| {
+    ///     ui.list_virtual(10_000, |ui, index| _ = ui.label(format!("row {index}")))
+    /// };
+    ///
+    /// state.build(rect, show);
+    /// let resp = state.build(rect, show);
+    /// assert_eq!(resp.visible(), 0..5);
+    /// // and a couple of rows past the end, in case it scrolls
+    /// assert_eq!(resp.shown(), 0..7);
+    /// assert_eq!(state.build(rect, |ui| ui.children_for(show(ui).id()).unwrap().len()), 7);
+    ///
+    /// // rows that aren't shown can still be scrolled to
+    /// resp.scroll_to(500);
+    /// let resp = state.build(rect, show);
+    /// assert_eq!(resp.visible(), 496..501);
+    /// assert_eq!(resp.shown(), 494..503);
+    ///
+    /// // and it can be scrolled with the keyboard, when focused
+    /// state.build(rect, |ui| ui.set_focus(show(ui).id()));
+    /// state.event(&Event::KeyPressed { key: Key::End, modifiers: Modifiers::NONE });
+    /// state.build(rect, show);
+    /// assert_eq!(state.build(rect, show).visible(), 9_995..10_000);
+    /// ```
+    pub fn list_virtual(
+        &self,
+        total: usize,
+        show: impl FnMut(&Ui, usize),
+    ) -> Response<views::VirtualListResponse> {
+        self.show_virtual(views::list_virtual(total), show)
+    }
+
+    /// Show a [`VirtualList`](views::VirtualList), calling `show` with the index of each row that should be shown this frame
+    pub fn show_virtual(
+        &self,
+        list: views::VirtualList,
+        mut show: impl FnMut(&Ui, usize),
+    ) -> Response<views::VirtualListResponse> {
+        let (id, resp) = self.nodes.begin_view::<views::VirtualListView>(list, self);
+        for index in resp.rows(&list, self) {
+            show(self, index);
+        }
+        self.nodes.end_view(id);
+        Response::new(id, resp)
+    }
+
+    pub fn drop_down<T>(
+        &self,
+        selected: &mut usize,
+        items: &[T],
+    ) -> Response<views::DropDownResponse>
+    where
+        T: AsRef<str> + 'static,
+    {
+        self.show(views::drop_down(selected, items))
+    }
+
     pub fn progress(&self, value: f32) -> Response {
         self.show(views::progress(value))
     }
@@ -332,10 +744,39 @@ impl<'a> Ui<'a> {
         resp
     }
 
-    pub fn slider(&self, value: &mut f32) -> Response {
+    pub fn slider(&self, value: &mut f32) -> Response<views::SliderResponse> {
         self.show(views::slider(value))
     }
 
+    /// Pick a color with hue, saturation and lightness sliders, and a swatch of the color
+    ///
+    /// This returns whether the color was changed
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Modifiers, MouseButton}, math::{pos2, vec2}, renderer::Rgba, view::test::Harness};
+    /// let click = |down| Event::MouseButtonChanged {
+    ///     pos: pos2(12, 0),
+    ///     button: MouseButton::Primary,
+    ///     down,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    ///
+    /// let mut color = Rgba::hex("#F00");
+    /// let mut changed = vec![];
+    /// Harness::new(vec2(22, 4))
+    ///     // the sliders are shown on the first tick, then the middle of the hue slider is clicked
+    ///     .events([Event::Resize(vec2(22, 4)), Event::MouseMove { pos: pos2(12, 0) }, click(true), click(false)])
+    ///     .run(4, |ui| changed.push(ui.color_picker(&mut color).into_inner()));
+    ///
+    /// assert_eq!(changed, [false, false, false, false, true]);
+    /// let (hue, saturation, lightness) = color.to_hsl();
+    /// assert!((170.0..200.0).contains(&hue)); // about halfway around
+    /// assert_eq!((saturation, lightness), (1.0, 0.5));
+    /// ```
+    pub fn color_picker(&self, color: &mut Rgba) -> Response<bool> {
+        self.show(views::color_picker(color))
+    }
+
     pub fn toggle<R>(
         &self,
         state: bool,
@@ -359,6 +800,50 @@ impl<'a> Ui<'a> {
         self.show(views::checkbox(value, label))
     }
 
+    /// Show a checkbox that can also be [indeterminate](views::TriState::Indeterminate)
+    ///
+    /// ```rust
+    /// # use too::{view::test::Harness, views::TriState};
+    /// let out = Harness::new((10, 1)).run(1, |ui| {
+    ///     ui.checkbox_tristate(&mut TriState::Indeterminate, "all");
+    /// });
+    /// assert_eq!(out.text(0).unwrap(), "▣ all");
+    /// ```
+    pub fn checkbox_tristate(
+        &self,
+        value: &mut views::TriState,
+        label: impl Into<Str>,
+    ) -> Response<views::TriCheckboxResponse> {
+        self.show(views::checkbox_tristate(value, label))
+    }
+
+    /// Show a label in front of some views, giving you back their response
+    ///
+    /// ```rust
+    /// # use too::{view::{test::render_to_string, State}, math::{pos2, vec2, Rect}};
+    /// let out = render_to_string(|ui| {
+    ///     ui.labeled("volume", |ui| ui.label("11"));
+    /// });
+    /// assert_eq!(out, "volume 11");
+    ///
+    /// // the response is whatever the closure returned
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+    /// let mut enabled = false;
+    /// let resp = state.build(rect, |ui| {
+    ///     ui.labeled("sound", |ui| ui.checkbox(&mut enabled, "enabled").into_inner())
+    /// });
+    /// assert!(!resp.into_inner());
+    /// ```
+    pub fn labeled<R: 'static>(
+        &self,
+        label: impl Into<Str>,
+        show: impl FnOnce(&Ui) -> R,
+    ) -> Response<R> {
+        self.show_children(views::labeled(label), show)
+            .flatten_right()
+    }
+
     pub fn todo_value(&self, value: &mut bool, label: impl Into<Str>) -> Response<bool> {
         self.show(views::todo_value(value, label))
     }
@@ -367,6 +852,18 @@ impl<'a> Ui<'a> {
         self.show(views::selected(value, label))
     }
 
+    pub fn selected_exclusive<T>(
+        &self,
+        current: &mut Option<T>,
+        value: T,
+        label: impl Into<Str>,
+    ) -> Response<bool>
+    where
+        T: PartialEq + 'static,
+    {
+        self.show(views::selected_exclusive(current, value, label))
+    }
+
     pub fn radio<V>(&self, value: V, existing: &mut V, label: impl Into<Str>) -> Response<bool>
     where
         V: PartialEq + 'static,
@@ -374,6 +871,78 @@ impl<'a> Ui<'a> {
         self.show(views::radio(value, existing, label))
     }
 
+    /// Split the available space between two panes along an `axis`, with a draggable divider between them
+    ///
+    /// The `ratio` is how much of the space the `first` pane takes, dragging the divider updates it.
+    ///
+    /// The response is the current ratio, and the results of both panes.
+    ///
+    /// ```rust
+    /// # use too::{view::{test::render_to_string, State}, layout::Axis, math::{pos2, vec2, Rect}, backend::{Event, Modifiers, MouseButton}};
+    /// // splits can be nested
+    /// let out = render_to_string(|ui| {
+    ///     ui.exact_size((11, 3), |ui| {
+    ///         let mut outer = 0.5;
+    ///         let mut inner = 0.5;
+    ///         ui.split_view(Axis::Horizontal, &mut outer, |ui| ui.label("left"), |ui| {
+    ///             ui.split_view(Axis::Vertical, &mut inner, |ui| ui.label("top"), |ui| ui.label("bot"));
+    ///         });
+    ///     });
+    /// });
+    /// assert_eq!(out, "left │top\n     │─────\n     │bot");
+    ///
+    /// let mut state = State::default();
+    /// let mut ratio = 0.5;
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(11, 3));
+    /// let mut build = |state: &mut State, ratio: &mut f32| {
+    ///     state.build(rect, |ui| {
+    ///         ui.split_view(Axis::Horizontal, ratio, |_| (), |_| ()).into_inner().0
+    ///     })
+    /// };
+    /// let drag = |state: &mut State, from, to| {
+    ///     let (button, modifiers) = (MouseButton::Primary, Modifiers::NONE);
+    ///     let pos = pos2(from, 1);
+    ///     state.event(&Event::MouseMove { pos });
+    ///     state.event(&Event::MouseButtonChanged { pos, button, down: true, modifiers });
+    ///     let pos = pos2(to, 1);
+    ///     state.event(&Event::MouseDrag { pos, button, modifiers });
+    ///     state.event(&Event::MouseButtonChanged { pos, button, down: false, modifiers });
+    /// };
+    ///
+    /// build(&mut state, &mut ratio);
+    /// build(&mut state, &mut ratio);
+    ///
+    /// // dragging the divider moves it
+    /// drag(&mut state, 5, 2);
+    /// assert_eq!(build(&mut state, &mut ratio), 0.2);
+    /// assert_eq!(ratio, 0.2);
+    ///
+    /// // but each pane keeps at least a cell
+    /// drag(&mut state, 2, 0);
+    /// assert_eq!(build(&mut state, &mut ratio), 0.1);
+    ///
+    /// // dragging anywhere else does nothing
+    /// drag(&mut state, 8, 6);
+    /// assert_eq!(build(&mut state, &mut ratio), 0.1);
+    /// ```
+    pub fn split_view<A, B>(
+        &self,
+        axis: Axis,
+        ratio: &mut f32,
+        first: impl FnOnce(&Ui) -> A,
+        second: impl FnOnce(&Ui) -> B,
+    ) -> Response<(f32, (A, B))>
+    where
+        A: 'static,
+        B: 'static,
+    {
+        self.show_children(views::split_view(axis, ratio), |ui| {
+            let first = ui.show_children(views::Pane, first).into_inner().1;
+            let second = ui.show_children(views::Pane, second).into_inner().1;
+            (first, second)
+        })
+    }
+
     pub fn label(&self, data: impl Into<Str>) -> Response {
         self.show(views::label(data))
     }
@@ -459,12 +1028,109 @@ impl<'a> Ui<'a> {
             .flatten_right()
     }
 
-    // pub fn collapsible<R: 'static>(
-    //     &self,
-    //     state: &mut bool,
-    //     title: &str,
-    //     show: impl FnOnce(&Ui) -> R,
-    // ) -> Response<Option<R>> {
-    //     self.adhoc(views::collapsible(state, title, show))
-    // }
+    /// A header you can click to open and close the body below it
+    ///
+    /// The body is always built, so its views keep their state while its closed. The response is in the form of `(CollapsibleResponse, R)`
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Modifiers, MouseButton}, math::{pos2, vec2}, view::test::Harness};
+    /// let click = |down| Event::MouseButtonChanged {
+    ///     pos: pos2(0, 0),
+    ///     button: MouseButton::Primary,
+    ///     down,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    ///
+    /// let mut open = false;
+    /// let mut toggled = vec![];
+    /// let recording = Harness::new(vec2(20, 5))
+    ///     .dt(0.075)
+    ///     .events([Event::MouseMove { pos: pos2(0, 0) }, click(true), click(false)])
+    ///     .run(4, |ui| {
+    ///         let (resp, _) = ui.collapsible(&mut open, "details", |ui| {
+    ///             ui.vertical(|ui| {
+    ///                 ui.label("a");
+    ///                 ui.label("b");
+    ///             })
+    ///         }).into_inner();
+    ///         toggled.push(resp.toggled());
+    ///     });
+    ///
+    /// // closed, only the header is shown
+    /// assert_eq!(recording.text(1).unwrap(), "▶ details");
+    ///
+    /// // clicking the header opens it, and the body grows over time
+    /// assert_eq!(recording.text(2).unwrap(), "▼ details\na");
+    /// assert_eq!(recording.text(3).unwrap(), "▼ details\na\nb");
+    /// assert_eq!(toggled, [false, false, false, true, false]);
+    /// assert!(open);
+    /// ```
+    pub fn collapsible<R: 'static>(
+        &self,
+        open: &mut bool,
+        header: impl Into<Str>,
+        show: impl FnOnce(&Ui) -> R,
+    ) -> Response<(views::CollapsibleResponse, R)> {
+        self.show_children(views::collapsible(open, header), show)
+    }
+
+    /// A strip of tabs, with the content of the active tab below it
+    ///
+    /// `show` is called once for each tab, with its index, and each tab's content is kept in its own column.
+    /// Every tab is built each frame, so their views keep their state while another tab is active
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Key, Modifiers, MouseButton}, math::{pos2, vec2}, view::test::Harness};
+    /// let click = |x| {
+    ///     let pos = pos2(x, 0);
+    ///     let button = |down| Event::MouseButtonChanged {
+    ///         pos,
+    ///         button: MouseButton::Primary,
+    ///         down,
+    ///         modifiers: Modifiers::NONE,
+    ///     };
+    ///     [Event::MouseMove { pos }, button(true), button(false)]
+    /// };
+    ///
+    /// let mut active = 0;
+    /// let mut changed = vec![];
+    /// let recording = Harness::new(vec2(20, 3))
+    ///     .events(
+    ///         [Event::KeyPressed { key: Key::Char('x'), modifiers: Modifiers::NONE }]
+    ///             .into_iter()
+    ///             .chain(click(6))
+    ///             .chain(click(1)),
+    ///     )
+    ///     .run(7, |ui| {
+    ///         let resp = ui.tabs(&mut active, &["one", "two"], |ui, tab| match tab {
+    ///             0 => _ = ui.text_input(true),
+    ///             _ => _ = ui.label("second"),
+    ///         });
+    ///         changed.push(resp.changed());
+    ///     });
+    ///
+    /// assert_eq!(recording.text(0).unwrap(), " one  two\nx");
+    ///
+    /// // clicking the second tab replaces the content
+    /// assert_eq!(recording.text(3).unwrap(), " one  two\nsecond");
+    ///
+    /// // and the first tab's text input kept what was typed into it
+    /// assert_eq!(recording.text(6).unwrap(), " one  two\nx");
+    /// assert_eq!(changed.iter().filter(|&&c| c).count(), 2);
+    /// assert_eq!(active, 0);
+    /// ```
+    pub fn tabs(
+        &self,
+        active: &mut usize,
+        labels: &[&str],
+        mut show: impl FnMut(&Ui, usize),
+    ) -> Response<views::TabsResponse> {
+        let count = labels.len();
+        self.show_children(views::tabs(active, labels.iter().copied()), |ui| {
+            for index in 0..count {
+                ui.vertical(|ui| show(ui, index));
+            }
+        })
+        .flatten_left()
+    }
 }
@@ -0,0 +1,84 @@
+use crate::{
+    math::{Size, Space},
+    view::{Builder, Layout, View},
+};
+
+/// Lay out the children in the largest box with this aspect ratio (`width / height`) that fits, centered in the space
+///
+/// The ratio is in cells. Cells are generally about twice as tall as they are wide, so a ratio of `2.0` looks square.
+///
+/// A ratio that isn't positive is ignored, and the children are laid out like they would be without it.
+///
+/// See [`Ui::aspect_ratio`](crate::view::Ui::aspect_ratio) for a shorthand
+pub const fn aspect_ratio(ratio: f32) -> AspectRatio {
+    AspectRatio { ratio }
+}
+
+#[derive(Debug, Copy, Clone)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct AspectRatio {
+    ratio: f32,
+}
+
+impl AspectRatio {
+    // the largest box with the ratio that fits in `max`, if it is bounded in either direction
+    fn fit(&self, max: Size) -> Option<Size> {
+        let (width, height) = match (max.width.is_finite(), max.height.is_finite()) {
+            (true, true) => {
+                let width = max.width.min(max.height * self.ratio);
+                (width, width / self.ratio)
+            }
+            (true, false) => (max.width, max.width / self.ratio),
+            (false, true) => (max.height * self.ratio, max.height),
+            (false, false) => return None,
+        };
+        Some(Size::new(width.floor(), height.floor().min(max.height)))
+    }
+}
+
+impl<'v> Builder<'v> for AspectRatio {
+    type View = Self;
+}
+
+impl View for AspectRatio {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(this: Self::Args<'_>) -> Self {
+        this
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        if !(self.ratio.is_finite() && self.ratio > 0.0) {
+            return self.default_layout(layout, space);
+        }
+
+        // without a bound, this is as large as the children want to be
+        let Some(inner) = self.fit(space.max) else {
+            return self.default_layout(layout, space.loosen());
+        };
+
+        // this takes up the space it can, so the box is centered in it
+        let outer = Size::new(
+            if space.max.width.is_finite() {
+                space.max.width
+            } else {
+                inner.width
+            },
+            if space.max.height.is_finite() {
+                space.max.height
+            } else {
+                inner.height
+            },
+        );
+        let size = space.fit(outer);
+        let offset = (size - inner) / 2.0;
+
+        let node = layout.nodes.get_current();
+        for &child in &node.children {
+            layout.compute(child, Space::tight(inner));
+            layout.set_position(child, offset.floor());
+        }
+        size
+    }
+}
@@ -1,23 +1,48 @@
 use crate::{
-    renderer::Rgba,
-    view::{Builder, Render, View},
+    math::Rect,
+    renderer::{shapes::Drawable, GradientFill, Rgba},
+    view::{Builder, Render, View, ViewMarker},
 };
 
+use super::canvas::DrawFn;
+
+/// A view that fills its area with a color or a gradient, behind its children
+///
+/// See [`Ui::background`](crate::view::Ui::background). To paint with a closure or any [`Drawable`], see [`background_with`] and [`background`]
 #[derive(Copy, Clone)]
 #[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
 pub struct Background {
-    bg: Rgba,
+    fill: Fill,
+}
+
+#[derive(Copy, Clone)]
+enum Fill {
+    Color(Rgba),
+    Gradient(GradientFill),
 }
 
 impl std::fmt::Debug for Background {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.bg.fmt(f)
+        match &self.fill {
+            Fill::Color(bg) => bg.fmt(f),
+            Fill::Gradient(fill) => fill.fmt(f),
+        }
     }
 }
 
 impl Background {
+    /// Fill the area with a color
     pub fn new(bg: impl Into<Rgba>) -> Self {
-        Self { bg: bg.into() }
+        Self {
+            fill: Fill::Color(bg.into()),
+        }
+    }
+
+    /// Fill the area with a [`GradientFill`]
+    pub const fn gradient(fill: GradientFill) -> Self {
+        Self {
+            fill: Fill::Gradient(fill),
+        }
     }
 }
 
@@ -34,7 +59,111 @@ impl View for Background {
     }
 
     fn draw(&mut self, mut render: Render) {
-        render.fill_bg(self.bg);
+        match self.fill {
+            Fill::Color(bg) => _ = render.fill_bg(bg),
+            Fill::Gradient(fill) => _ = render.fill_gradient(fill),
+        }
         self.default_draw(render);
     }
 }
+
+/// A view that paints with a closure, behind its children
+///
+/// See [`background_with`] and [`background`]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct BackgroundWith {
+    paint: DrawFn,
+}
+
+impl std::fmt::Debug for BackgroundWith {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundWith").finish_non_exhaustive()
+    }
+}
+
+impl<'v> Builder<'v> for BackgroundWith {
+    type View = Self;
+}
+
+impl View for BackgroundWith {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        args
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, _: &crate::view::Ui) -> Self::Response {
+        self.paint = args.paint;
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let rect = render.local_rect();
+        (self.paint)(rect, &mut render);
+        self.default_draw(render);
+    }
+}
+
+/// Create a [`BackgroundWith`] that paints with this closure, before its children are drawn
+///
+/// The closure is called with the view's local rect and a [`Render`] that is cropped to it, like a [`canvas`](super::canvas).
+/// It doesn't change the layout, the view is as large as its children
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2, Rect}, renderer::{Color, Pixel, Rgba, Surface}, view::{CroppedSurface, State}, views::background_with};
+/// let mut state = State::default();
+/// let mut surface = Surface::new(vec2(10, 3));
+/// let red = Rgba::hex("#F00");
+///
+/// state.build(surface.rect(), |ui| {
+///     ui.show_children(
+///         background_with(move |rect, render| {
+///             // the view is only as large as its child
+///             assert_eq!(rect.size(), vec2(2, 1));
+///             render.fill_with(Pixel::new('#').fg(red).bg(red));
+///             // this is outside of the view, so it isn't drawn
+///             render.set(pos2(5, 0), Pixel::new('!').fg(red));
+///         }),
+///         |ui| ui.label("ab"),
+///     );
+/// });
+/// state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+///
+/// // the child is drawn on top of the background
+/// let cell = surface.get(pos2(1, 0)).unwrap();
+/// assert_eq!(cell.bg(), Color::Set(red));
+/// assert_ne!(cell.fg(), Color::Set(red));
+/// assert_ne!(surface.get(pos2(5, 0)).unwrap().fg(), Color::Set(red));
+/// ```
+pub fn background_with(
+    paint: impl FnMut(Rect, &mut Render) + ViewMarker + 'static,
+) -> BackgroundWith {
+    BackgroundWith {
+        paint: Box::new(paint),
+    }
+}
+
+/// Create a [`BackgroundWith`] that draws a [`Drawable`] behind its children
+///
+/// The shape is given the size of the view, which is as large as its children
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2}, renderer::{Color, Gradient, GradientFill, Surface}, view::{CroppedSurface, State}, views::background};
+/// let fill = GradientFill::horizontal(Gradient::RAINBOW1);
+///
+/// let mut state = State::default();
+/// let mut surface = Surface::new(vec2(10, 1));
+/// state.build(surface.rect(), |ui| {
+///     ui.show_children(background(fill), |ui| ui.label("abc"));
+/// });
+/// state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+///
+/// // the gradient spans the label, and stops where the view does
+/// let bg = |x| surface.get(pos2(x, 0)).unwrap().bg();
+/// assert_eq!(bg(0), Color::Set(Gradient::RAINBOW1.as_rgba(0.0)));
+/// assert_eq!(bg(2), Color::Set(Gradient::RAINBOW1.as_rgba(1.0)));
+/// assert_ne!(bg(3), bg(2));
+/// ```
+pub fn background(shape: impl Drawable + ViewMarker + 'static) -> BackgroundWith {
+    background_with(move |_, render| _ = render.shape(&shape))
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use compact_str::CompactString;
 use unicode_segmentation::UnicodeSegmentation as _;
 use unicode_width::UnicodeWidthStr as _;
@@ -13,6 +15,8 @@ use crate::{
     Str,
 };
 
+use super::WrapMode;
+
 pub type BorderClass = fn(&Palette, bool, bool) -> BorderStyle;
 
 #[derive(Copy, Clone, Debug)]
@@ -87,6 +91,39 @@ impl Frame {
     }
 }
 
+impl Frame {
+    // truncate the title to fit in `width` cells, ending it with an ellipsis if it was truncated
+    fn fit_title(title: &str, width: usize) -> (Cow<'_, str>, usize) {
+        #[allow(deprecated)]
+        let tw = measure_text(title).width as usize;
+        if tw <= width {
+            return (Cow::Borrowed(title), tw);
+        }
+
+        let available = width.saturating_sub(WrapMode::ELLIPSIS.width());
+        let mut out = String::new();
+        let mut used = 0;
+        for grapheme in title.graphemes(true) {
+            // a wide grapheme that would spill over is dropped, rather than split
+            if used + grapheme.width() > available {
+                break;
+            }
+            used += grapheme.width();
+            out.push_str(grapheme);
+        }
+
+        let trimmed = out.trim_end().len();
+        used -= out[trimmed..].width();
+        out.truncate(trimmed);
+
+        if width >= WrapMode::ELLIPSIS.width() {
+            out.push_str(WrapMode::ELLIPSIS);
+            used += WrapMode::ELLIPSIS.width();
+        }
+        (Cow::Owned(out), used)
+    }
+}
+
 impl<'v> Builder<'v> for Frame {
     type View = Self;
 }
@@ -168,26 +205,24 @@ impl View for Frame {
         // we don't really want to delegate to the label type because we do that
         // weird intersperse border-behind-title things
         if let Some(title) = &self.title {
-            #[allow(deprecated)]
-            let tw = measure_text(title);
+            // the title goes between the corners, and is truncated if its too wide
+            let inner = (w - 1).max(0) as usize;
+            let (title, tw) = Self::fit_title(title, inner);
 
-            let w = w as f32;
-            let x = match self.align {
-                Align::Min => 1.0,
-                Align::Center => (w - tw.width) / 2.0,
-                Align::Max => w - tw.width,
-            };
+            let x = 1 + match self.align {
+                Align::Min => 0,
+                Align::Center => (inner - tw) / 2,
+                Align::Max => inner - tw,
+            } as i32;
 
-            let mut start = 0.0;
+            let mut start = 0;
             let fg = style.title;
             for grapheme in title.graphemes(true) {
-                if grapheme.chars().all(|c| c.is_whitespace()) {
-                    start += grapheme.width() as f32;
-                    continue;
+                let width = grapheme.width() as i32;
+                if !grapheme.chars().all(|c| c.is_whitespace()) {
+                    render.set(pos2(x + start, 0), Grapheme::new(grapheme).fg(fg));
                 }
-                let cell = Grapheme::new(grapheme).fg(fg);
-                render.set((start + x, 0.0), cell);
-                start += grapheme.width() as f32
+                start += width;
             }
         }
 
@@ -204,6 +239,21 @@ pub fn border(border: Border) -> Frame {
     }
 }
 
+/// A border with a title drawn in its top edge
+///
+/// The frame is widened to fit the title when there is room, otherwise the title is truncated to fit between the corners
+///
+/// ```rust
+/// # use too::{layout::Align, renderer::Border, view::test::render_to_string, views::frame};
+/// let out = render_to_string(|ui| {
+///     ui.exact_width(10, |ui| {
+///         ui.show_children(frame(Border::THIN, "Settings!").title_align(Align::Max), |ui| {
+///             ui.label("hello")
+///         })
+///     });
+/// });
+/// assert_eq!(out, "┌Setting…┐\n│hello   │\n└────────┘");
+/// ```
 pub fn frame(border: Border, title: impl Into<Str>) -> Frame {
     Frame {
         border,
@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use compact_str::CompactString;
 
 #[allow(deprecated)]
 use crate::view::measure_text;
 
 use crate::{
+    animation::{easing, Animation},
+    backend::MouseButton,
     layout::Align,
     math::{Margin, Size, Space},
     renderer::{Rgba, TextShape},
@@ -107,10 +111,27 @@ pub struct Button {
     label: CompactString,
     margin: Margin,
     state: ButtonState,
+    // the state we're fading away from when the hover changes
+    fade_from: ButtonState,
     disabled: bool,
     main: Align,
     cross: Align,
     class: StyleKind<ButtonClass, ButtonStyle>,
+    repeat: Option<(Duration, Duration)>,
+    long_press: Duration,
+    hold: Option<Hold>,
+    repeated: bool,
+    long_pressed: bool,
+}
+
+// how long the button has been held down for, while the cursor is inside of it
+#[derive(Copy, Clone, Debug)]
+struct Hold {
+    button: MouseButton,
+    elapsed: f32,
+    next_repeat: f32,
+    long_pressed: bool,
+    repeated: bool,
 }
 
 impl Button {
@@ -119,13 +140,75 @@ impl Button {
             label: label.into().into_inner(),
             margin: Margin::symmetric(1, 0),
             state: ButtonState::None,
+            fade_from: ButtonState::None,
             disabled: false,
             main: Align::Min,
             cross: Align::Min,
             class: StyleKind::Deferred(ButtonStyle::default),
+            repeat: None,
+            long_press: Duration::from_millis(500),
+            hold: None,
+            repeated: false,
+            long_pressed: false,
         }
     }
 
+    /// Keep clicking while the button is held down
+    ///
+    /// The first repeat is after `initial_delay`, then once every `interval`. Each repeat is a [`ButtonResponse::clicked`] for that frame, and releasing the button after it repeated doesn't click again.
+    ///
+    /// Moving the cursor out of the button, or releasing it, stops the repeating.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use too::{backend::{Event, Modifiers, MouseButton}, math::{pos2, vec2}, view::test::Harness};
+    /// let pos = pos2(1, 0);
+    /// let press = Event::MouseButtonChanged { pos, button: MouseButton::Primary, down: true, modifiers: Modifiers::NONE };
+    ///
+    /// let mut clicks = 0;
+    /// Harness::new(vec2(10, 1))
+    ///     .dt(0.1)
+    ///     .events([Event::MouseMove { pos }, press.clone()])
+    ///     .run(10, |ui| {
+    ///         let repeat = (Duration::from_millis(300), Duration::from_millis(200));
+    ///         if ui.show(too::views::button("+").repeat(repeat.0, repeat.1)).clicked() {
+    ///             clicks += 1;
+    ///         }
+    ///     });
+    ///
+    /// // held for 0.8 seconds: once at 0.3, 0.5 and 0.7
+    /// assert_eq!(clicks, 3);
+    ///
+    /// // dragging inside of the button and releasing it isn't a click, and it stops the repeating
+    /// let at = pos2(4, 0);
+    /// let drag = Event::MouseDrag { pos: at, button: MouseButton::Primary, modifiers: Modifiers::NONE };
+    /// let release = Event::MouseButtonChanged { pos: at, button: MouseButton::Primary, down: false, modifiers: Modifiers::NONE };
+    ///
+    /// let mut clicks = 0;
+    /// Harness::new(vec2(10, 1))
+    ///     .dt(0.1)
+    ///     .events([Event::MouseMove { pos }, press, drag, release])
+    ///     .run(30, |ui| {
+    ///         let repeat = (Duration::from_millis(300), Duration::from_millis(200));
+    ///         if ui.show(too::views::button("repeat").repeat(repeat.0, repeat.1)).clicked() {
+    ///             clicks += 1;
+    ///         }
+    ///     });
+    /// assert_eq!(clicks, 0);
+    /// ```
+    pub const fn repeat(mut self, initial_delay: Duration, interval: Duration) -> Self {
+        self.repeat = Some((initial_delay, interval));
+        self
+    }
+
+    /// How long the button has to be held down for before its [long pressed](ButtonResponse::long_pressed)
+    ///
+    /// By default this is 500 milliseconds
+    pub const fn long_press(mut self, threshold: Duration) -> Self {
+        self.long_press = threshold;
+        self
+    }
+
     pub const fn text_horizontal_align(mut self, justify: Align) -> Self {
         self.main = justify;
         self
@@ -166,6 +249,39 @@ impl<'v> Builder<'v> for Button {
     type View = Self;
 }
 
+impl Button {
+    fn tick(&mut self, dt: f32) {
+        let Some(hold) = &mut self.hold else {
+            return;
+        };
+        hold.elapsed += dt;
+
+        if !hold.long_pressed && hold.elapsed >= self.long_press.as_secs_f32() {
+            hold.long_pressed = true;
+            self.long_pressed = true;
+        }
+
+        let Some((_, interval)) = self.repeat else {
+            return;
+        };
+        if hold.elapsed < hold.next_repeat {
+            return;
+        }
+
+        // a long frame only repeats once
+        let interval = interval.as_secs_f32();
+        while hold.next_repeat <= hold.elapsed {
+            if interval <= 0.0 {
+                hold.next_repeat = hold.elapsed + f32::EPSILON;
+                break;
+            }
+            hold.next_repeat += interval;
+        }
+        hold.repeated = true;
+        self.repeated = true;
+    }
+}
+
 impl View for Button {
     type Args<'v> = Self;
     type Response = ButtonResponse;
@@ -187,38 +303,102 @@ impl View for Button {
         self.disabled = builder.disabled;
         self.main = builder.main;
         self.cross = builder.cross;
+        self.repeat = builder.repeat;
+        self.long_press = builder.long_press;
 
         let state = self.state;
+        let repeated = std::mem::take(&mut self.repeated);
+        let long_pressed = std::mem::take(&mut self.long_pressed);
         if let ButtonState::Clicked = self.state {
             self.state = ButtonState::Hovered
         }
 
         if self.disabled {
-            self.state = ButtonState::Disabled
+            self.state = ButtonState::Disabled;
+            self.hold = None;
         } else if !self.disabled && matches!(self.state, ButtonState::Disabled) {
             self.state = ButtonState::None
         }
 
-        ButtonResponse { state }
+        ButtonResponse {
+            state,
+            repeated,
+            long_pressed,
+        }
     }
 
     fn interests(&self) -> Interest {
-        Interest::MOUSE_INSIDE
+        // the timers only need to tick while the button is held down
+        match self.hold {
+            Some(..) => Interest::MOUSE_INSIDE | Interest::ANIMATE,
+            None => Interest::MOUSE_INSIDE,
+        }
     }
 
-    fn event(&mut self, event: ViewEvent, _ctx: EventCtx) -> Handled {
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
         if matches!(self.state, ButtonState::Disabled) {
             return Handled::Bubble;
         }
 
+        if let ViewEvent::Tick { dt } = event {
+            // releasing the button after dragging isn't a click, so nothing else clears the hold
+            if self
+                .hold
+                .is_some_and(|hold| !ctx.is_mouse_down(hold.button))
+            {
+                self.hold = None;
+                self.state = if ctx.is_hovered() {
+                    ButtonState::Hovered
+                } else {
+                    ButtonState::None
+                };
+                return Handled::Bubble;
+            }
+            self.tick(dt);
+            return Handled::Bubble;
+        }
+
+        let old = self.state;
         self.state = match event {
-            ViewEvent::MouseClicked { inside: true, .. } => ButtonState::Clicked,
-            ViewEvent::MouseHeld { inside: true, .. } => ButtonState::Held,
+            ViewEvent::MouseClicked { inside: true, .. } => {
+                match self.hold.take() {
+                    // the repeats were the clicks
+                    Some(hold) if hold.repeated => ButtonState::Hovered,
+                    _ => ButtonState::Clicked,
+                }
+            }
+            ViewEvent::MouseHeld {
+                inside: true,
+                button,
+                ..
+            } => {
+                self.hold = Some(Hold {
+                    button,
+                    elapsed: 0.0,
+                    next_repeat: self.repeat.map_or(0.0, |(delay, _)| delay.as_secs_f32()),
+                    long_pressed: false,
+                    repeated: false,
+                });
+                ButtonState::Held
+            }
             ViewEvent::MouseEntered => ButtonState::Hovered,
-            ViewEvent::MouseLeave => ButtonState::None,
+            ViewEvent::MouseLeave => {
+                self.hold = None;
+                ButtonState::None
+            }
             _ => return Handled::Bubble,
         };
 
+        if matches!(event, ViewEvent::MouseEntered | ViewEvent::MouseLeave) {
+            self.fade_from = old;
+            let fade = Animation::new()
+                .oneshot(true)
+                .with(easing::sine_in_out)
+                .schedule(Duration::from_millis(150))
+                .unwrap();
+            ctx.animation.add(ctx.current, fade, 0.0);
+        }
+
         Handled::Sink
     }
 
@@ -229,27 +409,44 @@ impl View for Button {
     }
 
     fn draw(&mut self, mut render: Render) {
-        let style = match self.class {
-            StyleKind::Deferred(class) => (class)(render.palette, self.state),
-            StyleKind::Direct(style) => style,
+        let (style, from) = match self.class {
+            StyleKind::Deferred(class) => (
+                (class)(render.palette, self.state),
+                (class)(render.palette, self.fade_from),
+            ),
+            StyleKind::Direct(style) => (style, style),
         };
 
-        render
-            .fill_bg(style.background)
-            .shrink(self.margin, |render| {
-                render.text(TextShape::new(&self.label).fg(style.text_color));
-            });
+        // fade the background between hover states
+        let background = match render.animation.get(render.current) {
+            Some(fade) => from.background.lerp(style.background, *fade.value),
+            None => style.background,
+        };
+
+        render.fill_bg(background).shrink(self.margin, |render| {
+            render.text(TextShape::new(&self.label).fg(style.text_color));
+        });
     }
 }
 
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct ButtonResponse {
     state: ButtonState,
+    repeated: bool,
+    long_pressed: bool,
 }
 
 impl ButtonResponse {
+    /// Was the button clicked, or did it [repeat](Button::repeat), this frame?
     pub const fn clicked(&self) -> bool {
-        matches!(self.state, ButtonState::Clicked)
+        matches!(self.state, ButtonState::Clicked) || self.repeated
+    }
+
+    /// Was the button held down past its [long press](Button::long_press) threshold this frame?
+    ///
+    /// This is only `true` for the frame the threshold was reached, and the button still clicks when it's released
+    pub const fn long_pressed(&self) -> bool {
+        self.long_pressed
     }
 
     pub const fn hovered(&self) -> bool {
@@ -0,0 +1,109 @@
+use crate::{
+    math::{Rect, Size, Space},
+    view::{Builder, Layout, Render, View, ViewMarker},
+};
+
+#[cfg(not(feature = "sync"))]
+pub(super) type DrawFn = Box<dyn FnMut(Rect, &mut Render)>;
+#[cfg(feature = "sync")]
+pub(super) type DrawFn = Box<dyn FnMut(Rect, &mut Render) + Send + Sync>;
+
+/// A view that lets you draw whatever you want with a closure
+///
+/// See [`canvas`]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Canvas {
+    draw: DrawFn,
+    size: Option<Size>,
+}
+
+impl std::fmt::Debug for Canvas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Canvas")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Canvas {
+    /// Ask for this size, rather than all of the available space
+    pub fn size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl<'v> Builder<'v> for Canvas {
+    type View = Self;
+}
+
+impl View for Canvas {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        args
+    }
+
+    fn layout(&mut self, _: Layout, space: Space) -> Size {
+        let Some(size) = self.size else {
+            // inside of something unbounded (like a scrollable) there is no available space to take
+            let max = space.max;
+            let size = Size::new(
+                if max.width.is_finite() {
+                    max.width
+                } else {
+                    space.min.width
+                },
+                if max.height.is_finite() {
+                    max.height
+                } else {
+                    space.min.height
+                },
+            );
+            return size;
+        };
+        space.fit(size)
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let rect = render.local_rect();
+        (self.draw)(rect, &mut render)
+    }
+}
+
+/// Create a [`Canvas`] that draws with this closure
+///
+/// The closure is called when the view is drawn, with its local rect and a [`Render`] that is cropped to it.
+///
+/// By default it takes up all of the available space, use [`Canvas::size`] to ask for a specific size.
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2, Rect, Size}, renderer::{Color, Pixel, Rgba, Surface}, view::{CroppedSurface, State}, views::canvas};
+/// let mut state = State::default();
+/// let mut surface = Surface::new(vec2(10, 3));
+/// let red = Rgba::hex("#F00");
+///
+/// state.build(surface.rect(), |ui| {
+///     ui.horizontal(|ui| {
+///         ui.label("ab");
+///         ui.show(canvas(move |rect, render| {
+///             assert_eq!(rect.size(), vec2(2, 1));
+///             // this is outside of the canvas, so it isn't drawn
+///             render.set(pos2(5, 0), Pixel::new('!').fg(red));
+///             render.set(pos2(0, 0), Pixel::new('#').fg(red));
+///         }).size(Size::new(2.0, 1.0)));
+///     });
+/// });
+/// state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+///
+/// // the canvas is placed after the label and the gap between them
+/// assert_eq!(surface.get(pos2(3, 0)).unwrap().fg(), Color::Set(red));
+/// assert_ne!(surface.get(pos2(8, 0)).unwrap().fg(), Color::Set(red));
+/// ```
+pub fn canvas(draw: impl FnMut(Rect, &mut Render) + ViewMarker + 'static) -> Canvas {
+    Canvas {
+        draw: Box::new(draw),
+        size: None,
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
+    backend::Key,
     renderer::Rgba,
-    view::{Builder, Palette, StyleKind, Ui, View},
+    view::{Builder, EventCtx, Handled, Interest, Palette, StyleKind, Ui, View, ViewEvent},
     Str,
 };
 
@@ -12,6 +13,8 @@ pub type CheckboxClass = fn(&Palette, bool) -> CheckboxStyle;
 pub struct CheckboxStyle {
     pub checked: &'static str,
     pub unchecked: &'static str,
+    /// The marker for a [`TriState::Indeterminate`] checkbox
+    pub indeterminate: &'static str,
     pub text_color: Rgba,
     pub hovered_color: Option<Rgba>,
 }
@@ -21,6 +24,7 @@ impl CheckboxStyle {
         Self {
             checked: "[X]",
             unchecked: "[ ]",
+            indeterminate: "[-]",
             ..Self::ascii(palette, checked)
         }
     }
@@ -29,6 +33,7 @@ impl CheckboxStyle {
         Self {
             checked: "🗹",
             unchecked: "☐",
+            indeterminate: "▣",
             text_color: palette.foreground,
             hovered_color: Some(palette.contrast),
         }
@@ -114,3 +119,201 @@ pub fn checkbox(value: &mut bool, label: impl Into<Str>) -> Checkbox<'_> {
         class: StyleKind::deferred(CheckboxStyle::ascii),
     }
 }
+
+/// The state of a [tri-state checkbox](checkbox_tristate)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TriState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Neither checked nor unchecked, e.g. a "select all" when only some things are selected
+    Indeterminate,
+}
+
+impl TriState {
+    /// The state after toggling this one
+    ///
+    /// Checked and unchecked flip, and indeterminate becomes checked
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Checked => Self::Unchecked,
+            Self::Unchecked | Self::Indeterminate => Self::Checked,
+        }
+    }
+
+    pub const fn is_checked(&self) -> bool {
+        matches!(self, Self::Checked)
+    }
+
+    pub const fn is_indeterminate(&self) -> bool {
+        matches!(self, Self::Indeterminate)
+    }
+}
+
+impl From<bool> for TriState {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
+#[derive(Debug)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct TriCheckbox<'a> {
+    value: &'a mut TriState,
+    label: Str,
+    class: StyleKind<CheckboxClass, CheckboxStyle>,
+}
+
+impl<'a> TriCheckbox<'a> {
+    pub const fn class(mut self, class: CheckboxClass) -> Self {
+        self.class = StyleKind::deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: CheckboxStyle) -> Self {
+        self.class = StyleKind::direct(style);
+        self
+    }
+}
+
+impl<'v> Builder<'v> for TriCheckbox<'v> {
+    type View = TriCheckboxView;
+}
+
+/// The response from a [`TriCheckbox`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TriCheckboxResponse {
+    state: TriState,
+    changed: bool,
+}
+
+impl TriCheckboxResponse {
+    /// The state of the checkbox, after any toggle this frame
+    pub const fn state(&self) -> TriState {
+        self.state
+    }
+
+    /// Was the checkbox toggled this frame?
+    pub const fn changed(&self) -> bool {
+        self.changed
+    }
+}
+
+/// A checkbox that can also be indeterminate
+///
+/// Clicking it, or pressing `Space` or `Enter` while its focused, toggles it. See [`TriState::toggled`]
+#[derive(Debug)]
+pub struct TriCheckboxView {
+    label: Str,
+    toggled: bool,
+    class: StyleKind<CheckboxClass, CheckboxStyle>,
+}
+
+impl View for TriCheckboxView {
+    type Args<'v> = TriCheckbox<'v>;
+    type Response = TriCheckboxResponse;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            label: args.label,
+            toggled: false,
+            class: args.class,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.label = args.label;
+        self.class = args.class;
+
+        let changed = std::mem::take(&mut self.toggled);
+        if changed {
+            *args.value = args.value.toggled();
+        }
+
+        let style = match self.class {
+            StyleKind::Deferred(style) => (style)(&ui.palette(), args.value.is_checked()),
+            StyleKind::Direct(style) => style,
+        };
+
+        let foreground = if ui.is_hovered() || ui.is_focused() {
+            style.hovered_color.unwrap_or(style.text_color)
+        } else {
+            style.text_color
+        };
+
+        ui.horizontal(|ui| {
+            let marker = match args.value {
+                TriState::Unchecked => style.unchecked,
+                TriState::Checked => style.checked,
+                TriState::Indeterminate => style.indeterminate,
+            };
+            ui.label(marker);
+            ui.show(super::label(&self.label).style(LabelStyle { foreground }));
+        });
+
+        TriCheckboxResponse {
+            state: *args.value,
+            changed,
+        }
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        Interest::MOUSE_INSIDE | Interest::FOCUS_INPUT
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        match event {
+            ViewEvent::MouseClicked { inside: true, .. } => {
+                ctx.input.set_focus(Some(ctx.current));
+                self.toggled = true;
+            }
+            ViewEvent::KeyInput {
+                key: Key::Char(' ') | Key::Enter,
+                ..
+            } => self.toggled = true,
+            _ => return Handled::Bubble,
+        }
+        Handled::Sink
+    }
+}
+
+/// A checkbox with three states, see [`TriState`]
+///
+/// Its toggled by clicking it, or by pressing `Space` or `Enter` while its focused
+///
+/// ```rust
+/// # use too::{backend::{Event, Key, Modifiers}, math::{pos2, vec2, Rect}, view::{test::click_at, State}, views::{checkbox_tristate, TriState}};
+/// let mut state = State::default();
+/// let mut select_all = TriState::Indeterminate;
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(80, 25));
+/// let mut app = |ui: &too::view::Ui| {
+///     ui.show(checkbox_tristate(&mut select_all, "select all")).into_inner()
+/// };
+/// state.build(rect, &mut app);
+///
+/// // indeterminate becomes checked
+/// let resp = click_at(&mut state, pos2(0, 0), &mut app);
+/// assert_eq!(resp.state(), TriState::Checked);
+/// assert!(resp.changed());
+///
+/// // the click also focused it, so the keyboard toggles it too
+/// state.event(&Event::KeyPressed { key: Key::Char(' '), modifiers: Modifiers::NONE });
+/// let resp = state.build(rect, &mut app);
+/// assert_eq!(resp.state(), TriState::Unchecked);
+/// assert!(resp.changed());
+/// ```
+pub fn checkbox_tristate(value: &mut TriState, label: impl Into<Str>) -> TriCheckbox<'_> {
+    TriCheckbox {
+        value,
+        label: label.into(),
+        class: StyleKind::deferred(CheckboxStyle::ascii),
+    }
+}
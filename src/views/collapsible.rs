@@ -1,18 +1,34 @@
+use std::time::Duration;
+
+use unicode_width::UnicodeWidthStr as _;
+
 use crate::{
-    renderer::Border,
-    view::{Adhoc, Palette, Response, StyleKind, Ui},
+    animation::{easing, Animation},
+    layout::Axis,
+    math::{pos2, vec2, Rect, Size, Space},
+    renderer::{Rgba, TextShape},
+    view::{
+        Builder, EventCtx, Handled, Interest, Layout, Palette, Render, StyleKind, Ui, View,
+        ViewEvent,
+    },
+    Str,
 };
 
-use super::border::BorderStyle;
-
 pub type CollapsibleClass = fn(&Palette, bool) -> CollapsibleStyle;
 
-#[derive(Debug)]
+/// The style of a [`Collapsible`]'s header
+#[derive(Debug, Copy, Clone)]
 pub struct CollapsibleStyle {
+    /// The marker shown when the body is open
     pub expanded: &'static str,
+    /// The marker shown when the body is closed
     pub collapsed: &'static str,
-    pub border: Border,
-    pub border_style: BorderStyle,
+    /// The color of the header
+    pub foreground: Rgba,
+    /// The color of the header when its hovered
+    pub hovered: Option<Rgba>,
+    /// The background color of the header
+    pub background: Option<Rgba>,
 }
 
 impl CollapsibleStyle {
@@ -20,31 +36,31 @@ impl CollapsibleStyle {
         Self {
             expanded: "▼",
             collapsed: "▶",
-            border: Border::THICK,
-            border_style: BorderStyle::default(palette, false, false),
-        }
-    }
-
-    pub fn interactive(palette: &Palette, _expanded: bool) -> Self {
-        Self {
-            expanded: "▼",
-            collapsed: "▶",
-            border: Border::THICK,
-            border_style: BorderStyle::interactive(palette, true, true),
+            foreground: palette.foreground,
+            hovered: Some(palette.primary),
+            background: None,
         }
     }
 }
 
 #[derive(Debug)]
-#[must_use = "a view does nothing unless `ui.adhoc()` is called"]
-pub struct Collapsible<'a, F> {
-    state: &'a mut bool,
-    title: &'a str,
-    show: F,
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Collapsible<'a> {
+    open: &'a mut bool,
+    header: Str,
+    duration: Duration,
     class: StyleKind<CollapsibleClass, CollapsibleStyle>,
 }
 
-impl<'a, F> Collapsible<'a, F> {
+impl<'a> Collapsible<'a> {
+    /// How long opening or closing the body takes
+    ///
+    /// By default this is `150ms`
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
     pub const fn class(mut self, class: CollapsibleClass) -> Self {
         self.class = StyleKind::Deferred(class);
         self
@@ -56,58 +72,287 @@ impl<'a, F> Collapsible<'a, F> {
     }
 }
 
-impl<'v, F, R: 'static> Adhoc<'v> for Collapsible<'v, F>
-where
-    F: FnOnce(&Ui) -> R,
-{
-    type Output = Response<Option<R>>;
+impl<'v> Builder<'v> for Collapsible<'v> {
+    type View = CollapsibleView;
+}
 
-    fn show(self, ui: &Ui) -> Self::Output {
-        let style = match self.class {
-            StyleKind::Deferred(style) => (style)(&ui.palette(), *self.state),
+/// The response from a [`Collapsible`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CollapsibleResponse {
+    open: bool,
+    toggled: bool,
+}
+
+impl CollapsibleResponse {
+    /// Is the body open?
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Was the header clicked this frame?
+    pub const fn toggled(&self) -> bool {
+        self.toggled
+    }
+}
+
+/// A clickable header, with a body that is shown below it when its open
+///
+/// The body's height animates between nothing and its full height, and its clipped while it does.
+///
+/// Once closed, the body isn't laid out, so it can't be drawn or get any events. If the focus was inside of the body, the focus is removed
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use too::view::{test::type_text, State, Ui};
+/// let mut state = State::default();
+/// let open = Cell::new(true);
+/// let mut focus = true;
+/// let mut app = |ui: &Ui| {
+///     let mut is_open = open.get();
+///     let (_, text) = ui
+///         .collapsible(&mut is_open, "details", |ui| {
+///             ui.vertical(|ui| {
+///                 let resp = ui.text_input(std::mem::take(&mut focus));
+///                 resp.into_inner().data().to_string()
+///             })
+///         })
+///         .into_inner();
+///     text.into_inner()
+/// };
+///
+/// assert_eq!(type_text(&mut state, "a", &mut app), "a");
+///
+/// // once its closed, the nested input doesn't get the key
+/// open.set(false);
+/// state.update(1.0);
+/// assert_eq!(type_text(&mut state, "b", &mut app), "a");
+/// assert_eq!(state.focus(), None);
+/// ```
+///
+/// While its opening or closing, its [animating](crate::view::State::is_animating)
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use too::{math::{pos2, vec2, Rect}, view::{State, Ui}};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 5));
+/// let open = Cell::new(false);
+/// let app = |ui: &Ui| {
+///     let mut is_open = open.get();
+///     ui.collapsible(&mut is_open, "details", |ui| _ = ui.label("body"));
+/// };
+///
+/// state.build(rect, app);
+/// state.update(1.0);
+/// state.build(rect, app);
+/// assert!(!state.is_animating());
+///
+/// open.set(true);
+/// state.update(0.01);
+/// state.build(rect, app);
+/// assert!(state.is_animating());
+///
+/// state.update(1.0);
+/// state.build(rect, app);
+/// assert!(!state.is_animating());
+/// ```
+pub struct CollapsibleView {
+    open: bool,
+    clicked: bool,
+    header: Str,
+    header_width: i32,
+    animation: Animation,
+    visible: f32,
+    class: StyleKind<CollapsibleClass, CollapsibleStyle>,
+}
+
+impl std::fmt::Debug for CollapsibleView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollapsibleView")
+            .field("open", &self.open)
+            .field("header", &self.header)
+            .field("visible", &self.visible)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CollapsibleView {
+    // this plays forward as the body opens, and backwards as it closes
+    fn animation(duration: Duration, open: bool) -> Animation {
+        let mut animation = Animation::new()
+            .with(easing::sine_in_out)
+            .schedule(duration.max(Duration::from_millis(1)))
+            .unwrap();
+
+        if open {
+            animation.set_position(1.0);
+        } else {
+            animation.reverse();
+        }
+        animation
+    }
+
+    fn marker(&self, style: &CollapsibleStyle) -> &'static str {
+        if self.open {
+            style.expanded
+        } else {
+            style.collapsed
+        }
+    }
+
+    fn style(&self, palette: &Palette) -> CollapsibleStyle {
+        match self.class {
+            StyleKind::Deferred(class) => (class)(palette, self.open),
             StyleKind::Direct(style) => style,
+        }
+    }
+}
+
+impl View for CollapsibleView {
+    type Args<'v> = Collapsible<'v>;
+    type Response = CollapsibleResponse;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        let open = *args.open;
+        Self {
+            open,
+            clicked: false,
+            // the marker is measured once the palette is available, in `update`
+            header_width: args.header.width() as i32 + 2,
+            header: args.header,
+            animation: Self::animation(args.duration, open),
+            visible: if open { 1.0 } else { 0.0 },
+            class: args.class,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.header = args.header;
+        self.class = args.class;
+
+        let toggled = std::mem::take(&mut self.clicked);
+        if toggled {
+            *args.open = !*args.open;
+        }
+
+        // this can also be changed by the application
+        if *args.open != self.open {
+            self.open = *args.open;
+            self.animation.reverse();
+        }
+
+        let value = self.animation.update(ui.dt());
+        self.visible = match (self.animation.is_finished(), self.open) {
+            (true, true) => 1.0,
+            (true, false) => 0.0,
+            (false, _) => value.clamp(0.0, 1.0),
         };
 
-        ui.vertical(|ui| {
-            let resp = ui.mouse_area(|ui| {
-                if *self.state {
-                    let inner = ui.show_children(
-                        // TODO make frame take a label so we can change the color of the text, but not the marker
-                        super::frame(style.border, format!("{} {}", style.expanded, self.title))
-                            .style(style.border_style),
-                        self.show,
-                    );
-                    return Some(inner);
+        let style = self.style(&ui.palette());
+        self.header_width = (self.marker(&style).width() + 1 + self.header.width()) as i32;
+
+        CollapsibleResponse {
+            open: self.open,
+            toggled,
+        }
+    }
+
+    fn primary_axis(&self) -> Axis {
+        Axis::Vertical
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        // the body only needs to tick while its opening or closing
+        if self.animation.is_finished() {
+            Interest::MOUSE_INSIDE
+        } else {
+            Interest::MOUSE_INSIDE | Interest::ANIMATE
+        }
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        match event {
+            // only the header toggles the body
+            ViewEvent::MouseClicked {
+                pos, inside: true, ..
+            } if pos.y == ctx.rect().top() => {
+                self.clicked = true;
+                Handled::Sink
+            }
+            _ => Handled::Bubble,
+        }
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let header = vec2(self.header_width, 1);
+
+        let node = layout.nodes.get_current();
+        if self.visible <= 0.0 {
+            for &child in &node.children {
+                layout.remove(child);
+            }
+            return space.fit(Size::from(header));
+        }
+
+        layout.enable_clipping();
+
+        let body_space = Space::new(Size::ZERO, Size::new(space.max.width, f32::INFINITY));
+        let mut body = Size::ZERO;
+        for &child in &node.children {
+            let size = layout.compute(child, body_space);
+            layout.set_position(child, pos2(0, 1));
+            body = body.max(size);
+        }
+
+        let height = (body.height * self.visible).round();
+        space.fit(Size::new(
+            body.width.max(header.x as f32),
+            header.y as f32 + height,
+        ))
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let style = self.style(render.palette);
+        let rect = render.rect();
+        let header = Rect::from_min_size(rect.left_top(), vec2(rect.width(), 1));
+
+        let fg = match style.hovered {
+            Some(hovered) if header.contains(render.mouse_pos()) => hovered,
+            _ => style.foreground,
+        };
+
+        let local = render.local_rect();
+        render.crop(
+            Rect::from_min_size(local.left_top(), header.size()),
+            |render| {
+                if let Some(bg) = style.background {
+                    render.fill_bg(bg);
                 }
+                let text = format!("{} {}", self.marker(&style), &*self.header);
+                render.text(TextShape::new(&text).fg(fg));
+            },
+        );
 
-                ui.show_children(
-                    super::border(style.border).style(style.border_style),
-                    |ui| {
-                        ui.horizontal(|ui| {
-                            ui.label(style.collapsed);
-                            ui.label(self.title);
-                        })
-                    },
-                );
-                None
-            });
-            *self.state ^= resp.0.clicked();
-            resp.flatten_right()
-        })
-        .into_inner()
-        .map(|c| c.map(|c| c.flatten_right().into_inner()))
+        if self.visible > 0.0 {
+            self.default_draw(render);
+        }
     }
 }
 
-pub fn collapsible<'a, R: 'static>(
-    state: &'a mut bool,
-    title: &'a str,
-    show: impl FnOnce(&Ui) -> R,
-) -> Collapsible<'a, impl FnOnce(&Ui) -> R> {
+/// A header you can click to open and close a body below it
+///
+/// Its children are the body. Clicking the header toggles `open`.
+///
+/// See [`Ui::collapsible`](crate::view::Ui::collapsible) for a shorthand
+pub fn collapsible(open: &mut bool, header: impl Into<Str>) -> Collapsible<'_> {
     Collapsible {
-        state,
-        title,
-        show,
+        open,
+        header: header.into(),
+        duration: Duration::from_millis(150),
         class: StyleKind::Deferred(CollapsibleStyle::default),
     }
 }
@@ -0,0 +1,98 @@
+use crate::{
+    layout::Axis,
+    math::Size,
+    renderer::Rgba,
+    view::{Builder, Ui, View},
+};
+
+use super::{label, slider, Fill};
+
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct ColorPicker<'a> {
+    color: &'a mut Rgba,
+}
+
+impl<'v> Builder<'v> for ColorPicker<'v> {
+    type View = ColorPickerView;
+}
+
+/// A hue, saturation and lightness slider for a color, with a swatch of it
+///
+/// The response is whether the color was changed. Its alpha is kept as-is
+#[derive(Debug)]
+pub struct ColorPickerView {
+    color: Rgba,
+    hue: f32,
+    saturation: f32,
+    lightness: f32,
+}
+
+impl ColorPickerView {
+    fn set_color(&mut self, color: Rgba) {
+        let (hue, saturation, lightness) = color.to_hsl();
+        // grays don't have a hue, so keep the previous one rather than jumping back to red
+        if saturation > 0.0 {
+            self.hue = hue;
+        }
+        self.saturation = saturation;
+        self.lightness = lightness;
+        self.color = color;
+    }
+}
+
+impl View for ColorPickerView {
+    type Args<'v> = ColorPicker<'v>;
+    type Response = bool;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        let mut this = Self {
+            color: *args.color,
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.0,
+        };
+        this.set_color(*args.color);
+        this
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        if *args.color != self.color {
+            self.set_color(*args.color);
+        }
+
+        ui.vertical(|ui| {
+            let hsl = (self.hue, self.saturation, self.lightness);
+            for (name, value, max) in [
+                ("H", &mut self.hue, 360.0),
+                ("S", &mut self.saturation, 1.0),
+                ("L", &mut self.lightness, 1.0),
+            ] {
+                ui.horizontal(|ui| {
+                    ui.show(label(name));
+                    ui.show(slider(value).range(0.0..=max));
+                });
+            }
+
+            if hsl != (self.hue, self.saturation, self.lightness) {
+                self.color = Rgba::from_hsl(self.hue, self.saturation, self.lightness)
+                    .with_alpha(self.color.alpha());
+            }
+            ui.show(Fill::new(self.color, Size::new(f32::INFINITY, 1.0)));
+        });
+
+        let changed = *args.color != self.color;
+        *args.color = self.color;
+        changed
+    }
+
+    fn primary_axis(&self) -> Axis {
+        Axis::Vertical
+    }
+}
+
+/// Pick a color with hue, saturation and lightness sliders
+///
+/// See [`ColorPickerView`]
+pub fn color_picker(color: &mut Rgba) -> ColorPicker<'_> {
+    ColorPicker { color }
+}
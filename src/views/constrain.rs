@@ -1,8 +1,13 @@
+use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+
 use crate::{
+    layout::Axis,
     math::{Size, Space},
-    view::{Builder, Layout, View},
+    view::{Builder, IntrinsicSize, Layout, Ui, View},
 };
 
+use super::forward_view;
+
 #[derive(Debug, Copy, Clone)]
 #[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
 pub struct Constrain {
@@ -90,6 +95,77 @@ impl View for Constrain {
     }
 }
 
+/// A builder with its [`Space`] constrained
+///
+/// This is created by [`ViewExt::min_size`](crate::view::ViewExt::min_size), [`ViewExt::max_size`](crate::view::ViewExt::max_size) and [`ViewExt::exact_size`](crate::view::ViewExt::exact_size)
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Constrained<B> {
+    space: Space,
+    builder: B,
+}
+
+impl<B> Constrained<B> {
+    pub(crate) fn new(constrain: Constrain, builder: B) -> Self {
+        Self {
+            space: constrain.space,
+            builder,
+        }
+    }
+}
+
+impl<'v, B: Builder<'v>> Builder<'v> for Constrained<B> {
+    type View = ConstrainedView<B::View>;
+}
+
+/// A view that lays out its inner view in a constrained [`Space`]
+///
+/// Everything other than layout is passed through to the inner view, so it keeps its response, flex and events
+#[derive(Debug)]
+pub struct ConstrainedView<V> {
+    space: Space,
+    view: V,
+}
+
+impl<V: View> View for ConstrainedView<V> {
+    type Args<'v> = Constrained<V::Args<'v>>;
+    type Response = V::Response;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            space: args.space,
+            view: V::create(args.builder),
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.space = args.space;
+        self.view.update(args.builder, ui)
+    }
+
+    fn size(&self, intrinsic: IntrinsicSize, axis: Axis, extent: f32) -> f32 {
+        let size = self.view.size(intrinsic, axis, extent);
+        size.clamp(axis.main(self.space.min), axis.main(self.space.max))
+    }
+
+    fn layout_hash(&self) -> Option<u64> {
+        let hash = self.view.layout_hash()?;
+        let mut hasher = DefaultHasher::new();
+        hash.hash(&mut hasher);
+        for value in [self.space.min, self.space.max] {
+            (value.width.to_bits(), value.height.to_bits()).hash(&mut hasher);
+        }
+        Some(hasher.finish())
+    }
+
+    fn layout(&mut self, layout: Layout, space: Space) -> Size {
+        // an unbounded max (e.g. from `min_size`) keeps the parent's max, so flex children don't collapse
+        let constrained = self.space.constrain(space);
+        self.view.layout(layout, constrained)
+    }
+
+    forward_view!(view => flex, interactive, interests, primary_axis, event, draw);
+}
+
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Unconstrained {
     pub horizontal: bool,
@@ -1,170 +1,430 @@
-use std::borrow::Cow;
+use std::marker::PhantomData;
 
 use crate::{
-    view::{Adhoc, Palette, Response, Ui},
-    Key,
+    backend::Key,
+    lock::{Lock, Shared},
+    math::{pos2, vec2, Rect, Size, Space},
+    renderer::{Pixel, Rgba, TextShape},
+    view::{
+        Builder, EventCtx, Handled, Interest, Layout, Palette, Render, StyleKind, Ui, View,
+        ViewEvent,
+    },
+    Str,
 };
 
-pub trait DropDownItem: PartialEq {
-    fn short(&self) -> Cow<'static, str>;
-    fn long(&self) -> Cow<'static, str> {
-        self.short()
+/// The style of a [`DropDown`]
+#[derive(Debug, Copy, Clone)]
+pub struct DropDownStyle {
+    /// The glyph drawn at the end of the selection
+    pub indicator: char,
+
+    pub text_color: Rgba,
+    pub background: Rgba,
+
+    /// The background of the currently selected item in the popup
+    pub selected_background: Rgba,
+
+    /// The colors of the highlighted item in the popup
+    pub highlight_text: Rgba,
+    pub highlight_background: Rgba,
+}
+
+impl DropDownStyle {
+    pub fn default(palette: &Palette, open: bool) -> Self {
+        Self {
+            indicator: if open { '▲' } else { '▼' },
+            text_color: palette.foreground,
+            background: palette.surface,
+            selected_background: palette.outline,
+            highlight_text: palette.surface,
+            highlight_background: palette.primary,
+        }
+    }
+}
+
+/// The style class for a [`DropDown`], the `bool` is whether the popup is open
+pub type DropDownClass = fn(&Palette, bool) -> DropDownStyle;
+
+/// A view that shows the selected item, and opens a popup list of items when clicked
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct DropDown<'a, T> {
+    selected: &'a mut usize,
+    items: &'a [T],
+    class: StyleKind<DropDownClass, DropDownStyle>,
+}
+
+impl<'a, T> DropDown<'a, T> {
+    pub const fn class(mut self, class: DropDownClass) -> Self {
+        self.class = StyleKind::Deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: DropDownStyle) -> Self {
+        self.class = StyleKind::Direct(style);
+        self
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+impl<'v, T: AsRef<str> + 'static> Builder<'v> for DropDown<'v, T> {
+    type View = DropDownView<T>;
+}
+
+/// The response for a [`DropDown`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct DropDownResponse {
-    selected: Option<usize>,
+    changed: bool,
+    selected: usize,
 }
 
 impl DropDownResponse {
-    pub fn resolve<'a, V: DropDownItem>(&self, values: &'a [V]) -> Option<&'a V> {
-        self.selected.and_then(|index| values.get(index))
+    /// Was a new item chosen this frame?
+    pub const fn changed(&self) -> bool {
+        self.changed
     }
 
-    pub const fn selected(&self) -> Option<usize> {
+    /// The index of the selected item
+    pub const fn selected(&self) -> usize {
         self.selected
     }
 }
 
-pub struct DropDownState {
-    pub expanded: bool,
-    pub index: usize,
-    pub hovered: Option<usize>,
+#[derive(Debug, Default)]
+struct DropDownState {
+    items: Vec<Str>,
+    selected: usize,
+    highlight: usize,
+    open: bool,
+    chosen: Option<usize>,
 }
 
-impl Default for DropDownState {
-    fn default() -> Self {
-        Self::new(0)
+impl DropDownState {
+    fn open(&mut self) {
+        self.open = true;
+        self.highlight = self.selected;
     }
-}
 
-impl DropDownState {
-    pub const fn new(index: usize) -> Self {
-        Self {
-            expanded: false,
-            index,
-            hovered: None,
+    fn choose(&mut self, index: usize) {
+        if index < self.items.len() {
+            self.chosen = Some(index);
         }
+        self.open = false;
     }
 
-    pub fn from_items<V: DropDownItem>(selected: &V, items: &[V]) -> Self {
-        Self::new(items.iter().position(|c| c == selected).unwrap_or(0))
+    fn width(&self) -> usize {
+        self.items
+            .iter()
+            .map(|item| TextShape::new(item).width())
+            .max()
+            .unwrap_or(0)
     }
 }
 
-pub struct DropDown<'a, V> {
-    values: &'a [V],
-    state: &'a mut DropDownState,
+pub struct DropDownView<T: 'static> {
+    state: Shared<Lock<DropDownState>>,
+    class: StyleKind<DropDownClass, DropDownStyle>,
+    // the items are copied out, so this doesn't need to own a `T`
+    _marker: PhantomData<fn() -> T>,
 }
 
-impl<'v, V> Adhoc<'v> for DropDown<'v, V>
-where
-    V: DropDownItem,
-{
-    type Output = Response<DropDownResponse>;
+impl<T> std::fmt::Debug for DropDownView<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DropDownView")
+            .field("state", &self.state)
+            .field("class", &self.class)
+            .finish()
+    }
+}
 
-    fn show(self, ui: &Ui) -> Self::Output {
-        let mut selected = None;
+impl<T: AsRef<str> + 'static> View for DropDownView<T> {
+    type Args<'v> = DropDown<'v, T>;
+    type Response = DropDownResponse;
 
-        let bg = Palette::current().surface;
-        let resp = ui.background(bg, |ui| {
-            ui.margin((1, 0), |ui| {
-                let resp = ui.mouse_area(|ui| {
-                    let resp = ui.key_area(|ui| {
-                        ui.vertical(|ui| {
-                            Self::draw_drop_list(ui, &mut selected, self.values, self.state)
-                        })
-                    });
-                    ui.set_focus(resp.id());
-                    let resp = resp.flatten_left();
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            state: Shared::default(),
+            class: args.class,
+            _marker: PhantomData,
+        }
+    }
 
-                    if resp.key_pressed(Key::Up) {
-                        self.state.index = self
-                            .state
-                            .index
-                            .checked_sub(1)
-                            .unwrap_or(self.values.len().saturating_sub(1));
-                        selected = Some(self.state.index);
-                    }
-                    if resp.key_pressed(Key::Down) {
-                        self.state.index = (self.state.index + 1) % self.values.len();
-                        selected = Some(self.state.index);
-                    }
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.class = args.class;
 
-                    if resp.key_pressed(Key::Right) {
-                        self.state.expanded = true;
-                    }
+        let mut state = self.state.borrow_mut();
+        let same = state.items.len() == args.items.len()
+            && (state.items.iter())
+                .zip(args.items)
+                .all(|(left, right)| &**left == right.as_ref());
+        if !same {
+            state.items = args
+                .items
+                .iter()
+                .map(|item| Str::from(item.as_ref()))
+                .collect();
+        }
+
+        let mut changed = false;
+        if let Some(chosen) = state.chosen.take() {
+            changed = *args.selected != chosen;
+            *args.selected = chosen;
+        }
+        state.selected = *args.selected;
+
+        let open = state.open && !state.items.is_empty();
+        state.open = open;
+        drop(state);
+
+        if open {
+            ui.float(|ui| {
+                ui.show(DropDownList {
+                    state: Shared::clone(&self.state),
+                    class: self.class,
+                })
+            });
+        }
 
-                    if resp.key_pressed(Key::Escape)
-                        || resp.key_pressed(Key::Left)
-                        || resp.key_pressed(Key::Enter)
-                    {
-                        self.state.expanded = false;
+        DropDownResponse {
+            changed,
+            selected: *args.selected,
+        }
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        Interest::MOUSE | Interest::FOCUS | Interest::FOCUS_INPUT
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        let mut state = self.state.borrow_mut();
+        match event {
+            ViewEvent::MouseHeld { inside: true, .. } => {}
+            ViewEvent::MouseClicked { inside: true, .. } => {
+                if state.open {
+                    state.open = false
+                } else {
+                    state.open()
+                }
+                ctx.input.set_focus(Some(ctx.current));
+            }
+
+            // clicking anywhere else closes the popup without changing anything
+            ViewEvent::MouseClicked { inside: false, .. } | ViewEvent::FocusLost => {
+                state.open = false;
+                return Handled::Bubble;
+            }
+
+            ViewEvent::KeyInput { key, .. } if state.open => {
+                let len = state.items.len().max(1);
+                match key {
+                    Key::Up => state.highlight = state.highlight.checked_sub(1).unwrap_or(len - 1),
+                    Key::Down => state.highlight = (state.highlight + 1) % len,
+                    Key::Enter | Key::Char(' ') => {
+                        let highlight = state.highlight;
+                        state.choose(highlight)
                     }
-                });
-                if resp.flatten_left().clicked() {
-                    self.state.expanded = !self.state.expanded
+                    Key::Escape => state.open = false,
+                    _ => return Handled::Bubble,
                 }
-            })
-        });
+            }
+
+            ViewEvent::KeyInput {
+                key: Key::Enter | Key::Char(' ') | Key::Down,
+                ..
+            } => state.open(),
+
+            _ => return Handled::Bubble,
+        }
 
-        resp.map(|_| DropDownResponse { selected })
+        Handled::Sink
     }
-}
 
-impl<'a, V: DropDownItem> DropDown<'a, V> {
-    fn draw_drop_list(
-        ui: &Ui,
-        selected: &mut Option<usize>,
-        values: &[V],
-        state: &mut DropDownState,
-    ) {
-        if !state.expanded {
-            ui.horizontal(|ui| {
-                ui.label('▶');
-                ui.label(&*values[state.index].short())
-            });
-            return;
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let state = self.state.borrow();
+        // a margin on either side, and a space between the text and the indicator
+        let size = space.fit(Size::new(state.width() as f32 + 4.0, 1.0));
+
+        // the popup hangs below us, without affecting our size
+        let node = layout.nodes.get_current();
+        for &child in &node.children {
+            let space = Space::new(Size::new(size.width, 0.0), Size::INFINITY);
+            layout.compute(child, space);
+            layout.set_position(child, pos2(0, size.height as i32));
         }
 
-        let (primary, secondary) = {
-            let p = Palette::current();
-            (p.primary, p.secondary)
+        size
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let state = self.state.borrow();
+        let style = match self.class {
+            StyleKind::Deferred(class) => (class)(render.palette, state.open),
+            StyleKind::Direct(style) => style,
         };
 
-        for (i, item) in values.iter().enumerate() {
-            let resp = ui.mouse_area(|ui| {
-                ui.horizontal(|ui| {
-                    ui.label(item.short());
-
-                    if Some(i) == state.hovered {
-                        ui.background(primary, |ui| ui.label(item.long()));
-                    } else if i == state.index {
-                        ui.background(secondary, |ui| ui.label(item.long()));
-                    } else {
-                        ui.label(item.long());
-                    };
-                });
+        let rect = render.local_rect();
+        render.fill_bg(style.background);
+
+        if let Some(item) = state.items.get(state.selected) {
+            render.crop(rect.shrink2(vec2(1, 0)), |render| {
+                render.text(TextShape::new(item).fg(style.text_color));
             });
+        }
 
-            let resp = resp.flatten_left();
-            if resp.hovered() {
-                state.hovered = Some(i)
-            }
+        render.set(
+            pos2(rect.right() - 2, 0),
+            Pixel::new(style.indicator).fg(style.text_color),
+        );
+
+        drop(state);
+        self.default_draw(render);
+    }
+}
+
+#[derive(Debug)]
+struct DropDownList {
+    state: Shared<Lock<DropDownState>>,
+    class: StyleKind<DropDownClass, DropDownStyle>,
+}
+
+impl<'v> Builder<'v> for DropDownList {
+    type View = Self;
+}
+
+impl DropDownList {
+    fn index_at(&self, ctx: &EventCtx, y: i32) -> usize {
+        (y - ctx.rect().top()).max(0) as usize
+    }
+}
+
+impl View for DropDownList {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        args
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        *self = args;
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
 
-            if resp.clicked() {
-                state.index = i;
-                *selected = Some(i);
-                state.hovered = None
+    fn interests(&self) -> Interest {
+        Interest::MOUSE_INSIDE | Interest::MOUSE_MOVE
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        match event {
+            ViewEvent::MouseMove { pos, .. } if ctx.rect().contains(pos) => {
+                let index = self.index_at(&ctx, pos.y);
+                self.state.borrow_mut().highlight = index;
+            }
+            ViewEvent::MouseHeld { inside: true, .. } => {}
+            ViewEvent::MouseClicked {
+                pos, inside: true, ..
+            } => {
+                let index = self.index_at(&ctx, pos.y);
+                self.state.borrow_mut().choose(index);
             }
+            _ => return Handled::Bubble,
+        }
+        Handled::Sink
+    }
+
+    fn layout(&mut self, _: Layout, space: Space) -> Size {
+        let state = self.state.borrow();
+        let size = Size::new(state.width() as f32 + 2.0, state.items.len() as f32);
+        space.fit(size)
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let state = self.state.borrow();
+        let style = match self.class {
+            StyleKind::Deferred(class) => (class)(render.palette, true),
+            StyleKind::Direct(style) => style,
+        };
+
+        let width = render.local_rect().width();
+        for (i, item) in state.items.iter().enumerate() {
+            let (fg, bg) = if i == state.highlight {
+                (style.highlight_text, style.highlight_background)
+            } else if i == state.selected {
+                (style.text_color, style.selected_background)
+            } else {
+                (style.text_color, style.background)
+            };
+
+            let row = Rect::from_min_size(pos2(0, i as i32), vec2(width, 1));
+            render.crop(row, |render| {
+                render.fill_bg(bg);
+                render.shrink(vec2(1, 0), |render| {
+                    render.text(TextShape::new(item).fg(fg));
+                });
+            });
         }
     }
 }
 
-pub fn drop_down<'a, V: DropDownItem>(
-    values: &'a [V],
-    state: &'a mut DropDownState,
-) -> DropDown<'a, V> {
-    DropDown { values, state }
+/// Create a [`DropDown`] that shows `items[selected]`, and updates `selected` when an item is chosen
+///
+/// ```rust
+/// # use too::{view::{State, Ui}, math::{pos2, vec2, Rect}, backend::{Event, Key, Modifiers, MouseButton}, views::drop_down};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 10));
+/// let items = ["apple", "banana", "cherry"];
+/// let mut selected = 0;
+///
+/// let click = |state: &mut State, pos| {
+///     state.event(&Event::MouseMove { pos });
+///     for down in [true, false] {
+///         let button = MouseButton::Primary;
+///         state.event(&Event::MouseButtonChanged { pos, button, down, modifiers: Modifiers::NONE });
+///     }
+/// };
+/// let key = |state: &mut State, key| state.event(&Event::KeyPressed { key, modifiers: Modifiers::NONE });
+///
+/// state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+///
+/// // open the popup, and click on the last item
+/// click(&mut state, pos2(1, 0));
+/// state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// click(&mut state, pos2(1, 3));
+///
+/// let resp = state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// assert!(resp.changed());
+/// assert_eq!(selected, 2);
+///
+/// // the arrow keys move the highlight while its open
+/// click(&mut state, pos2(1, 0));
+/// state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// key(&mut state, Key::Down);
+/// key(&mut state, Key::Enter);
+/// state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// assert_eq!(selected, 0);
+///
+/// // clicking outside closes it without changing the value
+/// click(&mut state, pos2(1, 0));
+/// state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// click(&mut state, pos2(15, 8));
+/// let resp = state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// assert!(!resp.changed());
+/// assert_eq!(selected, 0);
+///
+/// // and the popup is gone, so this doesn't pick anything
+/// click(&mut state, pos2(1, 2));
+/// state.build(rect, |ui| ui.drop_down(&mut selected, &items));
+/// assert_eq!(selected, 0);
+/// ```
+pub fn drop_down<'a, T: AsRef<str>>(selected: &'a mut usize, items: &'a [T]) -> DropDown<'a, T> {
+    DropDown {
+        selected,
+        items,
+        class: StyleKind::deferred(DropDownStyle::default),
+    }
 }
@@ -0,0 +1,111 @@
+use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+
+use crate::{
+    math::{Size, Space},
+    view::{Builder, EventCtx, Handled, Layout, Render, Ui, View, ViewEvent},
+};
+
+use super::forward_view;
+
+/// A builder that can disable a view, and all of its children
+///
+/// This is created by [`ViewExt::enabled`](crate::view::ViewExt::enabled)
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Enabled<B> {
+    enabled: bool,
+    builder: B,
+}
+
+impl<B> Enabled<B> {
+    pub(crate) const fn new(enabled: bool, builder: B) -> Self {
+        Self { enabled, builder }
+    }
+}
+
+impl<'v, B: Builder<'v>> Builder<'v> for Enabled<B> {
+    type View = EnabledView<B::View>;
+}
+
+/// A view that, when disabled, blocks input to its inner view and its children
+///
+/// Everything else is passed through to the inner view. When disabled, it [disables input](Layout::disable_input)
+/// when its laid out, and draws the subtree with the palette's `outline` color.
+///
+/// The views keep their state while disabled, so enabling them again is all it takes to use them again.
+///
+/// A disabled subtree can't hold the focus, even when its [layout is reused](crate::view::State::layout_stats)
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use too::{math::{pos2, vec2, Rect}, view::{State, Ui, ViewExt as _}, views::label};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+///
+/// let focus = Cell::new(false);
+/// let app = |ui: &Ui| {
+///     let id = ui.show(label("hello").enabled(false)).id();
+///     if focus.take() {
+///         ui.set_focus(id);
+///     }
+/// };
+/// state.build(rect, app);
+///
+/// // this is focused after the label was laid out, and its layout is reused
+/// focus.set(true);
+/// state.build(rect, app);
+/// state.build(rect, app);
+/// assert_eq!(state.layout_stats().hits, 1);
+/// assert_eq!(state.focus(), None);
+/// ```
+#[derive(Debug)]
+pub struct EnabledView<V> {
+    enabled: bool,
+    view: V,
+}
+
+impl<V: View> View for EnabledView<V> {
+    type Args<'v> = Enabled<V::Args<'v>>;
+    type Response = V::Response;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            enabled: args.enabled,
+            view: V::create(args.builder),
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.enabled = args.enabled;
+        self.view.update(args.builder, ui)
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        if !self.enabled {
+            return Handled::Bubble;
+        }
+        self.view.event(event, ctx)
+    }
+
+    fn layout_hash(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        (self.view.layout_hash()?, self.enabled).hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        if !self.enabled {
+            layout.disable_input();
+        }
+        self.view.layout(layout, space)
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        self.view.draw(render.reborrow());
+        if !self.enabled {
+            let (rect, outline) = (render.rect(), render.palette.outline);
+            render.patch_fg(rect, outline);
+        }
+    }
+
+    forward_view!(view => flex, interactive, interests, primary_axis, size);
+}
@@ -0,0 +1,84 @@
+/// Passes the listed [`View`](crate::view::View) methods through to a field of a wrapping view
+///
+/// Wrappers (e.g. [`EnabledView`](super::EnabledView)) only change a few methods, this writes the rest:
+///
+/// ```rust,ignore
+/// impl<V: View> View for Wrapper<V> {
+///     // create, update and the methods this changes
+///     forward_view!(view => flex, interactive, interests, primary_axis, event, size, layout_hash, draw);
+/// }
+/// ```
+macro_rules! forward_view {
+    ($field:tt => $($method:ident),* $(,)?) => {
+        $(forward_view!(@ $method $field);)*
+    };
+
+    (@ flex $field:tt) => {
+        fn flex(&self) -> $crate::layout::Flex {
+            self.$field.flex()
+        }
+    };
+
+    (@ interactive $field:tt) => {
+        fn interactive(&self) -> bool {
+            self.$field.interactive()
+        }
+    };
+
+    (@ interests $field:tt) => {
+        fn interests(&self) -> $crate::view::Interest {
+            self.$field.interests()
+        }
+    };
+
+    (@ primary_axis $field:tt) => {
+        fn primary_axis(&self) -> $crate::layout::Axis {
+            self.$field.primary_axis()
+        }
+    };
+
+    (@ event $field:tt) => {
+        fn event(
+            &mut self,
+            event: $crate::view::ViewEvent,
+            ctx: $crate::view::EventCtx,
+        ) -> $crate::view::Handled {
+            self.$field.event(event, ctx)
+        }
+    };
+
+    (@ size $field:tt) => {
+        fn size(
+            &self,
+            intrinsic: $crate::view::IntrinsicSize,
+            axis: $crate::layout::Axis,
+            extent: f32,
+        ) -> f32 {
+            self.$field.size(intrinsic, axis, extent)
+        }
+    };
+
+    (@ layout_hash $field:tt) => {
+        fn layout_hash(&self) -> Option<u64> {
+            self.$field.layout_hash()
+        }
+    };
+
+    (@ layout $field:tt) => {
+        fn layout(
+            &mut self,
+            layout: $crate::view::Layout,
+            space: $crate::math::Space,
+        ) -> $crate::math::Size {
+            self.$field.layout(layout, space)
+        }
+    };
+
+    (@ draw $field:tt) => {
+        fn draw(&mut self, render: $crate::view::Render) {
+            self.$field.draw(render)
+        }
+    };
+}
+
+pub(super) use forward_view;
@@ -3,10 +3,105 @@ use crate::{
     view::{Builder, EventCtx, Handled, Interest, Ui, View, ViewEvent},
 };
 
+/// A view that captures key input while it is focused
+///
+/// By default it captures every key. With [`KeyArea::claim`] it only captures those keys, and the rest bubble up,
+/// so they can still move the focus with `Tab`, or be seen with [`Ui::key_pressed`]
+///
+/// ```rust
+/// # use too::{backend::{Event, Key, Keybind, Modifiers}, view::State, math::{pos2, vec2, Rect}, views::key_area};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 2));
+///
+/// let app = |ui: &too::view::Ui| {
+///     let claims = [Keybind::from(Key::Up), Keybind::from(Key::Down), Keybind::from('s').ctrl()];
+///     let game = ui.show_children(key_area().claim(&claims), |_| {});
+///     let other = ui.show_children(key_area(), |_| {});
+///     (game.id(), other.id(), game.into_inner().0.key_binding())
+/// };
+///
+/// let (game, other, _) = state.build(rect, app);
+/// state.build(rect, |ui| ui.set_focus(game));
+///
+/// let press = |state: &mut State, key: Key, modifiers: Modifiers| {
+///     state.event(&Event::KeyPressed { key, modifiers });
+///     state.build(rect, app).2
+/// };
+///
+/// assert_eq!(press(&mut state, Key::Up, Modifiers::NONE), Some(Keybind::from(Key::Up)));
+/// assert_eq!(press(&mut state, Key::Char('x'), Modifiers::NONE), None);
+///
+/// // modifiers are part of the claim
+/// assert_eq!(press(&mut state, Key::Char('s'), Modifiers::CTRL), Some(Keybind::from('s').ctrl()));
+/// assert_eq!(press(&mut state, Key::Char('s'), Modifiers::NONE), None);
+///
+/// // tab wasn't claimed, so it moves the focus
+/// press(&mut state, Key::Tab, Modifiers::NONE);
+/// assert_eq!(state.focus(), Some(other));
+///
+/// // but claiming nothing captures everything
+/// press(&mut state, Key::Tab, Modifiers::NONE);
+/// assert_eq!(state.focus(), Some(other));
+/// ```
 #[derive(Debug, Default)]
 pub struct KeyArea {
     key: Option<Key>,
     modifiers: Option<Modifiers>,
+    claims: Vec<Keybind>,
+    global: bool,
+}
+
+impl KeyArea {
+    /// Only capture these keys, any other keys bubble up to the parent views
+    ///
+    /// The modifiers have to match exactly, so `Key::Up` doesn't claim `Ctrl+Up`. Claiming nothing captures every key
+    pub fn claim<K: Into<Keybind> + Copy>(mut self, keys: &[K]) -> Self {
+        self.claims.extend(keys.iter().copied().map(Into::into));
+        self
+    }
+}
+
+impl KeyArea {
+    /// Capture keys even when this isn't focused, see [`Interest::KEY_GLOBAL`]
+    ///
+    /// Global key areas get the keys before the focused view, in layout order. Use this with [`KeyArea::claim`] so other keys still reach the focused view
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Key, Keybind, Modifiers}, view::State, math::{pos2, vec2, Rect}, views::{key_area, text_input}};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 3));
+    /// let palette = [Keybind::from('p').ctrl()];
+    ///
+    /// let app = |ui: &too::view::Ui| {
+    ///     let first = ui.show_children(key_area().global(true).claim(&palette), |_| {});
+    ///     let second = ui.show_children(key_area().global(true).claim(&palette), |_| {});
+    ///     let input = ui.show(text_input());
+    ///     ui.set_focus(input.id());
+    ///     let data = input.data().to_string();
+    ///     (first.into_inner().0.key_binding(), second.into_inner().0.key_binding(), data)
+    /// };
+    ///
+    /// let press = |state: &mut State, key: Key, modifiers: Modifiers| {
+    ///     state.event(&Event::KeyPressed { key, modifiers });
+    ///     state.build(rect, app)
+    /// };
+    /// state.build(rect, app);
+    ///
+    /// // the first one in layout order sinks it, so the others don't see it
+    /// let (first, second, data) = press(&mut state, Key::Char('p'), Modifiers::CTRL);
+    /// assert_eq!(first, Some(Keybind::from('p').ctrl()));
+    /// assert_eq!(second, None);
+    /// assert_eq!(data, "");
+    ///
+    /// // unclaimed keys still go to the focused view
+    /// let (first, _, data) = press(&mut state, Key::Char('p'), Modifiers::NONE);
+    /// assert_eq!(first, None);
+    /// assert_eq!(data, "p");
+    /// ```
+    pub const fn global(mut self, global: bool) -> Self {
+        self.global = global;
+        self
+    }
 }
 
 impl<'v> Builder<'v> for KeyArea {
@@ -21,7 +116,9 @@ impl View for KeyArea {
         args
     }
 
-    fn update(&mut self, _args: Self::Args<'_>, _ui: &Ui) -> Self::Response {
+    fn update(&mut self, args: Self::Args<'_>, _ui: &Ui) -> Self::Response {
+        self.claims = args.claims;
+        self.global = args.global;
         Self::Response {
             key: self.key.take(),
             modifiers: self.modifiers.take(),
@@ -33,6 +130,9 @@ impl View for KeyArea {
     }
 
     fn interests(&self) -> Interest {
+        if self.global {
+            return Interest::FOCUS_INPUT | Interest::KEY_GLOBAL;
+        }
         Interest::FOCUS_INPUT
     }
 
@@ -41,6 +141,10 @@ impl View for KeyArea {
             return Handled::Bubble;
         };
 
+        if !self.claims.is_empty() && !self.claims.contains(&Keybind::new(key, modifiers)) {
+            return Handled::Bubble;
+        }
+
         self.key = Some(key);
         self.modifiers = Some(modifiers);
 
@@ -55,6 +159,9 @@ pub struct KeyAreaResponse {
 }
 
 impl KeyAreaResponse {
+    /// The key that was captured this frame, if there was one
+    ///
+    /// When keys are [claimed](KeyArea::claim), this is always one of them
     pub fn key_binding(&self) -> Option<Keybind> {
         self.key
             .and_then(|k| self.modifiers.map(|m| (k, m)))
@@ -82,6 +189,14 @@ impl KeyAreaResponse {
         self.modifiers.filter(|m| m.is_alt()).is_some()
     }
 
+    pub fn is_super(&self) -> bool {
+        self.modifiers.filter(|m| m.is_super()).is_some()
+    }
+
+    pub fn is_meta(&self) -> bool {
+        self.modifiers.filter(|m| m.is_meta()).is_some()
+    }
+
     pub fn is_shift_only(&self) -> bool {
         self.modifiers.filter(|m| m.is_shift_only()).is_some()
     }
@@ -93,11 +208,21 @@ impl KeyAreaResponse {
     pub fn is_alt_only(&self) -> bool {
         self.modifiers.filter(|m| m.is_alt_only()).is_some()
     }
+
+    pub fn is_super_only(&self) -> bool {
+        self.modifiers.filter(|m| m.is_super_only()).is_some()
+    }
+
+    pub fn is_meta_only(&self) -> bool {
+        self.modifiers.filter(|m| m.is_meta_only()).is_some()
+    }
 }
 
 pub const fn key_area() -> KeyArea {
     KeyArea {
         key: None,
         modifiers: None,
+        claims: Vec::new(),
+        global: false,
     }
 }
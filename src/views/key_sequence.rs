@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use crate::{
+    backend::{KeySequence, Keybind},
+    view::{Builder, EventCtx, Handled, Interest, Ui, View, ViewEvent},
+};
+
+#[derive(Debug)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct KeySequenceArea<'a> {
+    sequences: &'a [KeySequence],
+    timeout: Duration,
+}
+
+impl<'a> KeySequenceArea<'a> {
+    /// How long to wait for the next key of a sequence, before starting over
+    ///
+    /// By default this is `1s`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl<'v> Builder<'v> for KeySequenceArea<'v> {
+    type View = KeySequenceView;
+}
+
+/// A view that waits for any of its key sequences to be pressed, while its focused
+///
+/// The keys pressed so far are kept until they either complete a sequence, can't be the start of one, or the timeout passes.
+/// A key that can't continue the current sequence starts over, so it can still begin a new one.
+///
+/// While a sequence is started, its [animating](crate::view::State::is_animating), so the timeout passes even without any events
+///
+/// ```rust
+/// # use too::{backend::{Event, Key, KeySequence, Modifiers}, math::{pos2, vec2, Rect}, view::{State, Ui}, views::key_sequence};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+/// let sequences = [KeySequence::parse("g g").unwrap()];
+/// let app = |ui: &Ui| {
+///     let resp = ui.show(key_sequence(&sequences));
+///     ui.set_focus(resp.id());
+/// };
+///
+/// state.build(rect, app);
+/// state.build(rect, app);
+/// assert!(!state.is_animating());
+///
+/// state.event(&Event::KeyPressed { key: Key::Char('g'), modifiers: Modifiers::NONE });
+/// state.update(0.1);
+/// state.build(rect, app);
+/// assert!(state.is_animating());
+///
+/// // the timeout passed, so it starts over
+/// state.update(1.0);
+/// state.build(rect, app);
+/// assert!(!state.is_animating());
+/// ```
+#[derive(Debug)]
+pub struct KeySequenceView {
+    sequences: Vec<KeySequence>,
+    timeout: f32,
+    pending: Vec<Keybind>,
+    idle: f32,
+    matched: Option<usize>,
+}
+
+impl KeySequenceView {
+    fn is_prefix(&self, keys: &[Keybind]) -> bool {
+        self.sequences.iter().any(|seq| seq.starts_with(keys))
+    }
+
+    fn press(&mut self, key: Keybind) -> Handled {
+        self.idle = 0.0;
+        self.pending.push(key);
+        if !self.is_prefix(&self.pending) {
+            self.pending.clear();
+            if !self.is_prefix(&[key]) {
+                return Handled::Bubble;
+            }
+            self.pending.push(key);
+        }
+
+        if let Some(index) = self
+            .sequences
+            .iter()
+            .position(|seq| seq.keys() == self.pending)
+        {
+            self.matched = Some(index);
+            self.pending.clear();
+        }
+        Handled::Sink
+    }
+}
+
+impl View for KeySequenceView {
+    type Args<'v> = KeySequenceArea<'v>;
+    type Response = KeySequenceResponse;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            sequences: args.sequences.to_vec(),
+            timeout: args.timeout.as_secs_f32(),
+            pending: Vec::new(),
+            idle: 0.0,
+            matched: None,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        // the sequences are only copied when they change
+        if args.sequences != self.sequences {
+            self.sequences = args.sequences.to_vec();
+            self.pending.clear();
+        }
+        self.timeout = args.timeout.as_secs_f32();
+
+        if !self.pending.is_empty() {
+            self.idle += ui.dt();
+            if self.idle >= self.timeout {
+                self.pending.clear();
+            }
+        }
+
+        let matched = self.matched.take();
+        KeySequenceResponse {
+            sequence: matched.map(|index| self.sequences[index].clone()),
+            matched,
+        }
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        // the timeout only needs to tick while a sequence is started
+        if self.pending.is_empty() {
+            Interest::FOCUS_INPUT
+        } else {
+            Interest::FOCUS_INPUT | Interest::ANIMATE
+        }
+    }
+
+    fn event(&mut self, event: ViewEvent, _ctx: EventCtx) -> Handled {
+        let ViewEvent::KeyInput { key, modifiers } = event else {
+            return Handled::Bubble;
+        };
+        self.press(Keybind::new(key, modifiers))
+    }
+}
+
+/// The response from a [`KeySequenceArea`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeySequenceResponse {
+    matched: Option<usize>,
+    sequence: Option<KeySequence>,
+}
+
+impl KeySequenceResponse {
+    /// The index of the sequence that was completed, in the order they were given
+    pub const fn matched(&self) -> Option<usize> {
+        self.matched
+    }
+
+    /// The sequence that was completed
+    pub const fn sequence(&self) -> Option<&KeySequence> {
+        self.sequence.as_ref()
+    }
+}
+
+/// Wait for key sequences, like `"g g"` or `"ctrl-w ctrl-w"`, to be pressed while this view is focused
+///
+/// The sequences are borrowed, so they can be [parsed](KeySequence::parse) once and reused every frame,
+/// rather than parsing strings on every frame.
+///
+/// The keys pressed so far are kept by this view, not by the [`InputState`](crate::view::InputState).
+/// This way two views waiting for different sequences don't reset each other, the keys only go to the focused one,
+/// and a partial sequence goes away with its view.
+///
+/// ```rust
+/// # use too::{backend::KeySequence, views::key_sequence};
+/// let sequences: Vec<KeySequence> = ["g g", "d d"].iter().map(|seq| seq.parse().unwrap()).collect();
+/// let area = key_sequence(&sequences);
+/// ```
+///
+/// See [`Ui::key_sequence`](crate::view::Ui::key_sequence) for a shorthand
+pub const fn key_sequence(sequences: &[KeySequence]) -> KeySequenceArea<'_> {
+    KeySequenceArea {
+        sequences,
+        timeout: Duration::from_secs(1),
+    }
+}
@@ -1,10 +1,16 @@
+use std::{
+    borrow::Cow,
+    hash::{DefaultHasher, Hash as _, Hasher as _},
+};
+
 use compact_str::CompactString;
+use unicode_segmentation::UnicodeSegmentation as _;
 use unicode_width::UnicodeWidthStr as _;
 
 use crate::{
     layout::Align,
-    math::{Size, Space},
-    renderer::{Attribute, Rgba, TextShape},
+    math::{pos2, vec2, Rect, Size, Space},
+    renderer::{Attribute, ControlChars, Rgba, TextShape},
     view::{Builder, Layout, Palette, Render, StyleKind, View},
     Str,
 };
@@ -42,6 +48,112 @@ impl LabelStyle {
 
 pub type LabelClass = fn(&Palette) -> LabelStyle;
 
+/// How a [`Label`] fits text that is wider than it
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WrapMode {
+    /// Keep the text on a single line, anything that doesn't fit is clipped
+    #[default]
+    None,
+    /// Break lines between words, words wider than the label are broken between characters
+    Word,
+    /// Break lines between characters
+    Char,
+    /// Keep the text on a single line, ending it with `…` if it doesn't fit
+    Truncate,
+}
+
+impl WrapMode {
+    pub(super) const ELLIPSIS: &'static str = "…";
+
+    // a zero width produces no lines, rather than an endless amount of them
+    fn lines(self, text: &str, width: usize) -> Vec<Cow<'_, str>> {
+        match self {
+            Self::None => vec![Cow::Borrowed(text)],
+            _ if width == 0 => vec![],
+            _ if text.width() <= width => vec![Cow::Borrowed(text)],
+            Self::Truncate => {
+                let available = width.saturating_sub(Self::ELLIPSIS.width());
+                let mut total = 0;
+                let end = text
+                    .grapheme_indices(true)
+                    .find_map(|(i, grapheme)| {
+                        total += grapheme.width();
+                        (total > available).then_some(i)
+                    })
+                    .unwrap_or(text.len());
+                vec![Cow::Owned(format!("{}{}", &text[..end], Self::ELLIPSIS))]
+            }
+            Self::Char => Self::char_lines(text, width).map(Cow::Borrowed).collect(),
+            Self::Word => Self::word_lines(text, width),
+        }
+    }
+
+    // split off as many graphemes as fit in the width, but always at least one so this always moves forward
+    fn split_at_width(text: &str, width: usize) -> (&str, &str) {
+        let mut total = 0;
+        for (i, grapheme) in text.grapheme_indices(true) {
+            total += grapheme.width();
+            if total > width && i > 0 {
+                return text.split_at(i);
+            }
+        }
+        (text, "")
+    }
+
+    fn char_lines(mut text: &str, width: usize) -> impl Iterator<Item = &str> {
+        std::iter::from_fn(move || {
+            if text.is_empty() {
+                return None;
+            }
+            let (line, rest) = Self::split_at_width(text, width);
+            text = rest;
+            Some(line)
+        })
+    }
+
+    fn word_lines(text: &str, width: usize) -> Vec<Cow<'_, str>> {
+        let mut lines = vec![];
+        let mut line = String::new();
+
+        for word in text.split_word_bounds() {
+            let is_space = word.chars().all(char::is_whitespace);
+            // spaces at the start of a wrapped line are dropped
+            if is_space && line.is_empty() && !lines.is_empty() {
+                continue;
+            }
+
+            if line.width() + word.width() <= width {
+                line.push_str(word);
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(Cow::Owned(std::mem::take(&mut line).trim_end().to_string()));
+            }
+
+            if is_space {
+                continue;
+            }
+
+            // the word doesn't fit on a line of its own, so it is broken up
+            let mut parts = Self::char_lines(word, width).peekable();
+            while let Some(part) = parts.next() {
+                if parts.peek().is_none() {
+                    line.push_str(part);
+                } else {
+                    lines.push(Cow::Owned(part.to_string()));
+                }
+            }
+        }
+
+        let line = line.trim_end();
+        if !line.is_empty() {
+            lines.push(Cow::Owned(line.to_string()));
+        }
+        lines
+    }
+}
+
 pub fn label(label: impl Into<Str>) -> Label {
     Label::new(label)
 }
@@ -53,6 +165,10 @@ impl Label {
             class: StyleKind::Deferred(LabelStyle::default),
             main: Align::Min,
             attribute: None,
+            tab_width: TextShape::DEFAULT_TAB_WIDTH,
+            control_chars: ControlChars::Replace,
+            link: None,
+            wrap: WrapMode::None,
         }
     }
 
@@ -71,6 +187,84 @@ impl Label {
         self
     }
 
+    /// Expand tabs to the next multiple of this many columns
+    pub const fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// What to do with control characters in the label
+    pub const fn control_chars(mut self, control_chars: ControlChars) -> Self {
+        self.control_chars = control_chars;
+        self
+    }
+
+    /// How the label should fit text that is wider than it
+    ///
+    /// ```rust
+    /// # use too::views::{label, WrapMode};
+    /// let render = |mode| {
+    ///     too::view::test::render_to_string(|ui| {
+    ///         ui.exact_width(10, |ui| ui.show(label("the quick brown fox").wrap(mode)));
+    ///     })
+    /// };
+    ///
+    /// assert_eq!(render(WrapMode::None), "the quick");
+    /// assert_eq!(render(WrapMode::Word), "the quick\nbrown fox");
+    /// assert_eq!(render(WrapMode::Char), "the quick\nbrown fox");
+    /// assert_eq!(render(WrapMode::Truncate), "the quick…");
+    ///
+    /// // words that are too long for a line are broken up, without splitting a grapheme cluster
+    /// let out = too::view::test::render_to_string(|ui| {
+    ///     ui.exact_width(4, |ui| ui.show(label("ab e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}").wrap(WrapMode::Word)));
+    /// });
+    /// assert_eq!(out, "ab\ne\u{301}e\u{301}e\u{301}e\u{301}\ne\u{301}");
+    ///
+    /// // there is nothing to wrap into if the label has no width
+    /// let out = too::view::test::render_to_string(|ui| {
+    ///     ui.exact_width(0, |ui| ui.show(label("hello").wrap(WrapMode::Char)));
+    /// });
+    /// assert_eq!(out, "");
+    /// ```
+    pub const fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Make this label a hyperlink to this url
+    ///
+    /// Terminals that don't support hyperlinks just show the label
+    ///
+    /// ```rust
+    /// # use too::{math::{pos2, vec2, Rect}, renderer::{Surface, TermRenderer}, view::{CroppedSurface, State, Ui}, views::label};
+    /// let mut state = State::default();
+    /// let mut surface = Surface::new(vec2(20, 1));
+    /// state.build(Rect::from_min_size(pos2(0, 0), vec2(20, 1)), |ui| {
+    ///     ui.horizontal(|ui| {
+    ///         ui.show(label("docs").link("https://example.com"));
+    ///         ui.label("!");
+    ///     });
+    /// });
+    /// state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+    ///
+    /// let mut out = Vec::new();
+    /// surface.render(&mut TermRenderer::new(&mut out)).unwrap();
+    /// let out = String::from_utf8(out).unwrap();
+    ///
+    /// // the link is closed before its unlinked neighbor
+    /// let open = "\x1b]8;;https://example.com\x1b\\";
+    /// let close = "\x1b]8;;\x1b\\";
+    /// let start = out.find(open).unwrap() + open.len();
+    /// let end = start + out[start..].find(close).unwrap();
+    /// assert!(out[start..end].contains("docs"));
+    /// assert!(!out[start..end].contains('!'));
+    /// assert!(out[end..].contains('!'));
+    /// ```
+    pub fn link(mut self, url: impl Into<Str>) -> Self {
+        self.link = Some(url.into().into_inner());
+        self
+    }
+
     pub fn fg(self, fg: impl Into<Rgba>) -> Self {
         self.style(LabelStyle {
             foreground: fg.into(),
@@ -117,6 +311,26 @@ pub struct Label {
     class: StyleKind<LabelClass, LabelStyle>,
     main: Align,
     attribute: Option<Attribute>,
+    tab_width: usize,
+    control_chars: ControlChars,
+    link: Option<CompactString>,
+    wrap: WrapMode,
+}
+
+impl Label {
+    fn shape(&self) -> TextShape<'_> {
+        let shape = TextShape::new(&self.label)
+            .tab_width(self.tab_width)
+            .control_chars(self.control_chars);
+        self.with_link(shape)
+    }
+
+    fn with_link<'a>(&'a self, shape: TextShape<'a>) -> TextShape<'a> {
+        match &self.link {
+            Some(link) => shape.link(link),
+            None => shape,
+        }
+    }
 }
 
 impl<'v> Builder<'v> for Label {
@@ -131,8 +345,22 @@ impl View for Label {
         args
     }
 
+    fn layout_hash(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        (&self.label, self.tab_width, self.control_chars, self.wrap).hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
     fn layout(&mut self, _layout: Layout, space: Space) -> Size {
-        space.fit(Size::new(self.label.width() as f32, 1.0))
+        let shape = self.shape();
+        if self.wrap == WrapMode::None || space.max.width.is_infinite() {
+            return space.fit(Size::new(shape.width() as f32, 1.0));
+        }
+
+        let text = shape.sanitized();
+        let lines = self.wrap.lines(&text, space.max.width as usize);
+        let width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+        space.fit(Size::new(width as f32, lines.len() as f32))
     }
 
     fn draw(&mut self, mut render: Render) {
@@ -141,10 +369,27 @@ impl View for Label {
             StyleKind::Direct(style) => style,
         };
 
-        render.text(
-            TextShape::new(&self.label)
+        if self.wrap == WrapMode::None {
+            render.text(
+                self.shape()
+                    .fg(style.foreground)
+                    .maybe_attribute(self.attribute),
+            );
+            return;
+        }
+
+        let rect = render.local_rect();
+        let shape = self.shape();
+        let text = shape.sanitized();
+        for (y, line) in (0..).zip(self.wrap.lines(&text, rect.width() as usize)) {
+            let shape = TextShape::new(&line)
                 .fg(style.foreground)
-                .maybe_attribute(self.attribute),
-        );
+                .maybe_attribute(self.attribute);
+            let shape = self.with_link(shape);
+            render.crop(
+                Rect::from_min_size(pos2(0, y), vec2(rect.width(), 1)),
+                |render| _ = render.text(shape),
+            );
+        }
     }
 }
@@ -0,0 +1,89 @@
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{
+    layout::Axis,
+    math::{pos2, vec2, Rect, Size, Space},
+    renderer::TextShape,
+    view::{Builder, Layout, Render, Ui, View},
+    Str,
+};
+
+/// A label in front of its children
+///
+/// This is created by [`labeled`]
+#[derive(Debug)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Labeled {
+    label: Str,
+}
+
+impl<'v> Builder<'v> for Labeled {
+    type View = LabeledView;
+}
+
+/// A view that lays out a label, and then its children, in a row
+///
+/// The label is drawn by this view, and its children are placed after it
+#[derive(Debug)]
+pub struct LabeledView {
+    label: Str,
+}
+
+impl View for LabeledView {
+    type Args<'v> = Labeled;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self { label: args.label }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        self.label = args.label;
+    }
+
+    fn primary_axis(&self) -> Axis {
+        Axis::Horizontal
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let node = layout.nodes.get_current();
+
+        let label = self.label.width() as f32;
+        let mut size = Size::new(label, 1.0);
+        for &child in &node.children {
+            // a column between the label, and between each child
+            let x = size.width + 1.0;
+            let available = Space::new(
+                Size::ZERO,
+                Size::new((space.max.width - x).max(0.0), space.max.height),
+            );
+
+            let child_size = layout.compute(child, available);
+            layout.set_position(child, pos2(x as i32, 0));
+            size.width = x + child_size.width;
+            size.height = size.height.max(child_size.height);
+        }
+
+        space.fit(size)
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let width = self.label.width() as i32;
+        let fg = render.palette.foreground;
+        render.crop(Rect::from_min_size(pos2(0, 0), vec2(width, 1)), |render| {
+            render.text(TextShape::new(&self.label).fg(fg));
+        });
+        self.default_draw(render);
+    }
+}
+
+/// Put a label in front of some views
+///
+/// Its children are shown after the label, in a row.
+///
+/// See [`Ui::labeled`](crate::view::Ui::labeled) for a shorthand that forwards the children's response
+pub fn labeled(label: impl Into<Str>) -> Labeled {
+    Labeled {
+        label: label.into(),
+    }
+}
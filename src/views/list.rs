@@ -3,7 +3,7 @@ use core::f32;
 use crate::{
     backend::Key,
     layout::{Axis, CrossAlign, Justify},
-    math::{remap, vec2, Pos2, Rect, Size, Space, Vec2},
+    math::{remap_clamp, Pos2, Rect, Size, Space, Vec2},
     renderer::{Pixel, Rgba},
     view::{
         Builder, Elements, EventCtx, Handled, Interest, Layout, Palette, Render, StyleKind, Ui,
@@ -49,24 +49,51 @@ struct ListParams {
     total_gap: f32,
 }
 
+/// When a scrollable [`List`] should show its scrollbar
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScrollbarVisibility {
+    /// Always show the scrollbar, even when the children fit
+    Always,
+    /// Only show the scrollbar when the children don't fit
+    #[default]
+    Auto,
+    /// Never show the scrollbar, the list can still be scrolled
+    Never,
+}
+
 // TODO move the scrolling stuff on this so other types can also do scrolling
 #[derive(Default)]
 struct ScrollState {
     scrollable: bool,
+    visibility: ScrollbarVisibility,
+    // whether the last layout reserved space for the scrollbar
+    visible: bool,
     pos: usize,
     knob_held: bool,
 }
 
 pub type ScrollClass = fn(&Palette, Axis) -> ScrollStyle;
 
+/// The style of a scrollable [`List`]'s scrollbar
 #[derive(Copy, Clone)]
 pub struct ScrollStyle {
+    /// The character used for the knob (the thumb)
     pub knob: char,
+    /// The character used for the knob when its hovered or held
     pub knob_grab: Option<char>,
+    /// The character used for the track
+    ///
+    /// If this is `None` then a space is used
     pub track: Option<char>,
+    /// The color of the track
+    ///
+    /// If this is `None` then the palette's outline color is used
     pub track_color: Option<Rgba>,
+    /// The color of the knob
     pub knob_color: Rgba,
+    /// The color of the knob when its hovered or held
     pub knob_grab_color: Option<Rgba>,
+    /// The background color of the scrollbar
     pub background: Rgba,
 }
 
@@ -93,6 +120,45 @@ impl ScrollStyle {
     }
 }
 
+/// Lays out its children one after another along an axis
+///
+/// Children without any [`Flex`](crate::layout::Flex) are measured first. The space left over is then shared by the flexible children, in proportion to their factors:
+/// - [`Flex::Loose`](crate::layout::Flex::Loose) children can take up to their share
+/// - [`Flex::Tight`](crate::layout::Flex::Tight) children split whatever is left after that, taking exactly their share
+///
+/// Shares are whole cells, and the last flexible child gets any leftover from rounding, so tight children fill the list exactly.
+///
+/// ```rust
+/// # use too::{view::{State, Ui, ViewExt as _, ViewId}, layout::Flex, math::{pos2, vec2, Rect}, views::{list, Fill, Flexible}};
+/// fn widths(width: i32, factors: &[Flex]) -> Vec<i32> {
+///     let show = |ui: &Ui| {
+///         list().horizontal().show_children(ui, |ui| {
+///             ui.label("ab");
+///             factors
+///                 .iter()
+///                 .map(|&flex| {
+///                     ui.show_children(Flexible::new(flex), |ui| _ = ui.show(Fill::fill_with("#F00"))).id()
+///                 })
+///                 .collect::<Vec<ViewId>>()
+///         }).into_inner().1
+///     };
+///
+///     let mut state = State::default();
+///     let rect = Rect::from_min_size(pos2(0, 0), vec2(width, 1));
+///     state.build(rect, |ui| _ = show(ui));
+///     state.build(rect, |ui| {
+///         show(ui).into_iter().map(|id| ui.rect_of(id).unwrap().width()).collect()
+///     })
+/// }
+///
+/// use Flex::{Loose, Tight};
+/// // 10 cells are left after the label
+/// assert_eq!(widths(12, &[Tight(1.0), Tight(2.0), Tight(1.0)]), [2, 5, 3]);
+/// assert_eq!(widths(12, &[Tight(1.0), Tight(1.0), Tight(1.0)]), [3, 3, 4]);
+/// assert_eq!(widths(14, &[Tight(1.0), Tight(3.0)]), [3, 9]);
+/// // loose children take their share first, and tight children split the rest
+/// assert_eq!(widths(12, &[Loose(1.0), Tight(1.0)]), [5, 5]);
+/// ```
 #[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
 pub struct List {
     axis: Axis,
@@ -138,6 +204,121 @@ impl List {
         self
     }
 
+    /// When the scrollbar should be shown, if this list is scrollable
+    ///
+    /// The scrollbar takes up a row (or column) of the list, which isn't given to the children
+    ///
+    /// ```rust
+    /// # use too::{view::{CroppedSurface, State, Ui, ViewExt as _}, math::{pos2, vec2, Rect}, layout::CrossAlign, renderer::{Cell, Pixel, Rgba, Surface}, views::{list, ScrollbarVisibility, ScrollStyle}};
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 3));
+    ///
+    /// let style = ScrollStyle {
+    ///     knob: '#',
+    ///     knob_grab: None,
+    ///     track: Some('.'),
+    ///     track_color: Some(Rgba::hex("#F00")),
+    ///     knob_color: Rgba::hex("#0F0"),
+    ///     knob_grab_color: None,
+    ///     background: Rgba::hex("#000"),
+    /// };
+    ///
+    /// let show = |ui: &Ui, visibility, lines: usize| {
+    ///     let list = list().vertical().cross_align(CrossAlign::Fill).scrollable(true);
+    ///     list.scrollbar(visibility).style(style).show_children(ui, |ui| {
+    ///         let first = ui.label("line 0").id();
+    ///         (1..lines).for_each(|i| _ = ui.label(format!("line {i}")));
+    ///         first
+    ///     }).into_inner().1
+    /// };
+    ///
+    /// // how wide the children get
+    /// let width = |visibility, lines| {
+    ///     let mut state = State::default();
+    ///     state.build(rect, |ui| _ = show(ui, visibility, lines));
+    ///     state.build(rect, |ui| ui.rect_of(show(ui, visibility, lines)).unwrap().width())
+    /// };
+    ///
+    /// // the children get the column the scrollbar is in, unless it is shown
+    /// assert_eq!(width(ScrollbarVisibility::Auto, 2), 10);
+    /// assert_eq!(width(ScrollbarVisibility::Auto, 10), 9);
+    /// assert_eq!(width(ScrollbarVisibility::Always, 2), 9);
+    /// assert_eq!(width(ScrollbarVisibility::Never, 10), 10);
+    ///
+    /// // the knob is as long as the visible portion of the list
+    /// let knob = |lines| {
+    ///     let mut state = State::default();
+    ///     state.build(rect, |ui| _ = show(ui, ScrollbarVisibility::Auto, lines));
+    ///
+    ///     let mut surface = Surface::new(rect.size());
+    ///     state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+    ///
+    ///     let knob = Cell::Pixel(Pixel::new('#').fg("#0F0").bg("#000"));
+    ///     (0..3).filter(|&y| surface.get(pos2(9, y)) == Some(&knob)).count()
+    /// };
+    ///
+    /// assert_eq!(knob(4), 2);
+    /// assert_eq!(knob(9), 1);
+    /// ```
+    ///
+    /// Making room for the scrollbar lays the children out again, but they still only get each event once
+    ///
+    /// ```rust
+    /// # use std::{cell::Cell, rc::Rc};
+    /// # use too::{math::{pos2, vec2}, view::{test::hover, Builder, EventCtx, Handled, Interest, State, Ui, View, ViewEvent}, views::{list, ScrollbarVisibility}};
+    /// #[derive(Debug)]
+    /// struct Moves(Rc<Cell<usize>>);
+    ///
+    /// impl<'v> Builder<'v> for Moves {
+    ///     type View = Self;
+    /// }
+    ///
+    /// impl View for Moves {
+    ///     type Args<'v> = Self;
+    ///     type Response = ();
+    ///
+    ///     fn create(args: Self::Args<'_>) -> Self {
+    ///         args
+    ///     }
+    ///
+    ///     fn update(&mut self, _: Self::Args<'_>, _: &Ui) -> Self::Response {}
+    ///
+    ///     fn interests(&self) -> Interest {
+    ///         Interest::MOUSE_MOVE
+    ///     }
+    ///
+    ///     fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
+    ///         if let ViewEvent::MouseMove { .. } = event {
+    ///             self.0.set(self.0.get() + 1);
+    ///         }
+    ///         Handled::Bubble
+    ///     }
+    /// }
+    ///
+    /// let moves = Rc::new(Cell::new(0));
+    /// let mut state = State::default();
+    /// let mut app = |ui: &Ui| {
+    ///     ui.exact_size(vec2(10, 3), |ui| {
+    ///         let list = list().vertical().scrollable(true).scrollbar(ScrollbarVisibility::Auto);
+    ///         ui.show_children(list, |ui| {
+    ///             ui.show(Moves(moves.clone()));
+    ///             for i in 0..9 {
+    ///                 ui.label(format!("line {i}"));
+    ///             }
+    ///         });
+    ///     });
+    /// };
+    ///
+    /// // the first one is before there was a layout
+    /// for x in 0..3 {
+    ///     hover(&mut state, pos2(x, 0), &mut app);
+    /// }
+    /// assert_eq!(moves.get(), 2);
+    /// ```
+    pub const fn scrollbar(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.scroll.visibility = visibility;
+        self
+    }
+
     pub const fn class(mut self, class: ScrollClass) -> Self {
         self.class = StyleKind::Deferred(class);
         self
@@ -151,7 +332,7 @@ impl List {
 
 impl List {
     fn draw_scrollbar(&mut self, render: &mut Render) {
-        if self.total_extent() <= render.rect().height() as f32 {
+        if !self.scroll.visible {
             return;
         }
 
@@ -166,16 +347,21 @@ impl List {
         let track = style.track.unwrap_or(' ');
         let track_color = style.track_color.unwrap_or(render.palette.outline);
 
-        let bar_rect = Rect::from_min_size(self.axis.pack(0, extent), rect.size());
+        let bar_rect = Rect::from_min_size(
+            self.axis.pack(0, extent),
+            self.axis.pack(self.axis.main::<i32>(rect.size()), 1),
+        );
 
         render.crop(bar_rect, |render| {
             let pixel = Pixel::new(track).fg(track_color).bg(style.background);
             render.fill_with(pixel);
         });
 
-        let pos: Pos2 = self.axis.pack(self.knob_index(rect), extent);
-        let hovered =
-            self.scroll.knob_held || (render.mouse_pos() == pos + render.rect().left_top());
+        let (start, len) = self.knob(rect.size());
+        let mouse = render.mouse_pos() - render.rect().left_top();
+        let hovered = self.scroll.knob_held
+            || (self.axis.cross::<i32>(mouse) == extent
+                && (start..start + len).contains(&self.axis.main(mouse)));
 
         let knob = if hovered {
             style.knob_grab.unwrap_or(style.knob)
@@ -190,21 +376,43 @@ impl List {
         };
 
         render.local_space(|render| {
-            render.set(pos, Pixel::new(knob).fg(color));
+            for main in start..start + len {
+                let pos: Pos2 = self.axis.pack(main, extent);
+                render.set(pos, Pixel::new(knob).fg(color));
+            }
         });
     }
 
-    fn knob_offset(&self, size: Vec2) -> i32 {
-        let total = self.total_extent() - self.axis.main::<f32>(size);
-        let extent = self.axis.main(size - 1);
-        remap(self.scroll.pos as f32, 0.0..=total, 0.0..=extent).round() as i32
+    // the start and length of the knob along the track, the length is proportional to how much of the list is visible
+    fn knob(&self, size: Vec2) -> (i32, i32) {
+        let track = self.axis.main::<i32>(size).max(0);
+        let total = self.total_extent();
+        if total <= track as f32 {
+            return (0, track);
+        }
+
+        let len = ((track * track) as f32 / total).round() as i32;
+        let len = len.clamp(1.min(track), track);
+
+        let max = total - track as f32;
+        let start = remap_clamp(
+            self.scroll.pos as f32,
+            0.0..=max,
+            0.0..=(track - len) as f32,
+        );
+        (start.round() as i32, len)
     }
 
-    fn knob_index(&self, rect: Rect) -> i32 {
-        let total = self.total_extent() - self.axis.main::<f32>(rect.size());
-        let size = rect.right_bottom() - 1;
-        let extent: f32 = self.axis.main(size);
-        remap(self.scroll.pos as f32, 0.0..=total, 0.0..=extent).round() as i32
+    fn on_knob(&self, rect: Rect, pos: Pos2) -> bool {
+        if !self.scroll.visible {
+            return false;
+        }
+
+        let local = pos - rect.left_top();
+        let extent = self.axis.cross(rect.size() - 1);
+        let (start, len) = self.knob(rect.size());
+        self.axis.cross::<i32>(local) == extent
+            && (start..start + len).contains(&self.axis.main(local))
     }
 
     fn scroll(&mut self, delta: i32, rect: Rect) {
@@ -219,6 +427,48 @@ impl List {
         (self.state.main_sum() + gap).round()
     }
 
+    fn measure(&mut self, layout: &mut Layout, space: Space) {
+        let node = layout.nodes.get_current();
+        self.state.resize(node.children.len());
+
+        let (_, min_minor) = self.axis.unpack(space.min);
+        let (max_major, max_minor) = self.axis.unpack(space.max);
+
+        let min_minor = min_minor.min(max_minor);
+
+        let total_gap = self.gap * (node.children.len() as f32 - 1.0);
+
+        let align = self.cross_align;
+        if align.is_fill() || (align.is_stretch() && min_minor == max_minor) {
+            let args = ListParams {
+                max_major,
+                min_minor: max_minor,
+                max_minor,
+                total_gap,
+            };
+            self.flex_layout(layout, args);
+        } else {
+            let args = ListParams {
+                max_major,
+                min_minor: 0.0,
+                max_minor,
+                total_gap,
+            };
+            self.flex_layout(layout, args);
+
+            if align.is_stretch() {
+                let minor = f32::clamp(self.state.cross_sum(), min_minor, max_minor);
+                let args = ListParams {
+                    max_major,
+                    min_minor: minor,
+                    max_minor: minor,
+                    total_gap,
+                };
+                self.flex_layout(layout, args);
+            }
+        }
+    }
+
     #[cfg_attr(feature = "profile", profiling::function)]
     fn flex_layout(&mut self, layout: &mut Layout, args: ListParams) {
         self.state.flex = 0.0;
@@ -243,22 +493,30 @@ impl List {
             self.state.cross[i] = self.axis.cross(size);
         }
 
-        // flex stuff
+        // loose flex children can take up to their share of what is left
         let remaining = f32::max(args.max_major - args.total_gap - self.state.main_sum(), 0.0);
-        let division = (remaining / self.state.flex).floor();
-        // assert!(division.is_finite());
+        let tight = node
+            .children
+            .iter()
+            .map(|&child| layout.flex(child))
+            .filter(|flex| flex.has_flex() && flex.is_expand())
+            .map(|flex| flex.factor())
+            .sum::<f32>();
+        // without any tight children, the last loose child can take the rounding remainder
+        let loose = if tight == 0.0 {
+            self.state.flex
+        } else {
+            f32::INFINITY
+        };
+        let mut shares = Shares::new(remaining, self.state.flex, loose);
 
         for (i, &child) in node.children.iter().enumerate() {
             let flex = layout.flex(child);
-            if !flex.has_flex() {
-                continue;
-            }
-
-            if flex.is_expand() {
+            if !flex.has_flex() || flex.is_expand() {
                 continue;
             }
 
-            let major = division * flex.factor();
+            let major = shares.next(flex.factor());
             let space = Space::new(
                 self.axis.pack(0.0, args.min_minor),
                 self.axis.pack(major, args.max_minor),
@@ -269,22 +527,17 @@ impl List {
             self.state.cross[i] = self.axis.cross(size);
         }
 
-        // expanded stuff
+        // tight flex children split whatever the loose children left, exactly
         let remaining = f32::max(args.max_major - args.total_gap - self.state.main_sum(), 0.0);
-        let division = (remaining / self.state.flex).floor();
-        // assert!(division.is_finite());
+        let mut shares = Shares::new(remaining, tight, tight);
 
         for (i, &child) in node.children.iter().enumerate() {
             let flex = layout.flex(child);
-            if !flex.has_flex() {
+            if !flex.has_flex() || !flex.is_expand() {
                 continue;
             }
 
-            if !flex.is_expand() {
-                continue;
-            }
-
-            let major = division * flex.factor();
+            let major = shares.next(flex.factor());
             let space = Space::new(
                 self.axis.pack(major, args.min_minor),
                 self.axis.pack(major, args.max_minor),
@@ -297,6 +550,38 @@ impl List {
     }
 }
 
+// splits space by flex factors into whole cells. once `last` worth of factors has been given out, that child gets the rounding remainder
+struct Shares {
+    remaining: f32,
+    total: f32,
+    last: f32,
+    given: f32,
+    seen: f32,
+}
+
+impl Shares {
+    const fn new(remaining: f32, total: f32, last: f32) -> Self {
+        Self {
+            remaining,
+            total,
+            last,
+            given: 0.0,
+            seen: 0.0,
+        }
+    }
+
+    fn next(&mut self, factor: f32) -> f32 {
+        self.seen += factor;
+        let share = if self.seen >= self.last {
+            self.remaining - self.given
+        } else {
+            (self.remaining * factor / self.total).floor()
+        };
+        self.given += share;
+        share.max(0.0)
+    }
+}
+
 impl std::fmt::Debug for List {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("List")
@@ -325,6 +610,7 @@ impl View for List {
         *self = Self {
             state: std::mem::take(&mut self.state),
             scroll: ScrollState {
+                visible: self.scroll.visible,
                 pos: self.scroll.pos,
                 knob_held: self.scroll.knob_held,
                 ..args.scroll
@@ -356,14 +642,6 @@ impl View for List {
 
         let rect = ctx.rect();
 
-        let extent = match self.axis {
-            Axis::Horizontal => rect.left_bottom() - vec2(0, 1),
-            Axis::Vertical => rect.right_top() - vec2(1, 0),
-        };
-
-        let knob: Pos2 = self.axis.pack(self.knob_offset(rect.size()), 0);
-        let knob_pos = knob + extent;
-
         match event {
             ViewEvent::KeyInput { key, .. } => {
                 let delta = match key {
@@ -380,7 +658,7 @@ impl View for List {
             }
 
             ViewEvent::MouseMove { pos, .. } => {
-                if knob_pos != pos {
+                if !self.on_knob(rect, pos) {
                     self.scroll.knob_held = false;
                 }
                 Handled::Sink
@@ -389,7 +667,7 @@ impl View for List {
             ViewEvent::MouseHeld {
                 pos, inside: true, ..
             } => {
-                self.scroll.knob_held = knob_pos == pos;
+                self.scroll.knob_held = self.on_knob(rect, pos);
                 Handled::Sink
             }
 
@@ -400,15 +678,18 @@ impl View for List {
             } if self.scroll.knob_held => {
                 let max = self.total_extent() - self.axis.main::<f32>(rect.size()).abs();
 
-                let main = self.axis.main((rect.left(), rect.top()));
-                let delta: i32 = self.axis.main(current - main);
-                let extent: i32 = self.axis.main(rect.size() - 1);
+                // keep the middle of the knob under the mouse
+                let (_, len) = self.knob(rect.size());
+                let main = self.axis.main::<i32>(rect.left_top());
+                let delta = self.axis.main::<i32>(current) - main - len / 2;
+                let extent = self.axis.main::<i32>(rect.size()) - len;
 
-                self.scroll.pos = remap(
+                self.scroll.pos = remap_clamp(
                     delta as f32, //
-                    0.0..=extent as f32,
-                    0.0..=max,
-                ) as usize;
+                    0.0..=extent.max(1) as f32,
+                    0.0..=max.max(0.0),
+                )
+                .round() as usize;
 
                 Handled::Sink
             }
@@ -440,18 +721,41 @@ impl View for List {
             layout.enable_clipping();
         }
 
-        let margin: Size = if self.scroll.scrollable {
-            self.axis.pack(0.0, 1.0)
+        let margin: Size = self.axis.pack(0.0, 1.0);
+        self.scroll.visible = self.scroll.scrollable
+            && match self.scroll.visibility {
+                ScrollbarVisibility::Always => true,
+                ScrollbarVisibility::Auto | ScrollbarVisibility::Never => false,
+            };
+
+        if self.scroll.visible {
+            space.max -= margin;
+        }
+
+        let mark = layout.interest_mark();
+        self.measure(&mut layout, space);
+
+        // only take the space for the scrollbar once we know the children overflow
+        if self.scroll.scrollable
+            && self.scroll.visibility == ScrollbarVisibility::Auto
+            && self.total_extent() > self.axis.main(space.max)
+        {
+            // the first pass was only a measurement, so its interests would otherwise be registered twice
+            layout.rollback_interests(mark);
+            self.scroll.visible = true;
+            space.max -= margin;
+            self.measure(&mut layout, space);
+        }
+
+        let margin = if self.scroll.visible {
+            margin
         } else {
             Size::ZERO
         };
 
-        space.max -= margin;
-
         let total_extent = self.axis.main(space.max);
 
         let node = layout.nodes.get_current();
-        self.state.resize(node.children.len());
 
         let (min_major, min_minor) = self.axis.unpack(space.min);
         let (max_major, max_minor) = self.axis.unpack(space.max);
@@ -461,36 +765,6 @@ impl View for List {
 
         let total_gap = self.gap * (node.children.len() as f32 - 1.0);
 
-        let align = self.cross_align;
-        if align.is_fill() || (align.is_stretch() && min_minor == max_minor) {
-            let args = ListParams {
-                max_major,
-                min_minor: max_minor,
-                max_minor,
-                total_gap,
-            };
-            self.flex_layout(&mut layout, args);
-        } else {
-            let args = ListParams {
-                max_major,
-                min_minor: 0.0,
-                max_minor,
-                total_gap,
-            };
-            self.flex_layout(&mut layout, args);
-
-            if align.is_stretch() {
-                let minor = f32::clamp(self.state.cross_sum(), min_minor, max_minor);
-                let args = ListParams {
-                    max_major,
-                    min_minor: minor,
-                    max_minor: minor,
-                    total_gap,
-                };
-                self.flex_layout(&mut layout, args);
-            }
-        }
-
         let mut main = f32::clamp(self.state.main_sum() + total_gap, min_major, max_major);
         let cross = f32::clamp(self.state.cross_sum(), min_minor, max_minor);
 
@@ -548,6 +822,8 @@ pub const fn list() -> List {
         state: ListState::new(),
         scroll: ScrollState {
             scrollable: false,
+            visibility: ScrollbarVisibility::Auto,
+            visible: false,
             pos: 0,
             knob_held: false,
         },
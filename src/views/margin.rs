@@ -1,3 +1,5 @@
+use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+
 use crate::{
     math::{Margin, Size, Space},
     view::{Builder, Layout, View},
@@ -29,6 +31,12 @@ impl View for Padding {
         this
     }
 
+    fn layout_hash(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.margin.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
     fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
         let node = layout.nodes.get_current();
 
@@ -5,8 +5,11 @@
 mod aligned;
 pub use aligned::{aligned, Aligned};
 
+mod aspect_ratio;
+pub use aspect_ratio::{aspect_ratio, AspectRatio};
+
 mod background;
-pub use background::Background;
+pub use background::{background, background_with, Background, BackgroundWith};
 
 mod border;
 pub use border::{border, frame, BorderClass, BorderStyle, Frame};
@@ -14,14 +17,34 @@ pub use border::{border, frame, BorderClass, BorderStyle, Frame};
 mod button;
 pub use button::{button, Button, ButtonClass, ButtonResponse, ButtonStyle};
 
+mod canvas;
+pub use canvas::{canvas, Canvas};
+
 mod checkbox;
-pub use checkbox::{checkbox, Checkbox, CheckboxClass, CheckboxStyle};
+pub use checkbox::{
+    checkbox, checkbox_tristate, Checkbox, CheckboxClass, CheckboxStyle, TriCheckbox,
+    TriCheckboxResponse, TriState,
+};
+
+mod collapsible;
+pub use collapsible::{
+    collapsible, Collapsible, CollapsibleClass, CollapsibleResponse, CollapsibleStyle,
+    CollapsibleView,
+};
 
-// mod collapsible;
-// pub use collapsible::{collapsible, Collapsible, CollapsibleClass, CollapsibleStyle};
+mod color_picker;
+pub use color_picker::{color_picker, ColorPicker, ColorPickerView};
 
 mod constrain;
-pub use constrain::{Constrain, Unconstrained};
+pub use constrain::{Constrain, Constrained, ConstrainedView, Unconstrained};
+
+mod drop_down;
+pub use drop_down::{
+    drop_down, DropDown, DropDownClass, DropDownResponse, DropDownStyle, DropDownView,
+};
+
+mod enabled;
+pub use enabled::{Enabled, EnabledView};
 
 mod expander;
 pub use expander::{expander, separator, Expander, Separator, SeparatorClass, SeparatorStyle};
@@ -32,14 +55,23 @@ pub use fill::{fill, Fill};
 mod flex;
 pub use flex::Flexible;
 
+mod forward;
+use forward::forward_view;
+
 mod key_area;
 pub use key_area::{key_area, KeyArea, KeyAreaResponse};
 
+mod key_sequence;
+pub use key_sequence::{key_sequence, KeySequenceArea, KeySequenceResponse, KeySequenceView};
+
 mod label;
-pub use label::{label, Label, LabelClass, LabelStyle};
+pub use label::{label, Label, LabelClass, LabelStyle, WrapMode};
+
+mod labeled;
+pub use labeled::{labeled, Labeled, LabeledView};
 
 mod list;
-pub use list::{list, List, ScrollClass, ScrollStyle};
+pub use list::{list, List, ScrollClass, ScrollStyle, ScrollbarVisibility};
 
 mod margin;
 pub use margin::Padding;
@@ -48,19 +80,40 @@ mod mouse_area;
 pub use mouse_area::{mouse_area, DraggingResponse, MouseArea, MouseAreaResponse};
 
 mod offset;
-pub use offset::Offset;
+pub use offset::{Offset, OffsetResponse};
+
+mod overflow;
+pub use overflow::{OverflowVisible, OverflowVisibleView};
+
+mod overlay;
+pub use overlay::{overlay, Overlay, OverlayLayout};
 
 mod progress;
-pub use progress::{progress, Progress, ProgressClass, ProgressStyle};
+pub use progress::{progress, progress_indeterminate, Progress, ProgressClass, ProgressStyle};
 
 mod radio;
 pub use radio::{radio, Radio, RadioClass, RadioStyle};
 
+mod rich_text;
+pub use rich_text::{RichText, SpanStyle};
+
+mod scrollable;
+pub use scrollable::{scrollable, ScrollView, Scrollable, ScrollableResponse};
+
 mod selected;
-pub use selected::{selected, Selected, SelectedClass, SelectedStyle};
+pub use selected::{
+    selected, selected_exclusive, Selected, SelectedClass, SelectedExclusive, SelectedStyle,
+};
 
 mod slider;
-pub use slider::{slider, Slider, SliderClass, SliderStyle};
+pub use slider::{slider, Slider, SliderClass, SliderResponse, SliderStyle};
+
+mod split_view;
+pub(crate) use split_view::Pane;
+pub use split_view::{split_view, Split, SplitClass, SplitStyle, SplitView};
+
+mod tabs;
+pub use tabs::{tabs, Tabs, TabsClass, TabsResponse, TabsStyle, TabsView};
 
 mod text_input;
 pub use text_input::{text_input, TextInput, TextInputClass, TextInputResponse, TextInputStyle};
@@ -74,26 +127,27 @@ pub use toggle::{toggle, Toggle};
 mod toggle_switch;
 pub use toggle_switch::{toggle_switch, ToggleClass, ToggleResponse, ToggleStyle, ToggleSwitch};
 
+mod tooltip;
+pub use tooltip::{Tooltip, TooltipClass, TooltipStyle, TooltipView};
+
+mod virtual_list;
+pub use virtual_list::{list_virtual, VirtualList, VirtualListResponse, VirtualListView};
+
+mod window;
+pub use window::{window, Window, WindowResponse, WindowView};
+
 mod wrap;
 pub use wrap::{horizontal_wrap, vertical_wrap, Wrap};
 
-// pub mod drop_down;
-// pub mod scrollable;
 // pub mod focus_ring;
-// pub mod split_view;
 
 // tree view (why not)
-// drop down
-// split view
 // link (hyperlink support. OSC 8 https://github.com/Alhadis/OSC8-Adoption/)
 // panel (docking)
 //
-// stack (? z-index layering)
-//
 //
 // floating window
 //
-// canvas
 // animate
 //
 //
@@ -34,6 +34,7 @@ impl DraggingResponse {
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct MouseAreaResponse {
     clicked: bool,
+    clicks: usize,
     hovered: bool,
     held: bool,
     pos: Pos2,
@@ -41,6 +42,7 @@ pub struct MouseAreaResponse {
     leave: bool,
     dragged: Option<DragState>,
     scrolled: Option<Vec2>,
+    hover_pos: Option<Pos2>,
 }
 
 impl MouseAreaResponse {
@@ -52,6 +54,59 @@ impl MouseAreaResponse {
         self.clicked
     }
 
+    /// Was this click the second one in a row?
+    ///
+    /// A double click is also a [`clicked`](Self::clicked) click
+    ///
+    /// ```rust
+    /// # use too::{view::State, math::{pos2, vec2, Rect}, backend::{Event, Modifiers, MouseButton}, views::mouse_area};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+    ///
+    /// let click = |state: &mut State, x| {
+    ///     state.event(&Event::MouseMove { pos: pos2(x, 0) });
+    ///     for down in [true, false] {
+    ///         let pos = pos2(x, 0);
+    ///         state.event(&Event::MouseButtonChanged { pos, button: MouseButton::Primary, down, modifiers: Modifiers::NONE });
+    ///     }
+    /// };
+    /// let mut build = |state: &mut State| {
+    ///     state.build(rect, |ui| ui.show_children(mouse_area(), |ui| _ = ui.label("hello world")).into_inner().0)
+    /// };
+    ///
+    /// build(&mut state);
+    /// click(&mut state, 1);
+    /// let resp = build(&mut state);
+    /// assert!(resp.clicked() && !resp.double_clicked());
+    ///
+    /// click(&mut state, 1);
+    /// let resp = build(&mut state);
+    /// assert!(resp.clicked() && resp.double_clicked());
+    ///
+    /// click(&mut state, 2);
+    /// assert!(build(&mut state).triple_clicked());
+    ///
+    /// // moving too far away starts over
+    /// click(&mut state, 8);
+    /// let resp = build(&mut state);
+    /// assert!(resp.clicked() && !resp.double_clicked());
+    ///
+    /// // and so does waiting too long between clicks
+    /// state.set_multi_click_window(std::time::Duration::ZERO);
+    /// click(&mut state, 8);
+    /// assert!(!build(&mut state).double_clicked());
+    /// ```
+    pub const fn double_clicked(&self) -> bool {
+        self.clicked && self.clicks == 2
+    }
+
+    /// Was this click the third one in a row?
+    ///
+    /// A triple click is also a [`clicked`](Self::clicked) click
+    pub const fn triple_clicked(&self) -> bool {
+        self.clicked && self.clicks == 3
+    }
+
     pub const fn hovered(&self) -> bool {
         self.hovered
     }
@@ -76,9 +131,35 @@ impl MouseAreaResponse {
         })
     }
 
+    /// How far the mouse was scrolled over the area, since the last frame
     pub const fn scrolled(&self) -> Option<Vec2> {
         self.scrolled
     }
+
+    /// Where the mouse is, relative to the top-left of the area
+    ///
+    /// This is clamped to the area, and is `None` when the mouse isn't over it
+    ///
+    /// ```rust
+    /// # use too::{math::pos2, view::{test::hover, State}, views::mouse_area};
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| {
+    ///     ui.horizontal(|ui| {
+    ///         ui.label("ab");
+    ///         ui.show_children(mouse_area(), |ui| _ = ui.label("hello")).into_inner().0.hover_pos()
+    ///     })
+    ///     .into_inner()
+    /// };
+    ///
+    /// assert_eq!(hover(&mut state, pos2(20, 0), app), None);
+    /// // the label is followed by a column of spacing
+    /// assert_eq!(hover(&mut state, pos2(5, 0), app), Some(pos2(2, 0)));
+    /// assert_eq!(hover(&mut state, pos2(7, 0), app), Some(pos2(4, 0)));
+    /// assert_eq!(hover(&mut state, pos2(20, 0), app), None);
+    /// ```
+    pub const fn hover_pos(&self) -> Option<Pos2> {
+        self.hover_pos
+    }
 }
 
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
@@ -94,11 +175,13 @@ enum MouseState {
 pub struct MouseArea {
     state: MouseState,
     clicked: bool,
+    clicks: usize,
     pos: Pos2,
     entered: bool,
     leave: bool,
     scrolled: Option<Vec2>,
     dragged: Option<DragState>,
+    hover_pos: Option<Pos2>,
 }
 
 impl MouseArea {
@@ -134,12 +217,14 @@ impl View for MouseArea {
         MouseAreaResponse {
             pos: self.pos,
             clicked: std::mem::take(&mut self.clicked),
+            clicks: std::mem::take(&mut self.clicks),
             hovered: matches!(state, MouseState::Hovering),
             held: matches!(state, MouseState::Held),
             dragged,
             scrolled: std::mem::take(&mut self.scrolled),
             entered: self.entered,
             leave: self.leave,
+            hover_pos: self.hover_pos,
         }
     }
 
@@ -152,18 +237,22 @@ impl View for MouseArea {
     }
 
     fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        let rect = ctx.rect();
+        let local = |pos| rect.nearest_point(pos) - rect.min;
+
         match event {
             ViewEvent::MouseMove { pos, .. } => {
                 self.state = MouseState::None;
                 self.pos = pos;
+                self.hover_pos = Some(local(pos));
                 self.dragged.take();
             }
 
             ViewEvent::MouseDrag { start, current, .. } => {
                 self.state = MouseState::Held;
                 self.pos = current;
+                self.hover_pos = Some(local(current));
 
-                let rect = ctx.rect();
                 self.dragged
                     .get_or_insert_with(|| DragState {
                         start,
@@ -173,10 +262,12 @@ impl View for MouseArea {
                     .pos = current;
             }
 
-            ViewEvent::MouseClicked { pos, .. } => {
+            ViewEvent::MouseClicked { pos, button, .. } => {
                 self.state = MouseState::Held;
                 self.pos = pos;
-                self.clicked = true
+                self.hover_pos = Some(local(pos));
+                self.clicked = true;
+                self.clicks = ctx.input.click_count(button);
             }
 
             // ViewEvent::MouseButtonChanged {
@@ -200,12 +291,14 @@ impl View for MouseArea {
             ViewEvent::MouseEntered => {
                 self.entered = true;
                 self.state = MouseState::Hovering;
+                self.hover_pos = Some(local(ctx.cursor_pos()));
                 self.dragged.take();
             }
 
             ViewEvent::MouseLeave => {
                 self.leave = true;
                 self.state = MouseState::None;
+                self.hover_pos = None;
                 self.dragged.take();
             }
             _ => return Handled::Bubble,
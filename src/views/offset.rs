@@ -1,17 +1,80 @@
 use crate::{
-    math::{Pos2, Size, Space},
-    view::{Builder, Layout, View},
+    math::{pos2, Pos2, Rect, Size, Space, Vec2},
+    view::{Builder, Layout, Ui, View},
 };
 
+/// Shift the children by an offset, relative to where this is shown
+///
+/// ```rust
+/// # use too::{math::{pos2, vec2, Rect}, view::{test::render_to_string, State, Ui}, views::{Constrain, Offset}};
+/// let out = render_to_string(|ui| {
+///     ui.constrain(Constrain::max_size((10, 3)), |ui| ui.offset((-2, 1), |ui| ui.label("hello")));
+/// });
+/// // the child is clipped
+/// assert_eq!(out, "\nllo");
+///
+/// // but it can be kept inside of the space instead
+/// let out = render_to_string(|ui| {
+///     ui.constrain(Constrain::max_size((10, 3)), |ui| {
+///         ui.show_children(Offset::new((-2, 1)).clamp_inside(true), |ui| ui.label("hello"))
+///     });
+/// });
+/// assert_eq!(out, "\nhello");
+///
+/// // and the response says where they ended up, once they have been laid out
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 3));
+/// let app = |ui: &Ui| {
+///     let offset = Offset::new((8, 1)).clamp_inside(true);
+///     ui.show_children(offset, |ui| ui.label("hello")).into_inner().0
+/// };
+/// state.build(rect, app);
+/// let resp = state.build(rect, app);
+/// assert!(resp.clamped());
+/// assert_eq!(resp.rect(), Rect::from_min_size(pos2(5, 1), vec2(5, 1)));
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
 pub struct Offset {
     pos: Pos2,
+    clamp_inside: bool,
+    rect: Rect,
+    clamped: bool,
 }
 
 impl Offset {
     pub fn new(pos: impl Into<Pos2>) -> Self {
-        Self { pos: pos.into() }
+        Self {
+            pos: pos.into(),
+            clamp_inside: false,
+            rect: Rect::ZERO,
+            clamped: false,
+        }
+    }
+
+    /// Should the children be moved back inside of the space they were given, when the offset would push them out of it?
+    ///
+    /// Both edges are kept inside, but if a child is larger than the space, it is kept against the top-left edges
+    pub const fn clamp_inside(mut self, clamp_inside: bool) -> Self {
+        self.clamp_inside = clamp_inside;
+        self
+    }
+
+    // keep the child inside of the space, its right and bottom edges are exclusive
+    fn clamp(&self, space: Space, rect: Rect) -> Rect {
+        let bound = |max: f32| {
+            if max.is_finite() {
+                max as i32
+            } else {
+                i32::MAX
+            }
+        };
+        let parent = Rect::from_min_size(
+            Pos2::ZERO,
+            Vec2::new(bound(space.max.width), bound(space.max.height)),
+        );
+        let rect = parent.clamp_rect(rect);
+        Rect::from_min_size(rect.min.max(pos2(0, 0)), rect.size())
     }
 }
 
@@ -19,20 +82,60 @@ impl<'v> Builder<'v> for Offset {
     type View = Self;
 }
 
+/// The response from an [`Offset`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct OffsetResponse {
+    rect: Rect,
+    clamped: bool,
+}
+
+impl OffsetResponse {
+    /// Where the children were placed when they were last laid out, relative to where the offset is shown
+    ///
+    /// This covers all of the children
+    pub const fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Were the children moved to keep them inside, when they were last laid out?
+    pub const fn clamped(&self) -> bool {
+        self.clamped
+    }
+}
+
 impl View for Offset {
     type Args<'v> = Self;
-    type Response = ();
+    type Response = OffsetResponse;
 
     fn create(args: Self::Args<'_>) -> Self {
         args
     }
 
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        self.pos = args.pos;
+        self.clamp_inside = args.clamp_inside;
+        OffsetResponse {
+            rect: self.rect,
+            clamped: self.clamped,
+        }
+    }
+
     fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
         let node = layout.nodes.get_current();
         let mut size = Size::ZERO;
         for &child in &node.children {
             size = size.max(layout.compute(child, space));
-            layout.set_position(child, self.pos);
+        }
+
+        let mut rect = Rect::from_min_size(self.pos, Vec2::from(size));
+        if self.clamp_inside {
+            rect = self.clamp(space, rect);
+        }
+        self.clamped = rect.min != self.pos;
+        self.rect = rect;
+
+        for &child in &node.children {
+            layout.set_position(child, rect.min);
         }
         space.constrain_min(size)
     }
@@ -0,0 +1,54 @@
+use crate::{
+    math::{Size, Space},
+    view::{Builder, Layout, Ui, View},
+};
+
+use super::forward_view;
+
+/// A builder that is allowed to draw outside of its rect
+///
+/// This is created by [`ViewExt::overflow_visible`](crate::view::ViewExt::overflow_visible)
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct OverflowVisible<B> {
+    builder: B,
+}
+
+impl<B> OverflowVisible<B> {
+    pub(crate) const fn new(builder: B) -> Self {
+        Self { builder }
+    }
+}
+
+impl<'v, B: Builder<'v>> Builder<'v> for OverflowVisible<B> {
+    type View = OverflowVisibleView<B::View>;
+}
+
+/// A view that lets its inner view draw outside of its rect
+///
+/// Everything is passed through to the inner view, it just [enables overflow](Layout::enable_overflow) when its laid out
+#[derive(Debug)]
+pub struct OverflowVisibleView<V> {
+    view: V,
+}
+
+impl<V: View> View for OverflowVisibleView<V> {
+    type Args<'v> = OverflowVisible<V::Args<'v>>;
+    type Response = V::Response;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            view: V::create(args.builder),
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.view.update(args.builder, ui)
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        layout.enable_overflow();
+        self.view.layout(layout, space)
+    }
+
+    forward_view!(view => flex, interactive, interests, primary_axis, event, size, layout_hash, draw);
+}
@@ -0,0 +1,116 @@
+use crate::{
+    layout::Align2,
+    math::{Pos2, Size, Space},
+    view::{Builder, Layout, Response, Ui, View},
+};
+
+/// A view that stacks its children on top of each other, in the same rect
+///
+/// Each child gets the same space, and they're all placed at the same position. Later children are drawn on top of earlier ones.
+///
+/// See [`Ui::overlay_layout`] for a shorthand, which aligns each child independently
+///
+/// The children are measured before they're given the overlay's size, but they only get each event once
+///
+/// ```rust
+/// # use std::{cell::Cell, rc::Rc};
+/// # use too::{math::pos2, view::{test::hover, Builder, EventCtx, Handled, Interest, State, Ui, View, ViewEvent}};
+/// #[derive(Debug)]
+/// struct Moves(Rc<Cell<usize>>);
+///
+/// impl<'v> Builder<'v> for Moves {
+///     type View = Self;
+/// }
+///
+/// impl View for Moves {
+///     type Args<'v> = Self;
+///     type Response = ();
+///
+///     fn create(args: Self::Args<'_>) -> Self {
+///         args
+///     }
+///
+///     fn update(&mut self, _: Self::Args<'_>, _: &Ui) -> Self::Response {}
+///
+///     fn interests(&self) -> Interest {
+///         Interest::MOUSE_MOVE
+///     }
+///
+///     fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
+///         if let ViewEvent::MouseMove { .. } = event {
+///             self.0.set(self.0.get() + 1);
+///         }
+///         Handled::Bubble
+///     }
+/// }
+///
+/// let moves = Rc::new(Cell::new(0));
+/// let mut state = State::default();
+/// let mut app = |ui: &Ui| {
+///     ui.show_children(too::views::overlay(), |ui| {
+///         ui.show(Moves(moves.clone()));
+///     });
+/// };
+///
+/// // the first one is before there was a layout
+/// for x in 0..3 {
+///     hover(&mut state, pos2(x, 0), &mut app);
+/// }
+/// assert_eq!(moves.get(), 2);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Overlay;
+
+impl<'v> Builder<'v> for Overlay {
+    type View = Self;
+}
+
+impl View for Overlay {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        args
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let node = layout.nodes.get_current();
+
+        let mut size = Size::ZERO;
+        for &child in &node.children {
+            size = size.max(layout.measure(child, space.loosen()));
+        }
+        let size = space.fit(size);
+
+        // every child fills the overlay, so they can place themselves in it
+        for &child in &node.children {
+            layout.compute(child, Space::tight(size));
+            layout.set_position(child, Pos2::ZERO);
+        }
+
+        size
+    }
+}
+
+/// Stacks its children on top of each other, see [`Overlay`]
+pub const fn overlay() -> Overlay {
+    Overlay
+}
+
+/// Adds aligned children to an [`Overlay`], see [`Ui::overlay_layout`]
+pub struct OverlayLayout<'a, 'b> {
+    pub(crate) ui: &'a Ui<'b>,
+}
+
+impl<'a, 'b> OverlayLayout<'a, 'b> {
+    /// Show some views aligned in the overlay, on top of the views added before them
+    ///
+    /// Each of these is on its own input layer, so the later ones get the mouse first
+    pub fn at<R>(&self, align: Align2, show: impl FnOnce(&Ui) -> R) -> Response<R>
+    where
+        R: 'static,
+    {
+        self.ui.layer(|ui| ui.aligned(align, show).into_inner())
+    }
+}
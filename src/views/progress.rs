@@ -1,10 +1,11 @@
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, time::Duration};
 
 use crate::{
+    animation::{easing, Animation},
     layout::Axis,
     math::{lerp, normalize, Pos2, Size, Space},
     renderer::{Pixel, Rgba},
-    view::{Builder, Elements, Layout, Palette, Render, StyleKind, View},
+    view::{Builder, Elements, Layout, Palette, Render, StyleKind, Ui, View},
 };
 
 pub type ProgressClass = fn(&Palette, Axis) -> ProgressStyle;
@@ -17,6 +18,10 @@ pub struct ProgressStyle {
     pub filled_hovered: Option<Rgba>,
     pub unfilled: char,
     pub filled: char,
+    /// How wide the moving segment of an [indeterminate](progress_indeterminate) bar is
+    pub segment_width: u16,
+    /// How fast the moving segment of an [indeterminate](progress_indeterminate) bar moves, in cells per second
+    pub speed: f32,
 }
 
 impl ProgressStyle {
@@ -28,6 +33,8 @@ impl ProgressStyle {
             filled_hovered: None,
             unfilled: axis.main((Elements::MEDIUM_RECT, Elements::LARGE_RECT)),
             filled: Elements::LARGE_RECT,
+            segment_width: 4,
+            speed: 20.0,
         }
     }
 
@@ -98,6 +105,8 @@ pub struct Progress {
     value: f32,
     range: RangeInclusive<f32>,
     axis: Axis,
+    indeterminate: bool,
+    travel: i32,
     class: StyleKind<ProgressClass, ProgressStyle>,
 }
 
@@ -107,10 +116,18 @@ impl Progress {
             value,
             range: 0.0..=1.0,
             axis: Axis::Horizontal,
+            indeterminate: false,
+            travel: 0,
             class: StyleKind::deferred(ProgressStyle::default),
         }
     }
 
+    /// Should this bar bounce a segment back and forth, rather than showing its value?
+    pub const fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
     pub const fn range(mut self, range: RangeInclusive<f32>) -> Self {
         self.range = range;
         self
@@ -152,6 +169,12 @@ impl View for Progress {
         args
     }
 
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        let travel = self.travel;
+        *self = args;
+        self.travel = travel;
+    }
+
     fn layout(&mut self, _layout: Layout, space: Space) -> Size {
         let main = self.axis.main((20.0, 10.0));
         let size = self.axis.pack(main, 1.0);
@@ -174,6 +197,11 @@ impl View for Progress {
         };
         render.fill_with(Pixel::new(style.unfilled).fg(color));
 
+        if self.indeterminate {
+            self.draw_segment(&mut render, style);
+            return;
+        }
+
         let x = normalize(self.value, self.range.clone());
 
         let extent = axis.main::<f32>(rect.size());
@@ -195,6 +223,92 @@ impl View for Progress {
     }
 }
 
+impl Progress {
+    fn draw_segment(&mut self, render: &mut Render, style: ProgressStyle) {
+        let axis = self.axis;
+        let rect = render.rect();
+
+        let extent = axis.main::<i32>(rect.size());
+        let width = (style.segment_width as i32).clamp(1, extent.max(1));
+        let travel = extent - width;
+
+        // the speed is in cells, so the schedule changes with the size
+        if self.travel != travel {
+            self.travel = travel;
+            render.animation.remove(render.current);
+        }
+
+        let t = if travel > 0 && style.speed > 0.0 {
+            let there_and_back = Duration::from_secs_f32(2.0 * travel as f32 / style.speed);
+            let animation = Animation::new()
+                .repeat(true)
+                .round_trip(true)
+                .with(easing::linear)
+                .schedule(there_and_back)
+                .unwrap();
+            render.animate(render.current, animation)
+        } else {
+            0.0
+        };
+
+        let color = if render.is_hovered() {
+            style.filled_hovered.unwrap_or(style.filled_color)
+        } else {
+            style.filled_color
+        };
+
+        let start = (t * travel as f32).round() as i32;
+        let cross = axis.cross(rect.size() - 1);
+        let pixel = Pixel::new(style.filled).fg(color);
+        for x in start..start + width {
+            let pos: Pos2 = axis.pack(x, cross);
+            render.set(pos, pixel);
+        }
+    }
+}
+
 pub const fn progress(value: f32) -> Progress {
     Progress::new(value)
 }
+
+/// A progress bar for tasks of an unknown length
+///
+/// This bounces a segment back and forth, see [`ProgressStyle::segment_width`] and [`ProgressStyle::speed`].
+///
+/// It keeps moving as long as it is in the tree, driven by the time given to [`State::update`](crate::view::State::update)
+///
+/// ```rust
+/// # use too::{animation::Animations, layout::Axis, math::{pos2, vec2, Rect}, renderer::{Cell, Pixel, Surface}, view::{CroppedSurface, Palette, State, ViewExt as _}, views::{progress_indeterminate, ProgressStyle}};
+/// let palette = Palette::dark();
+/// let mut state = State::new(palette, Animations::new());
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+///
+/// // it takes a second to go from one end to the other
+/// let style = ProgressStyle {
+///     segment_width: 2,
+///     speed: 8.0,
+///     ..ProgressStyle::default(&palette, Axis::Horizontal)
+/// };
+///
+/// let mut frame = |state: &mut State, dt| {
+///     state.update(dt);
+///     state.build(rect, |ui| progress_indeterminate().style(style).show(ui));
+///
+///     let mut surface = Surface::new(rect.size());
+///     state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+///
+///     // where the segment starts
+///     let filled = Cell::Pixel(Pixel::new(style.filled).fg(style.filled_color));
+///     (0..10).find(|&x| surface.get(pos2(x, 0)) == Some(&filled))
+/// };
+///
+/// assert_eq!(frame(&mut state, 0.0), Some(0));
+/// assert_eq!(frame(&mut state, 0.5), Some(4));
+/// assert_eq!(frame(&mut state, 0.5), Some(8));
+///
+/// // and then it comes back
+/// assert_eq!(frame(&mut state, 0.25), Some(6));
+/// ```
+pub const fn progress_indeterminate() -> Progress {
+    Progress::new(0.0).indeterminate(true)
+}
@@ -0,0 +1,325 @@
+use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+
+use compact_str::CompactString;
+use unicode_segmentation::UnicodeSegmentation as _;
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{
+    math::{pos2, vec2, Rect, Size, Space},
+    renderer::{Attribute, Rgba, TextShape},
+    view::{Builder, Layout, Render, StyleKind, View},
+    Str,
+};
+
+use super::{LabelClass, LabelStyle, WrapMode};
+
+/// How a span of [`RichText`] is drawn
+///
+/// Anything that isn't set uses the [`LabelStyle`] of the text
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub fg: Option<Rgba>,
+    pub bg: Option<Rgba>,
+    pub attribute: Option<Attribute>,
+}
+
+impl SpanStyle {
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            attribute: None,
+        }
+    }
+
+    pub fn fg(mut self, fg: impl Into<Rgba>) -> Self {
+        self.fg = Some(fg.into());
+        self
+    }
+
+    pub fn bg(mut self, bg: impl Into<Rgba>) -> Self {
+        self.bg = Some(bg.into());
+        self
+    }
+
+    pub fn italic(self) -> Self {
+        self.attribute(Attribute::ITALIC)
+    }
+
+    pub fn bold(self) -> Self {
+        self.attribute(Attribute::BOLD)
+    }
+
+    pub fn underline(self) -> Self {
+        self.attribute(Attribute::UNDERLINE)
+    }
+
+    pub fn faint(self) -> Self {
+        self.attribute(Attribute::FAINT)
+    }
+
+    pub fn strikeout(self) -> Self {
+        self.attribute(Attribute::STRIKEOUT)
+    }
+
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        match &mut self.attribute {
+            Some(old) => *old |= attribute,
+            this @ None => *this = Some(attribute),
+        }
+        self
+    }
+}
+
+impl From<Rgba> for SpanStyle {
+    fn from(fg: Rgba) -> Self {
+        Self::new().fg(fg)
+    }
+}
+
+impl From<Attribute> for SpanStyle {
+    fn from(attribute: Attribute) -> Self {
+        Self::new().attribute(attribute)
+    }
+}
+
+/// Text made of spans, each with their own style
+///
+/// ```rust
+/// # use too::{renderer::Rgba, view::test::render_to_string, views::{RichText, SpanStyle, WrapMode}};
+/// let text = || {
+///     RichText::new()
+///         .push("OK", Rgba::hex("#0F0"))
+///         .push(" 3 failed", SpanStyle::new().fg("#F00").bold())
+/// };
+///
+/// assert_eq!(render_to_string(move |ui| ui.show(text())), "OK 3 failed");
+///
+/// // wrapping can break lines between spans, as well as between words
+/// let out = render_to_string(move |ui| {
+///     ui.exact_width(5, |ui| ui.show(text().wrap(WrapMode::Word)));
+/// });
+/// assert_eq!(out, "OK 3\nfaile\nd");
+/// ```
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+#[derive(Debug)]
+pub struct RichText {
+    spans: Vec<(CompactString, SpanStyle)>,
+    class: StyleKind<LabelClass, LabelStyle>,
+    wrap: WrapMode,
+}
+
+impl Default for RichText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RichText {
+    pub const fn new() -> Self {
+        Self {
+            spans: Vec::new(),
+            class: StyleKind::Deferred(LabelStyle::default),
+            wrap: WrapMode::None,
+        }
+    }
+
+    /// Add a span of text, drawn with this style
+    pub fn push(mut self, text: impl Into<Str>, style: impl Into<SpanStyle>) -> Self {
+        self.spans.push((text.into().into_inner(), style.into()));
+        self
+    }
+
+    pub const fn class(mut self, class: LabelClass) -> Self {
+        self.class = StyleKind::Deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: LabelStyle) -> Self {
+        self.class = StyleKind::Direct(style);
+        self
+    }
+
+    /// How the text should fit when it is wider than the view
+    ///
+    /// Lines can be broken between spans, as well as where the [`WrapMode`] would break them
+    pub const fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The width of all of the spans, on a single line
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(|(text, _)| text.width()).sum()
+    }
+
+    fn lines(&self, width: usize) -> Vec<Line> {
+        match self.wrap {
+            WrapMode::None => vec![self.single_line()],
+            _ if width == 0 => vec![],
+            _ if self.width() <= width => vec![self.single_line()],
+            WrapMode::Truncate => vec![self.truncated(width)],
+            WrapMode::Char => self.wrapped(width, |text| text.graphemes(true)),
+            WrapMode::Word => self.wrapped(width, |text| text.split_word_bounds()),
+        }
+    }
+
+    fn single_line(&self) -> Line {
+        let mut line = Line::default();
+        for (span, (text, _)) in self.spans.iter().enumerate() {
+            line.push(text, span);
+        }
+        line
+    }
+
+    fn truncated(&self, width: usize) -> Line {
+        let available = width.saturating_sub(WrapMode::ELLIPSIS.width());
+        let mut line = Line::default();
+        for (span, (text, _)) in self.spans.iter().enumerate() {
+            for grapheme in text.graphemes(true) {
+                if line.width + grapheme.width() > available {
+                    line.push(WrapMode::ELLIPSIS, span);
+                    return line;
+                }
+                line.push(grapheme, span);
+            }
+        }
+        line
+    }
+
+    // this is like `WrapMode::word_lines`, but each span is split separately so the lines can also break between them
+    fn wrapped<'a, I>(&'a self, width: usize, split: impl Fn(&'a str) -> I) -> Vec<Line>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let mut lines = vec![];
+        let mut line = Line::default();
+
+        for (span, (text, _)) in self.spans.iter().enumerate() {
+            for word in split(text) {
+                let is_space = word.chars().all(char::is_whitespace);
+                // spaces at the start of a wrapped line are dropped
+                if is_space && line.width == 0 && !lines.is_empty() {
+                    continue;
+                }
+
+                if line.width + word.width() <= width {
+                    line.push(word, span);
+                    continue;
+                }
+
+                if line.width > 0 {
+                    lines.push(std::mem::take(&mut line).trim_end());
+                }
+
+                if is_space {
+                    continue;
+                }
+
+                // the word doesn't fit on a line of its own, so it is broken up
+                for grapheme in word.graphemes(true) {
+                    if line.width > 0 && line.width + grapheme.width() > width {
+                        lines.push(std::mem::take(&mut line));
+                    }
+                    line.push(grapheme, span);
+                }
+            }
+        }
+
+        let line = line.trim_end();
+        if line.width > 0 {
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+// the pieces of each span on a line, with the index of their span
+#[derive(Default)]
+struct Line {
+    pieces: Vec<(String, usize)>,
+    width: usize,
+}
+
+impl Line {
+    fn push(&mut self, text: &str, span: usize) {
+        self.width += text.width();
+        match self.pieces.last_mut() {
+            Some((piece, index)) if *index == span => piece.push_str(text),
+            _ => self.pieces.push((text.to_string(), span)),
+        }
+    }
+
+    fn trim_end(mut self) -> Self {
+        while let Some((piece, _)) = self.pieces.last_mut() {
+            let trimmed = piece.trim_end();
+            self.width -= piece.width() - trimmed.width();
+            piece.truncate(trimmed.len());
+            if !piece.is_empty() {
+                break;
+            }
+            self.pieces.pop();
+        }
+        self
+    }
+}
+
+impl<'v> Builder<'v> for RichText {
+    type View = Self;
+}
+
+impl View for RichText {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        args
+    }
+
+    fn layout_hash(&self) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.wrap.hash(&mut hasher);
+        for (text, _) in &self.spans {
+            text.hash(&mut hasher);
+        }
+        Some(hasher.finish())
+    }
+
+    fn layout(&mut self, _layout: Layout, space: Space) -> Size {
+        if self.wrap == WrapMode::None || space.max.width.is_infinite() {
+            return space.fit(Size::new(self.width() as f32, 1.0));
+        }
+
+        let lines = self.lines(space.max.width as usize);
+        let width = lines.iter().map(|line| line.width).max().unwrap_or(0);
+        space.fit(Size::new(width as f32, lines.len() as f32))
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let style = match self.class {
+            StyleKind::Deferred(class) => (class)(render.palette),
+            StyleKind::Direct(style) => style,
+        };
+
+        let rect = render.local_rect();
+        for (y, line) in (0..).zip(self.lines(rect.width() as usize)) {
+            let mut x = 0;
+            for (piece, span) in &line.pieces {
+                let span = self.spans[*span].1;
+                let width = piece.width() as i32;
+
+                let mut shape = TextShape::new(piece)
+                    .fg(span.fg.unwrap_or(style.foreground))
+                    .maybe_attribute(span.attribute);
+                if let Some(bg) = span.bg {
+                    shape = shape.bg(bg);
+                }
+
+                render.crop(Rect::from_min_size(pos2(x, y), vec2(width, 1)), |render| {
+                    _ = render.text(shape)
+                });
+                x += width;
+            }
+        }
+    }
+}
@@ -1,62 +1,43 @@
 use crate::{
     layout::Axis,
-    math::{pos2, remap, vec2, Pos2, Rect, Vec2},
-    view::{
-        geom::{Size, Space},
-        Builder, Elements, EventCtx, Handled, Interest, Layout, Palette, Render, Ui, View,
-        ViewEvent,
-    },
-    Key, Pixel,
+    lock::{Lock, Shared},
+    math::{vec2, Pos2, Size, Space, Vec2},
+    view::{Builder, EventCtx, Handled, Interest, Layout, Ui, View, ViewEvent},
 };
 
-#[derive(Copy, Clone, Debug, Default, PartialEq)]
-enum ScrollAxis {
-    Horizontal,
-    #[default]
-    Vertical,
-    Both,
+/// A view that lets its children be larger than it, scrolling them with the mouse wheel
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Scrollable {
+    horizontal: bool,
+    vertical: bool,
 }
 
-impl ScrollAxis {
-    const fn horizontal(self, using: bool) -> Self {
-        match self {
-            Self::Vertical if using => Self::Both,
-            Self::Horizontal | Self::Both if !using => Self::Vertical,
-            _ => self,
-        }
-    }
-
-    const fn vertical(self, using: bool) -> Self {
-        match self {
-            Self::Horizontal if using => Self::Both,
-            Self::Vertical | Self::Both if !using => Self::Horizontal,
-            _ => self,
-        }
+impl Default for Scrollable {
+    fn default() -> Self {
+        scrollable()
     }
 }
 
-#[derive(Debug, Default)]
-pub struct Scrollable {
-    axis: ScrollAxis,
-}
-
 impl Scrollable {
+    /// Should this scroll horizontally?
     pub const fn horizontal(mut self, horizontal: bool) -> Self {
-        self.axis = self.axis.horizontal(horizontal);
+        self.horizontal = horizontal;
         self
     }
 
+    /// Should this scroll vertically?
     pub const fn vertical(mut self, vertical: bool) -> Self {
-        self.axis = self.axis.vertical(vertical);
+        self.vertical = vertical;
         self
     }
 
     const fn has_horizontal(&self) -> bool {
-        matches!(self.axis, ScrollAxis::Horizontal | ScrollAxis::Both)
+        self.horizontal
     }
 
     const fn has_vertical(&self) -> bool {
-        matches!(self.axis, ScrollAxis::Vertical | ScrollAxis::Both)
+        self.vertical
     }
 }
 
@@ -65,310 +46,189 @@ impl<'v> Builder<'v> for Scrollable {
 }
 
 #[derive(Debug, Default)]
-#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
-pub struct ScrollView {
-    opts: Scrollable,
-
-    // TODO don't store these inverted
-    pos: Vec2,
-
-    child_size: Vec2,
-    size: Vec2,
-
-    x_hovered: bool,
-    y_hovered: bool,
+struct ScrollState {
+    offset: Pos2,
+    content: Vec2,
+    viewport: Vec2,
 }
 
-impl ScrollView {
-    fn find_knob(&self, size: Vec2, axis: Axis) -> Pos2 {
-        let max: f32 = axis.main(self.child_size);
-        let (min, cross) = axis.unpack(self.size);
-        let pos = axis.main(self.pos);
-        let main = remap(pos, 0.0..=max, 0.0..=min);
-        axis.pack(main, cross)
+impl ScrollState {
+    fn clamp(&mut self) {
+        let max = (self.content - self.viewport).max(Vec2::ZERO);
+        self.offset = self.offset.clamp(Pos2::ZERO, max.to_pos2());
     }
+}
 
-    fn find_knob_horizontal(&self, size: Vec2) -> Option<Pos2> {
-        if !self.opts.has_horizontal() {
-            return None;
-        }
+/// The response for a [`Scrollable`]
+#[derive(Debug, Default)]
+pub struct ScrollableResponse {
+    state: Shared<Lock<ScrollState>>,
+}
 
-        // (self.x_pos != 0).then_some(pos2(x, size.y))
-        Some(self.find_knob(size, Axis::Horizontal))
+impl ScrollableResponse {
+    /// How far the children have been scrolled
+    pub fn offset(&self) -> Pos2 {
+        self.state.borrow().offset
     }
 
-    fn find_knob_vertical(&self, size: Vec2) -> Option<Pos2> {
-        if !self.opts.has_vertical() {
-            return None;
-        }
-
-        // (self.y_pos != 0).then_some(pos2(size.x, y))
-        Some(self.find_knob(size, Axis::Vertical))
+    /// Scroll so this position of the children is at the top-left of the view
+    ///
+    /// This is clamped to the size of the children when the view is next laid out
+    pub fn scroll_to(&self, pos: impl Into<Pos2>) {
+        self.state.borrow_mut().offset = pos.into();
     }
+}
 
-    fn draw_scrollbars(&self, render: &mut Render, local: Rect) {
-        let size = local.size() - 1;
-
-        let showing_horizontal = self.find_knob_horizontal(size);
-        if let Some(pos) = showing_horizontal {
-            let rect = Rect::from_min_size(
-                local.left_bottom() - pos2(0, 1), //
-                vec2(local.width(), 1),
-            );
-            let pixel = Elements::DASH_HORIZONTAL_LINE;
-            let pixel = Pixel::new(pixel)
-                .fg(Palette::current().outline)
-                .bg(Palette::current().surface);
-            render.surface.fill_rect_with(rect, pixel);
-
-            let pixel = if self.x_hovered {
-                Elements::MEDIUM_RECT
-            } else {
-                Elements::THICK_HORIZONTAL_LINE
-            };
-            let pixel = Pixel::new(pixel).fg(Palette::current().contrast);
-            render.surface.set(pos, pixel);
-        }
-
-        let showing_vertical = self.find_knob_vertical(size);
-        if let Some(pos) = showing_vertical {
-            let rect = Rect::from_min_size(
-                local.right_top() - pos2(1, 0), //
-                vec2(1, local.height()),
-            );
-            let pixel = Elements::DASH_VERTICAL_LINE;
-            let pixel = Pixel::new(pixel)
-                .fg(Palette::current().outline)
-                .bg(Palette::current().surface);
-            render.surface.fill_rect_with(rect, pixel);
-
-            let pixel = if self.y_hovered {
-                Elements::LARGE_RECT
-            } else {
-                Elements::THICK_VERTICAL_LINE
-            };
-            let pixel = Pixel::new(pixel).fg(Palette::current().contrast);
-            render.surface.set(pos, pixel);
-        };
-
-        if let (Some(h), Some(v)) = (showing_horizontal, showing_vertical) {
-            const MERGE: char = '┘';
-            const BOTH_SELECTED: char = '┛';
-            const PARTIAL_HORIZONATAL: char = '╸';
-            const PARTIAL_VERTICAL: char = '╹';
-
-            let bottom_right = local.right_bottom() - 1;
-
-            let cell = Pixel::new(MERGE)
-                .fg(Palette::current().outline)
-                .bg(Palette::current().surface);
-            render.surface.set(bottom_right, cell);
-
-            if h == bottom_right {
-                let cell = Pixel::new(PARTIAL_HORIZONATAL).fg(Palette::current().contrast);
-                render.surface.set(h, cell);
-            }
-
-            if v == bottom_right {
-                let cell = Pixel::new(PARTIAL_VERTICAL).fg(Palette::current().contrast);
-                render.surface.set(v, cell);
-            }
-
-            if h == v {
-                let cell = Pixel::new(BOTH_SELECTED).fg(Palette::current().contrast);
-                render.surface.set(h, cell);
-            }
-        }
-    }
+#[derive(Debug)]
+pub struct ScrollView {
+    opts: Scrollable,
+    state: Shared<Lock<ScrollState>>,
 }
 
 impl View for ScrollView {
     type Args<'v> = Scrollable;
-    type Response = ();
+    type Response = ScrollableResponse;
 
     fn create(args: Self::Args<'_>) -> Self {
         Self {
             opts: args,
-            ..Default::default()
+            state: Shared::default(),
         }
     }
 
-    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
         self.opts = args;
+        ScrollableResponse {
+            state: Shared::clone(&self.state),
+        }
     }
 
-    fn primary_axis(&self) -> Axis {
-        match self.opts.axis {
-            ScrollAxis::Horizontal => Axis::Horizontal,
-            ScrollAxis::Vertical | ScrollAxis::Both => Axis::Vertical,
-        }
+    fn interactive(&self) -> bool {
+        true
     }
 
     fn interests(&self) -> Interest {
-        Interest::MOUSE | Interest::FOCUS_INPUT
+        Interest::MOUSE_INSIDE
     }
 
-    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
-        let rect = ctx.rect();
-        let offset = rect.left_top();
-
-        let delta = match event {
-            ViewEvent::KeyInput { key, modifiers } => match key {
-                Key::Up => vec2(0, -1),
-                Key::Down => vec2(0, 1),
-                Key::Left => vec2(-1, 0),
-                Key::Right => vec2(1, 0),
-
-                Key::PageUp if modifiers.is_ctrl() => vec2(-self.size.x, 0),
-                Key::PageDown if modifiers.is_ctrl() => vec2(self.size.x, 0),
-                Key::PageUp => vec2(0, -self.size.y),
-                Key::PageDown => vec2(0, self.size.y),
-
-                Key::Home if modifiers.is_ctrl() => Vec2::MIN_X,
-                Key::End if modifiers.is_ctrl() => Vec2::MAX_X,
-                Key::Home => Vec2::MIN_Y,
-                Key::End => Vec2::MAX_Y,
-
-                _ => return Handled::Bubble,
-            },
-
-            ViewEvent::MouseScroll { delta, modifiers } if modifiers.is_ctrl_only() => {
-                vec2(delta.y, 0)
-            }
-
-            ViewEvent::MouseScroll { delta, .. } => vec2(0, -delta.y),
-
-            // slider knobs
-            ViewEvent::MouseMove { pos, .. } => {
-                let horizontal = self.find_knob_horizontal(rect.size() - vec2(1, 0));
-                let vertical = self.find_knob_vertical(rect.size() - vec2(0, 1));
-                self.y_hovered = vertical == Some(pos - offset + vec2(1, 0));
-                self.x_hovered = horizontal == Some(pos - offset + vec2(0, 1));
-                return Handled::Bubble;
-            }
-
-            ViewEvent::MouseDrag {
-                current,
-                modifiers,
-                inside: true,
-                ..
-            } if self.y_hovered => {
-                let (start, end) = (rect.top() as f32, rect.bottom() as f32 - 1.0);
-                let max = self.child_size.y as f32;
-                let pos = current.y as f32;
-
-                let y = match pos {
-                    pos if pos <= start => i32::MIN,
-                    pos if pos >= end => i32::MAX,
-                    pos => {
-                        let inverse = (pos - start) / (end - start) * (max - end);
-                        let pos = inverse.round() as i32;
-
-                        self.pos.y = -pos;
-                        self.pos.y = self.pos.y.min(0).max(-(max.abs() as i32 - self.size.y));
-                        return Handled::Sink;
-                    }
-                };
-
-                vec2(0, y)
-            }
-
-            ViewEvent::MouseDrag {
-                current,
-                modifiers,
-                inside: true,
-                ..
-            } if self.x_hovered => {
-                let (start, end) = (rect.left() as f32, rect.right() as f32 - 1.0);
-                let max = self.child_size.x as f32;
-                let pos = current.x as f32;
-
-                let x = match pos {
-                    pos if pos <= start => i32::MIN,
-                    pos if pos >= end => i32::MAX,
-                    pos => {
-                        let inverse = (pos - start) / (end - start) * (max - end);
-                        let pos = inverse.round() as i32;
-                        self.pos.x = -pos;
-                        self.pos.x = self.pos.x.min(0).max(-(max.abs() as i32 - self.size.x));
-                        return Handled::Sink;
-                    }
-                };
-
-                vec2(x, 0)
-            }
-
-            ViewEvent::MouseDrag {
-                delta,
-                modifiers,
-                inside: true,
-                ..
-            } => {
-                if modifiers.is_alt() {
-                    -delta
-                } else if modifiers.is_ctrl_only() {
-                    vec2(delta.x, 0)
-                } else {
-                    vec2(0, delta.y)
-                }
-            }
+    fn primary_axis(&self) -> Axis {
+        if self.opts.has_horizontal() && !self.opts.has_vertical() {
+            Axis::Horizontal
+        } else {
+            Axis::Vertical
+        }
+    }
 
-            _ => return Handled::Bubble,
+    fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
+        let ViewEvent::MouseScroll { delta, .. } = event else {
+            return Handled::Bubble;
         };
 
-        let Vec2 { x, y } = self.child_size;
+        // only take the parts of the scroll we can use, so a parent can use the rest
+        let delta = vec2(
+            if self.opts.has_horizontal() {
+                delta.x
+            } else {
+                0
+            },
+            if self.opts.has_vertical() { delta.y } else { 0 },
+        );
 
-        if self.opts.has_horizontal() {
-            self.pos.x = self
-                .pos
-                .x
-                .saturating_sub(delta.x)
-                .min(0)
-                .max(-(x.abs() - self.size.x));
-        }
+        let mut state = self.state.borrow_mut();
+        let old = state.offset;
+        state.offset += delta;
+        state.clamp();
 
-        if self.opts.has_vertical() {
-            self.pos.y = self
-                .pos
-                .y
-                .saturating_sub(delta.y)
-                .min(0)
-                .max(-(y.abs() - self.size.y));
+        if state.offset == old {
+            return Handled::Bubble;
         }
-
         Handled::Sink
     }
 
     fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
         layout.enable_clipping();
-        let node = layout.nodes.get_current();
 
-        let child_space = Space::new(space.max, Size::INFINITY);
-        let mut size = Size::ZERO;
+        let max = Size::new(
+            if self.opts.has_horizontal() {
+                f32::INFINITY
+            } else {
+                space.max.width
+            },
+            if self.opts.has_vertical() {
+                f32::INFINITY
+            } else {
+                space.max.height
+            },
+        );
+
+        let node = layout.nodes.get_current();
+        let mut content = Size::ZERO;
         for &child in &node.children {
-            size = size.max(layout.compute(child, child_space));
-            layout.set_position(child, self.pos);
+            content = content.max(layout.compute(child, Space::new(Size::ZERO, max)));
         }
 
-        self.child_size = size.into();
-        space.max
-    }
+        let size = space.fit(content);
 
-    fn draw(&mut self, mut render: Render) {
-        let local = render.local_rect();
-        self.size = local.size();
+        let mut state = self.state.borrow_mut();
+        state.content = content.into();
+        state.viewport = size.into();
+        state.clamp();
 
-        let node = render.nodes.get_current();
         for &child in &node.children {
-            render.draw(child)
+            layout.set_position(child, -state.offset);
         }
 
-        self.draw_scrollbars(&mut render, local);
+        size
     }
 }
 
+/// Create a [`Scrollable`] that scrolls vertically
+///
+/// ```rust
+/// # use too::{view::{State, Ui}, math::{pos2, vec2, Rect}, backend::{Event, Modifiers}, views::{scrollable, Scrollable}, view::ViewExt as _};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 3));
+///
+/// let scroll = |state: &mut State, delta| {
+///     state.event(&Event::MouseMove { pos: pos2(1, 1) });
+///     state.event(&Event::MouseScroll { delta, modifiers: Modifiers::NONE });
+/// };
+///
+/// let mut build = |state: &mut State, view: Scrollable, lines: usize| {
+///     state.build(rect, |ui| {
+///         view.show_children(ui, |ui| {
+///             ui.vertical(|ui| (0..lines).for_each(|i| _ = ui.label(format!("line {i}"))));
+///         }).into_inner().0
+///     })
+/// };
+///
+/// build(&mut state, scrollable(), 10);
+/// scroll(&mut state, vec2(0, 2));
+/// let resp = build(&mut state, scrollable(), 10);
+/// assert_eq!(resp.offset(), pos2(0, 2));
+///
+/// // it can be scrolled programmatically, but not past the end of its children
+/// resp.scroll_to(pos2(0, 100));
+/// assert_eq!(build(&mut state, scrollable(), 10).offset(), pos2(0, 7));
+///
+/// // if the children fit, there is nothing to scroll
+/// assert_eq!(build(&mut state, scrollable(), 2).offset(), pos2(0, 0));
+///
+/// // a horizontal scrollable ignores vertical scrolling
+/// let horizontal = scrollable().horizontal(true).vertical(false);
+/// build(&mut state, horizontal, 10);
+/// scroll(&mut state, vec2(0, 2));
+/// assert_eq!(build(&mut state, horizontal, 10).offset(), pos2(0, 0));
+///
+/// // each axis is set directly, so this doesn't scroll at all
+/// let neither = scrollable().vertical(false);
+/// build(&mut state, neither, 10);
+/// scroll(&mut state, vec2(0, 2));
+/// assert_eq!(build(&mut state, neither, 10).offset(), pos2(0, 0));
+/// ```
 pub const fn scrollable() -> Scrollable {
     Scrollable {
-        axis: ScrollAxis::Vertical,
+        horizontal: false,
+        vertical: true,
     }
 }
@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use crate::{
     renderer::Rgba,
     view::{Builder, Palette, StyleKind, Ui, View},
@@ -81,40 +83,52 @@ impl View for SelectedView {
     }
 
     fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
-        let resp = ui
-            .mouse_area(|ui| {
-                let style = match self.class {
-                    StyleKind::Deferred(style) => (style)(&ui.palette(), *args.value),
-                    StyleKind::Direct(style) => style,
-                };
-
-                let hovered = ui.is_hovered();
-                let fill = match (hovered, *args.value) {
-                    (false, true) => style.selected_background,
-                    (false, false) => style.background,
-                    (true, true) => style
-                        .hovered_background
-                        .unwrap_or(style.selected_background),
-                    (true, false) => style.hovered_background.unwrap_or(style.background),
-                };
-
-                let text = if hovered {
-                    style.hovered_text.unwrap_or(style.text_color)
-                } else {
-                    style.text_color
-                };
-
-                ui.background(fill, |ui| {
-                    ui.show(label(&self.label).style(LabelStyle { foreground: text }))
-                });
-            })
-            .flatten_left();
-
-        *args.value ^= resp.clicked();
-        resp.clicked()
+        let clicked = chip(ui, &self.label, self.class, *args.value);
+        *args.value ^= clicked;
+        clicked
     }
 }
 
+fn chip(
+    ui: &Ui,
+    text: &str,
+    class: StyleKind<SelectedClass, SelectedStyle>,
+    selected: bool,
+) -> bool {
+    let resp = ui
+        .mouse_area(|ui| {
+            let style = match class {
+                StyleKind::Deferred(style) => (style)(&ui.palette(), selected),
+                StyleKind::Direct(style) => style,
+            };
+
+            let hovered = ui.is_hovered();
+            let fill = match (hovered, selected) {
+                (false, true) => style.selected_background,
+                (false, false) => style.background,
+                (true, true) => style
+                    .hovered_background
+                    .unwrap_or(style.selected_background),
+                (true, false) => style.hovered_background.unwrap_or(style.background),
+            };
+
+            let text_color = if hovered {
+                style.hovered_text.unwrap_or(style.text_color)
+            } else {
+                style.text_color
+            };
+
+            ui.background(fill, |ui| {
+                ui.show(label(text).style(LabelStyle {
+                    foreground: text_color,
+                }))
+            });
+        })
+        .flatten_left();
+
+    resp.clicked()
+}
+
 pub fn selected(value: &mut bool, label: impl Into<Str>) -> Selected<'_> {
     Selected {
         value,
@@ -122,3 +136,151 @@ pub fn selected(value: &mut bool, label: impl Into<Str>) -> Selected<'_> {
         class: StyleKind::Deferred(SelectedStyle::default),
     }
 }
+
+#[derive(Debug)]
+#[must_use = "a view does nothing unless `ui.adhoc()` is called"]
+pub struct SelectedExclusive<'a, T> {
+    current: &'a mut Option<T>,
+    value: T,
+    label: Str,
+    allow_none: bool,
+    class: StyleKind<SelectedClass, SelectedStyle>,
+}
+
+impl<'a, T> SelectedExclusive<'a, T> {
+    /// Allow clicking the already-selected chip to clear the selection
+    ///
+    /// By default this is `false`, so clicking it again does nothing
+    pub const fn allow_none(mut self, allow_none: bool) -> Self {
+        self.allow_none = allow_none;
+        self
+    }
+
+    pub const fn class(mut self, class: SelectedClass) -> Self {
+        self.class = StyleKind::Deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: SelectedStyle) -> Self {
+        self.class = StyleKind::Direct(style);
+        self
+    }
+}
+
+impl<'v, T: PartialEq + 'static> Builder<'v> for SelectedExclusive<'v, T> {
+    type View = SelectedExclusiveView<T>;
+}
+
+pub struct SelectedExclusiveView<T> {
+    label: Str,
+    class: StyleKind<SelectedClass, SelectedStyle>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> std::fmt::Debug for SelectedExclusiveView<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectedExclusiveView")
+            .field("label", &self.label)
+            .field("class", &self.class)
+            .finish()
+    }
+}
+
+impl<T> View for SelectedExclusiveView<T>
+where
+    T: PartialEq + 'static,
+{
+    type Args<'v> = SelectedExclusive<'v, T>;
+    type Response = bool;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            label: args.label,
+            class: args.class,
+            _marker: PhantomData,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        let selected = args.current.as_ref() == Some(&args.value);
+        if !chip(ui, &self.label, self.class, selected) {
+            return false;
+        }
+
+        match (selected, args.allow_none) {
+            (true, true) => *args.current = None,
+            (true, false) => return false,
+            (false, _) => *args.current = Some(args.value),
+        }
+        true
+    }
+}
+
+/// A [`selected`] chip that is part of a group where only one can be active
+///
+/// Clicking it sets `current` to `value`, which deselects its siblings that share `current`.
+///
+/// Clicking the already-selected chip does nothing, unless [`SelectedExclusive::allow_none`] is set.
+///
+/// The response is whether `current` changed this frame.
+///
+/// ```rust
+/// # use too::{view::State, math::{pos2, vec2, Rect}, backend::{Event, Modifiers, MouseButton}, views::selected_exclusive};
+/// let mut state = State::default();
+/// let mut current = Some(1);
+///
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+/// let mut build = |state: &mut State, current: &mut Option<i32>, allow_none| {
+///     state.build(rect, |ui| {
+///         ui.horizontal(|ui| {
+///             let a = ui.show(selected_exclusive(current, 1, "a").allow_none(allow_none));
+///             let b = ui.show(selected_exclusive(current, 2, "b").allow_none(allow_none));
+///             a.into_inner() | b.into_inner()
+///         })
+///         .into_inner()
+///     })
+/// };
+/// let click = |state: &mut State, x| {
+///     for down in [true, false] {
+///         let pos = pos2(x, 0);
+///         state.event(&Event::MouseMove { pos });
+///         let (button, modifiers) = (MouseButton::Primary, Modifiers::NONE);
+///         state.event(&Event::MouseButtonChanged { pos, button, down, modifiers });
+///     }
+/// };
+///
+/// // views are only created on their first frame, so their chips show up on the next one
+/// build(&mut state, &mut current, false);
+/// assert!(!build(&mut state, &mut current, false));
+///
+/// // selecting the other chip deselects the first one
+/// click(&mut state, 2);
+/// assert!(build(&mut state, &mut current, false));
+/// assert_eq!(current, Some(2));
+///
+/// // clicking the selected chip again is a no-op
+/// click(&mut state, 2);
+/// assert!(!build(&mut state, &mut current, false));
+/// assert_eq!(current, Some(2));
+///
+/// // unless clearing the selection is allowed
+/// click(&mut state, 2);
+/// assert!(build(&mut state, &mut current, true));
+/// assert_eq!(current, None);
+/// ```
+pub fn selected_exclusive<T>(
+    current: &mut Option<T>,
+    value: T,
+    label: impl Into<Str>,
+) -> SelectedExclusive<'_, T>
+where
+    T: PartialEq,
+{
+    SelectedExclusive {
+        current,
+        value,
+        label: label.into(),
+        allow_none: false,
+        class: StyleKind::Deferred(SelectedStyle::default),
+    }
+}
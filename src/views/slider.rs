@@ -2,6 +2,7 @@ use core::f32;
 use std::ops::RangeInclusive;
 
 use crate::{
+    backend::Key,
     layout::Axis,
     math::{denormalize, inverse_lerp, lerp, normalize, Pos2, Size, Space},
     renderer::{Pixel, Rgba},
@@ -87,17 +88,18 @@ pub fn slider(value: &mut f32) -> Slider {
         value,
         range: 0.0..=1.0,
         clickable: true,
+        steps: 0,
         axis: Axis::Horizontal,
         class: StyleKind::Deferred(SliderStyle::small_rounded),
     }
 }
 
-// TODO step by
 #[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
 pub struct Slider<'v> {
     value: &'v mut f32,
     range: RangeInclusive<f32>,
     clickable: bool,
+    steps: usize,
     axis: Axis,
     class: StyleKind<SliderClass, SliderStyle>,
 }
@@ -113,6 +115,44 @@ impl<'v> Slider<'v> {
         self
     }
 
+    /// Snap the value to this many evenly spaced stops, including both ends of the range
+    ///
+    /// Dragging, clicking and the keyboard all snap to the nearest stop.
+    ///
+    /// `0` or `1` stops makes the slider continuous, which is the default
+    ///
+    /// ```rust
+    /// # use too::{backend::{Event, Key, Modifiers}, math::{pos2, vec2, Rect}, view::State, views::slider};
+    /// let mut state = State::new(Default::default(), Default::default());
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+    ///
+    /// let mut value = 0.3;
+    /// let id = state.build(rect, |ui| ui.show(slider(&mut value).steps(5)).id());
+    /// state.build(rect, |ui| {
+    ///     ui.set_focus(id);
+    ///     ui.show(slider(&mut value).steps(5));
+    /// });
+    ///
+    /// let right = Event::KeyPressed { key: Key::Right, modifiers: Modifiers::NONE };
+    /// state.event(&right);
+    /// let changed = state.build(rect, |ui| ui.show(slider(&mut value).steps(5)).changed());
+    /// assert!(changed);
+    /// // it snapped to 0.25, and then moved to the next stop
+    /// assert_eq!(value, 0.5);
+    ///
+    /// state.event(&Event::KeyPressed { key: Key::PageUp, modifiers: Modifiers::NONE });
+    /// state.build(rect, |ui| ui.show(slider(&mut value).steps(5)));
+    /// assert_eq!(value, 0.75);
+    ///
+    /// state.event(&Event::KeyPressed { key: Key::PageDown, modifiers: Modifiers::NONE });
+    /// state.build(rect, |ui| ui.show(slider(&mut value).steps(5)));
+    /// assert_eq!(value, 0.5);
+    /// ```
+    pub const fn steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
     pub const fn axis(mut self, axis: Axis) -> Self {
         self.axis = axis;
         self
@@ -141,19 +181,124 @@ impl<'v> Builder<'v> for Slider<'v> {
     type View = SliderView;
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct SliderResponse {
+    changed: bool,
+}
+
+impl SliderResponse {
+    /// Was the value changed by dragging, clicking or a key press?
+    pub const fn changed(&self) -> bool {
+        self.changed
+    }
+}
+
+/// A slider between two values
+///
+/// When focused:
+///
+/// | key | action |
+/// | --- | --- |
+/// | `Left` / `Down` | decrease by a step |
+/// | `Right` / `Up` | increase by a step |
+/// | `PageDown` | decrease by a tenth of the range (or a step, if thats larger) |
+/// | `PageUp` | increase by a tenth of the range (or a step, if thats larger) |
+///
+/// `Up` and `Down` are used by a vertical slider, `Left` and `Right` by a horizontal one. A vertical slider has its largest value at the top.
+///
+/// ```rust
+/// # use too::{backend::{Event, Key, Modifiers, MouseButton}, math::{pos2, vec2, Rect}, renderer::{Color, Surface}, view::{CroppedSurface, Palette, State, Ui, ViewId}, views::slider};
+/// let mut state = State::new(Default::default(), Default::default());
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(1, 20));
+/// let show = |ui: &Ui, value: &mut f32| -> ViewId {
+///     ui.exact_size(vec2(1, 20), |ui| ui.show(slider(value).vertical().steps(5)).id()).into_inner()
+/// };
+///
+/// let mut value = 0.5;
+/// let id = state.build(rect, |ui| show(ui, &mut value));
+/// state.build(rect, |ui| {
+///     ui.set_focus(id);
+///     show(ui, &mut value);
+/// });
+///
+/// state.event(&Event::KeyPressed { key: Key::Up, modifiers: Modifiers::NONE });
+/// state.build(rect, |ui| show(ui, &mut value));
+/// assert_eq!(value, 0.75);
+///
+/// // the top of it is the largest value, and the bottom is the smallest
+/// for (y, expected) in [(0, 1.0), (19, 0.0)] {
+///     let pos = pos2(0, y);
+///     state.event(&Event::MouseMove { pos });
+///     for down in [true, false] {
+///         state.event(&Event::MouseButtonChanged { pos, button: MouseButton::Primary, down, modifiers: Modifiers::NONE });
+///     }
+///     state.build(rect, |ui| show(ui, &mut value));
+///     assert_eq!(value, expected);
+/// }
+///
+/// // so the knob is drawn at the bottom, too
+/// let mut surface = Surface::new(rect.size());
+/// state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+/// let fg = |y| surface.get(pos2(0, y)).unwrap().fg();
+/// assert_eq!(fg(19), Color::Set(Palette::dark().primary));
+/// assert_eq!(fg(0), Color::Set(Palette::dark().outline));
+/// ```
+///
+/// Without [`steps`](Slider::steps), a step is a hundredth of the range
 #[derive(Debug)]
 pub struct SliderView {
     value: f32,
     changed: bool,
     range: RangeInclusive<f32>,
     clickable: bool,
+    steps: usize,
     axis: Axis,
     class: StyleKind<SliderClass, SliderStyle>,
 }
 
+impl SliderView {
+    // the normalized distance between each stop, if there are any
+    fn step(&self) -> Option<f32> {
+        (self.steps > 1).then(|| 1.0 / (self.steps - 1) as f32)
+    }
+
+    fn snap(&self, normalized: f32) -> f32 {
+        let normalized = normalized.clamp(0.0, 1.0);
+        match self.step() {
+            Some(step) => (normalized / step).round() * step,
+            None => normalized,
+        }
+    }
+
+    fn set_normalized(&mut self, normalized: f32) {
+        let value = denormalize(self.snap(normalized), self.range.clone());
+        if value != self.value {
+            self.value = value;
+            self.changed = true;
+        }
+    }
+
+    fn nudge(&mut self, key: Key) -> Handled {
+        let step = self.step().unwrap_or(0.01);
+        let page = step * (0.1 / step).round().max(1.0);
+
+        let delta = match (key, self.axis) {
+            (Key::Left, Axis::Horizontal) | (Key::Down, Axis::Vertical) => -step,
+            (Key::Right, Axis::Horizontal) | (Key::Up, Axis::Vertical) => step,
+            (Key::PageDown, _) => -page,
+            (Key::PageUp, _) => page,
+            _ => return Handled::Bubble,
+        };
+
+        let current = normalize(self.value, self.range.clone());
+        self.set_normalized(self.snap(current) + delta);
+        Handled::Sink
+    }
+}
+
 impl View for SliderView {
     type Args<'v> = Slider<'v>;
-    type Response = (); // TODO `changed`
+    type Response = SliderResponse;
 
     fn create(args: Self::Args<'_>) -> Self {
         Self {
@@ -161,6 +306,7 @@ impl View for SliderView {
             value: *args.value,
             range: args.range.clone(),
             clickable: args.clickable,
+            steps: args.steps,
             axis: args.axis,
             class: args.class,
         }
@@ -169,14 +315,17 @@ impl View for SliderView {
     fn update(&mut self, args: Self::Args<'_>, _ui: &Ui) -> Self::Response {
         self.range = args.range.clone();
         self.clickable = args.clickable;
+        self.steps = args.steps;
         self.axis = args.axis;
         self.class = args.class;
 
+        let changed = self.changed;
         if std::mem::take(&mut self.changed) {
             *args.value = self.value;
         } else if self.value != *args.value {
             self.value = *args.value;
         }
+        SliderResponse { changed }
     }
 
     fn interactive(&self) -> bool {
@@ -184,11 +333,15 @@ impl View for SliderView {
     }
 
     fn interests(&self) -> Interest {
-        Interest::MOUSE
+        Interest::MOUSE | Interest::FOCUS_INPUT
     }
 
     fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
         let pos = match event {
+            ViewEvent::KeyInput { key, modifiers } if modifiers.is_none() => {
+                return self.nudge(key)
+            }
+
             ViewEvent::MouseDrag {
                 current,
                 inside: true,
@@ -207,10 +360,14 @@ impl View for SliderView {
         let end = self.axis.main(rect.right_bottom() - 1);
         let pos = self.axis.main(pos);
 
-        let value = inverse_lerp(start, end, pos).unwrap_or(0.0);
-        self.value = denormalize(value, self.range.clone());
+        // a vertical slider has its largest value at the top
+        let value = match self.axis {
+            Axis::Horizontal => inverse_lerp(start, end, pos),
+            Axis::Vertical => inverse_lerp(end, start, pos),
+        };
+        self.set_normalized(value.unwrap_or(0.0));
 
-        self.changed = true;
+        ctx.input.set_focus(Some(ctx.current));
         Handled::Sink
     }
 
@@ -236,7 +393,10 @@ impl View for SliderView {
 
         let extent: f32 = self.axis.main(render.rect().size());
         let value = normalize(self.value, self.range.clone());
-        let x = lerp(0.0, extent - 1.0, value);
+        let x = match self.axis {
+            Axis::Horizontal => lerp(0.0, extent - 1.0, value),
+            Axis::Vertical => lerp(extent - 1.0, 0.0, value),
+        };
         let pos: Pos2 = self.axis.pack(x, 0.0);
 
         let knob_color = if render.is_hovered() {
@@ -1,140 +1,284 @@
-use core::f32;
-
 use crate::{
     layout::Axis,
-    math::{inverse_lerp, Rect},
+    math::{Pos2, Rect, Size, Space, Vec2},
+    renderer::{Pixel, Rgba},
     view::{
-        geom::{Flex, Size, Space},
-        views::list,
-        Builder, Elements, Interest, Layout, Render, Ui, View, ViewExt as _, ViewId,
+        Builder, Elements, EventCtx, Handled, Interest, Layout, Palette, Render, StyleKind, Ui,
+        View, ViewEvent,
     },
-    Pixel,
 };
 
+pub type SplitClass = fn(&Palette, Axis) -> SplitStyle;
+
+/// The style of a [`SplitView`]'s divider
+#[derive(Debug, Copy, Clone)]
+pub struct SplitStyle {
+    /// The character used for the divider
+    pub divider: char,
+    /// The character used for the divider when its hovered or held
+    pub divider_grab: Option<char>,
+    /// The color of the divider
+    pub divider_color: Rgba,
+    /// The color of the divider when its hovered or held
+    pub divider_grab_color: Option<Rgba>,
+    /// The background color of the divider
+    pub background: Option<Rgba>,
+}
+
+impl SplitStyle {
+    pub fn default(palette: &Palette, axis: Axis) -> Self {
+        Self {
+            divider: axis.cross((
+                Elements::HORIZONTAL_LINE, //
+                Elements::VERTICAL_LINE,
+            )),
+            divider_grab: Some(axis.cross((
+                Elements::THICK_HORIZONTAL_LINE,
+                Elements::THICK_VERTICAL_LINE,
+            ))),
+            divider_color: palette.outline,
+            divider_grab_color: Some(palette.secondary),
+            background: None,
+        }
+    }
+}
+
 #[derive(Debug)]
-pub struct Splitter {
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Split<'a> {
     axis: Axis,
+    ratio: &'a mut f32,
+    min_size: f32,
+    class: StyleKind<SplitClass, SplitStyle>,
 }
 
-impl<'v> Builder<'v> for Splitter {
-    type View = Splitter;
+impl<'a> Split<'a> {
+    /// The smallest size either pane can be dragged to, along the axis
+    ///
+    /// By default this is `1`
+    pub fn min_size(mut self, min_size: u16) -> Self {
+        self.min_size = min_size as f32;
+        self
+    }
+
+    pub const fn class(mut self, class: SplitClass) -> Self {
+        self.class = StyleKind::Deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: SplitStyle) -> Self {
+        self.class = StyleKind::Direct(style);
+        self
+    }
 }
 
-impl View for Splitter {
-    type Args<'v> = Self;
-    type Response = ();
+impl<'v> Builder<'v> for Split<'v> {
+    type View = SplitView;
+}
+
+/// Two panes laid out along an axis, with a draggable divider between them
+///
+/// This takes all of the space available to it. Its first child is the first pane, and its second child is the second pane.
+#[derive(Debug)]
+pub struct SplitView {
+    axis: Axis,
+    ratio: f32,
+    min_size: f32,
+    divider: i32,
+    held: bool,
+    dragged: bool,
+    class: StyleKind<SplitClass, SplitStyle>,
+}
+
+impl SplitView {
+    // where the first pane ends, leaving at least `min_size` for each pane
+    fn first_extent(&self, available: f32, ratio: f32) -> f32 {
+        let min = self.min_size.min(available / 2.0);
+        (available * ratio).round().clamp(min, available - min)
+    }
+
+    fn on_divider(&self, rect: Rect, pos: Pos2) -> bool {
+        rect.contains(pos) && self.axis.main::<i32>(pos - rect.left_top()) == self.divider
+    }
+}
+
+impl View for SplitView {
+    type Args<'v> = Split<'v>;
+    type Response = f32;
 
     fn create(args: Self::Args<'_>) -> Self {
-        args
+        Self {
+            axis: args.axis,
+            ratio: args.ratio.clamp(0.0, 1.0),
+            min_size: args.min_size,
+            divider: 0,
+            held: false,
+            dragged: false,
+            class: args.class,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        self.axis = args.axis;
+        self.min_size = args.min_size;
+        self.class = args.class;
+
+        if std::mem::take(&mut self.dragged) {
+            *args.ratio = self.ratio;
+        } else {
+            self.ratio = args.ratio.clamp(0.0, 1.0);
+        }
+        self.ratio
+    }
+
+    fn primary_axis(&self) -> Axis {
+        self.axis
     }
 
     fn interests(&self) -> Interest {
         Interest::MOUSE
     }
 
-    fn flex(&self) -> Flex {
-        Flex::Tight(1.0)
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        let rect = ctx.rect();
+
+        match event {
+            // releasing the mouse after a drag isn't a click, so any other mouse movement ends the drag
+            ViewEvent::MouseMove { .. } => {
+                self.held = false;
+                Handled::Bubble
+            }
+
+            ViewEvent::MouseHeld {
+                pos, inside: true, ..
+            } => {
+                self.held = self.on_divider(rect, pos);
+                if self.held {
+                    Handled::Sink
+                } else {
+                    Handled::Bubble
+                }
+            }
+
+            ViewEvent::MouseDrag { current, .. } if self.held => {
+                let available = self.axis.main::<f32>(rect.size()) - 1.0;
+                if available > 0.0 {
+                    let pos = self.axis.main::<i32>(current - rect.left_top()) as f32;
+                    self.ratio = self.first_extent(available, pos / available) / available;
+                    self.dragged = true;
+                }
+                Handled::Sink
+            }
+
+            ViewEvent::MouseClicked { .. } if self.held => {
+                self.held = false;
+                Handled::Sink
+            }
+
+            _ => Handled::Bubble,
+        }
     }
 
-    fn layout(&mut self, _: Layout, space: Space) -> Size {
-        // TODO axis
-        // TODO properties
-        let size = match self.axis {
-            Axis::Horizontal => Size::new(1.0, f32::INFINITY),
-            Axis::Vertical => Size::new(f32::INFINITY, 1.0),
-        };
-        space.constrain_min(size)
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let node = layout.nodes.get_current();
+        let (main, cross) = self.axis.unpack_size(space.max.finite_or_zero());
+
+        let available = (main - 1.0).max(0.0);
+        let first = self.first_extent(available, self.ratio);
+        let second = available - first;
+        self.divider = first as i32;
+
+        for (i, &child) in node.children.iter().enumerate() {
+            let (offset, extent) = match i {
+                0 => (0.0, first),
+                1 => (first + 1.0, second),
+                _ => {
+                    layout.remove(child);
+                    continue;
+                }
+            };
+            layout.compute(child, Space::tight(self.axis.pack_size(extent, cross)));
+            layout.set_position(child, self.axis.pack::<_, Pos2>(offset, 0.0));
+        }
+
+        self.axis.pack(main, cross)
     }
 
     fn draw(&mut self, mut render: Render) {
-        // TODO properties
-        let ch = match self.axis {
-            Axis::Horizontal => Elements::THICK_VERTICAL_LINE,
-            Axis::Vertical => Elements::THICK_HORIZONTAL_LINE,
+        let node = render.nodes.get_current();
+        for &child in &node.children {
+            render.draw(child)
+        }
+
+        let style = match self.class {
+            StyleKind::Deferred(style) => (style)(render.palette, self.axis),
+            StyleKind::Direct(style) => style,
+        };
+
+        let grab = self.held || self.on_divider(render.rect(), render.mouse_pos());
+
+        let (divider, color) = if grab {
+            (
+                style.divider_grab.unwrap_or(style.divider),
+                style.divider_grab_color.unwrap_or(style.divider_color),
+            )
+        } else {
+            (style.divider, style.divider_color)
         };
 
-        // let fg = if render.layout.is_hovered() {
-        //     render.theme.info
-        // } else {
-        //     render.theme.outline
-        // };
-        render
-            .surface
-            .fill_with(Pixel::new(ch).fg(render.theme.outline));
+        let mut pixel = Pixel::new(divider).fg(color);
+        if let Some(bg) = style.background {
+            pixel = pixel.bg(bg)
+        }
+
+        let divider = Rect::from_min_size(
+            self.axis.pack::<_, Pos2>(self.divider, 0),
+            self.axis
+                .pack::<_, Vec2>(1, self.axis.cross::<i32>(render.rect().size())),
+        );
+        render.crop(divider, |render| {
+            render.fill_with(pixel);
+        });
     }
 }
 
-pub fn horizontal_split<L, R>(
-    ui: &Ui,
-    ratio: &mut f32,
-    // this: &mut T,
-    left: impl FnMut(&Ui) -> L,
-    right: impl FnMut(&Ui) -> R,
-)
-// -> (Response<L>, Response<R>)
-where
-    L: 'static,
-    R: 'static,
-{
-    split(ui, Axis::Horizontal, ratio, left, right)
-}
+/// A pane of a [`SplitView`]
+///
+/// This clips its children to the pane
+#[derive(Debug)]
+pub(crate) struct Pane;
 
-pub fn vertical_split<L, R>(
-    ui: &Ui,
-    ratio: &mut f32,
-
-    left: impl FnMut(&Ui) -> L,
-    right: impl FnMut(&Ui) -> R,
-)
-// -> (Response<L>, Response<R>)
-where
-    L: 'static,
-    R: 'static,
-{
-    split(ui, Axis::Vertical, ratio, left, right)
+impl<'v> Builder<'v> for Pane {
+    type View = Self;
 }
 
-pub fn split<L, R>(
-    ui: &Ui,
-    axis: Axis,
-    ratio: &mut f32,
-
-    mut left: impl FnMut(&Ui) -> L,
-    mut right: impl FnMut(&Ui) -> R,
-)
-// -> (Response<L>, Response<R>)
-where
-    L: 'static,
-    R: 'static,
-{
-    let rect = ui.current_available_rect();
-
-    let (main, cross) = match axis {
-        Axis::Horizontal => rect.split_horizontal(1, *ratio),
-        Axis::Vertical => rect.split_vertical(1, *ratio),
-    };
-
-    let show = |ui: &Ui| {
-        let (main, cross) = (Size::from(main.size()), Size::from(cross.size()));
-
-        let left = ui.flex(|ui| left(ui));
-        let resp = ui
-            .mouse_area(|ui| ui.show(Splitter { axis }))
-            .flatten_left();
-        let right = ui.flex(|ui| right(ui));
-
-        if let Some(pos) = resp.dragged().map(|c| c.current()) {
-            let (x, y, t) = match axis {
-                Axis::Horizontal => (rect.left(), rect.right(), pos.x),
-                Axis::Vertical => (rect.top(), rect.bottom(), pos.y),
-            };
-            let (x, y, t) = (x as f32, y as f32, t as f32);
-            *ratio = inverse_lerp(x, y, t).unwrap_or(0.5);
-        }
+impl View for Pane {
+    type Args<'v> = Self;
+    type Response = ();
+
+    fn create(args: Self::Args<'_>) -> Self {
+        args
+    }
 
-        (left, right)
-    };
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        layout.enable_clipping();
+        self.default_layout(layout, space.loosen());
+        space.max
+    }
+}
 
-    let resp = list().axis(axis).gap(0).show_children(ui, show);
-    // resp.remap_inner()
+/// Split the available space between two panes, along an `axis`
+///
+/// The `ratio` is how much of the space the first pane takes, in the range of `0.0..=1.0`. Dragging the divider updates it.
+///
+/// The response is the current ratio.
+///
+/// See [`Ui::split_view`](crate::view::Ui::split_view) for a shorthand that creates the panes for you.
+pub fn split_view(axis: Axis, ratio: &mut f32) -> Split<'_> {
+    Split {
+        axis,
+        ratio,
+        min_size: 1.0,
+        class: StyleKind::Deferred(SplitStyle::default),
+    }
 }
@@ -0,0 +1,269 @@
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{
+    layout::Axis,
+    math::{pos2, vec2, Rect, Size, Space},
+    renderer::{Rgba, TextShape},
+    view::{
+        Builder, EventCtx, Handled, Interest, Layout, Palette, Render, StyleKind, Ui, View,
+        ViewEvent,
+    },
+    Str,
+};
+
+pub type TabsClass = fn(&Palette) -> TabsStyle;
+
+/// The style of a [`Tabs`]'s tab strip
+#[derive(Debug, Copy, Clone)]
+pub struct TabsStyle {
+    /// The color of the labels
+    pub foreground: Rgba,
+    /// The background color of the tabs
+    pub background: Rgba,
+    /// The color of the active tab's label
+    pub active_foreground: Rgba,
+    /// The background color of the active tab
+    pub active_background: Rgba,
+    /// The color of a label when its hovered
+    pub hovered: Option<Rgba>,
+}
+
+impl TabsStyle {
+    pub fn default(palette: &Palette) -> Self {
+        Self {
+            foreground: palette.foreground,
+            background: palette.surface,
+            active_foreground: palette.background,
+            active_background: palette.primary,
+            hovered: Some(palette.secondary),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Tabs<'a> {
+    active: &'a mut usize,
+    labels: Vec<Str>,
+    class: StyleKind<TabsClass, TabsStyle>,
+}
+
+impl<'a> Tabs<'a> {
+    pub const fn class(mut self, class: TabsClass) -> Self {
+        self.class = StyleKind::Deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: TabsStyle) -> Self {
+        self.class = StyleKind::Direct(style);
+        self
+    }
+}
+
+impl<'v> Builder<'v> for Tabs<'v> {
+    type View = TabsView;
+}
+
+/// The response from a [`Tabs`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TabsResponse {
+    active: usize,
+    changed: bool,
+}
+
+impl TabsResponse {
+    /// The index of the active tab
+    pub const fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Did the active tab change this frame?
+    pub const fn changed(&self) -> bool {
+        self.changed
+    }
+}
+
+/// A strip of tabs, with the content of the active tab below it
+///
+/// Each child is the content of the tab with the same index, so each tab keeps its own views.
+///
+/// Only the active tab's content is laid out and drawn, the others aren't laid out, so they don't get any events.
+/// If the focus was inside of a tab when it was hidden, the focus is removed
+///
+/// ```rust
+/// # use std::cell::Cell;
+/// # use too::view::{test::type_text, State, Ui};
+/// let mut state = State::default();
+/// let active = Cell::new(0);
+/// let mut focus = true;
+/// let mut app = |ui: &Ui| {
+///     let mut text = String::new();
+///     let mut tab = active.get();
+///     ui.tabs(&mut tab, &["one", "two"], |ui, tab| {
+///         if tab == 0 {
+///             let resp = ui.text_input(std::mem::take(&mut focus));
+///             text = resp.into_inner().data().to_string();
+///         }
+///     });
+///     text
+/// };
+///
+/// assert_eq!(type_text(&mut state, "a", &mut app), "a");
+/// assert!(state.focus().is_some());
+///
+/// // switching to the second tab hides the focused input, so it doesn't get the key
+/// active.set(1);
+/// assert_eq!(type_text(&mut state, "b", &mut app), "a");
+/// assert_eq!(state.focus(), None);
+/// ```
+#[derive(Debug)]
+pub struct TabsView {
+    active: usize,
+    labels: Vec<Str>,
+    clicked: Option<usize>,
+    class: StyleKind<TabsClass, TabsStyle>,
+}
+
+impl TabsView {
+    // each label has a column of padding on either side of it
+    fn tab_widths(&self) -> impl Iterator<Item = i32> + '_ {
+        self.labels.iter().map(|label| label.width() as i32 + 2)
+    }
+
+    fn tab_at(&self, x: i32) -> Option<usize> {
+        let mut start = 0;
+        self.tab_widths().position(|width| {
+            start += width;
+            x < start
+        })
+    }
+}
+
+impl View for TabsView {
+    type Args<'v> = Tabs<'v>;
+    type Response = TabsResponse;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        *args.active = (*args.active).min(args.labels.len().saturating_sub(1));
+        Self {
+            active: *args.active,
+            labels: args.labels,
+            clicked: None,
+            class: args.class,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        self.labels = args.labels;
+        self.class = args.class;
+
+        if let Some(clicked) = self.clicked.take() {
+            *args.active = clicked;
+        }
+        *args.active = (*args.active).min(self.labels.len().saturating_sub(1));
+
+        // this can also be changed by the application
+        let changed = std::mem::replace(&mut self.active, *args.active) != *args.active;
+        TabsResponse {
+            active: self.active,
+            changed,
+        }
+    }
+
+    fn primary_axis(&self) -> Axis {
+        Axis::Vertical
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        Interest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        match event {
+            // only the tab strip switches tabs
+            ViewEvent::MouseClicked {
+                pos, inside: true, ..
+            } if pos.y == ctx.rect().top() => match self.tab_at(pos.x - ctx.rect().left()) {
+                Some(index) => {
+                    self.clicked = Some(index);
+                    Handled::Sink
+                }
+                None => Handled::Bubble,
+            },
+            _ => Handled::Bubble,
+        }
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let strip = self.tab_widths().sum::<i32>() as f32;
+
+        let node = layout.nodes.get_current();
+        let content_space = space.shrink(Size::new(0.0, 1.0)).loosen();
+        let mut content = Size::ZERO;
+        for (i, &child) in node.children.iter().enumerate() {
+            if i != self.active {
+                layout.remove(child);
+                continue;
+            }
+            content = layout.compute(child, content_space);
+            layout.set_position(child, pos2(0, 1));
+        }
+
+        space.fit(Size::new(content.width.max(strip), content.height + 1.0))
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let style = match self.class {
+            StyleKind::Deferred(class) => (class)(render.palette),
+            StyleKind::Direct(style) => style,
+        };
+
+        let rect = render.rect();
+        let mouse = render.mouse_pos();
+        let hovered = (mouse.y == rect.top())
+            .then(|| self.tab_at(mouse.x - rect.left()))
+            .flatten();
+
+        let mut x = 0;
+        for (i, width) in self.tab_widths().enumerate() {
+            let (fg, bg) = match () {
+                _ if i == self.active => (style.active_foreground, style.active_background),
+                _ if Some(i) == hovered => {
+                    (style.hovered.unwrap_or(style.foreground), style.background)
+                }
+                _ => (style.foreground, style.background),
+            };
+
+            let tab = Rect::from_min_size(pos2(x, 0), vec2(width, 1));
+            render.crop(tab, |render| _ = render.fill_bg(bg));
+
+            let label = Rect::from_min_size(pos2(x + 1, 0), vec2(width - 2, 1));
+            render.crop(label, |render| {
+                render.text(TextShape::new(&self.labels[i]).fg(fg));
+            });
+            x += width;
+        }
+
+        let node = render.nodes.get_current();
+        if let Some(&child) = node.children.get(self.active) {
+            render.draw(child);
+        }
+    }
+}
+
+/// A strip of tabs, with the content of the active tab below it
+///
+/// Each child is the content of a tab, in the same order as the labels. Clicking a tab makes it `active`.
+///
+/// See [`Ui::tabs`](crate::view::Ui::tabs) for a shorthand, which gives each tab its own child
+pub fn tabs<T: Into<Str>>(active: &mut usize, labels: impl IntoIterator<Item = T>) -> Tabs<'_> {
+    Tabs {
+        active,
+        labels: labels.into_iter().map(Into::into).collect(),
+        class: StyleKind::Deferred(TabsStyle::default),
+    }
+}
@@ -1,13 +1,14 @@
 use core::f32;
+use std::{borrow::Cow, ops::Range};
 
 use unicode_segmentation::UnicodeSegmentation as _;
-use unicode_width::UnicodeWidthStr as _;
+use unicode_width::{UnicodeWidthChar as _, UnicodeWidthStr as _};
 
 use crate::{
     backend::Key,
     layout::Axis,
     lock::{Lock, Ref, RefMapped, Shared},
-    math::{pos2, Size, Space},
+    math::{pos2, Pos2, Size, Space},
     renderer::{Attribute, Grapheme, Pixel, Rgba},
     view::{
         Builder, EventCtx, Handled, Interest, Layout, Palette, Render, StyleKind, Ui, View,
@@ -46,12 +47,14 @@ impl TextInputStyle {
     }
 }
 
-// TODO multi-line
 #[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
 pub struct TextInput<'a> {
     enabled: bool,
+    multiline: bool,
     placeholder: Option<&'a str>,
     initial: Option<&'a str>,
+    mask: Option<char>,
+    filter: Option<fn(char) -> bool>,
     class: StyleKind<TextInputClass, TextInputStyle>,
 }
 
@@ -61,6 +64,156 @@ impl<'a> TextInput<'a> {
         self
     }
 
+    /// Should this input allow multiple lines?
+    ///
+    /// When enabled, `Enter` inserts a new line and `Ctrl+Enter` submits the text.
+    /// `Up` and `Down` move between the (wrapped) lines, and the view is as tall as its lines.
+    ///
+    /// ```rust
+    /// # use too::{view::State, math::{pos2, vec2, Rect}, backend::{Event, Key, Modifiers}, views::text_input};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(4, 10));
+    ///
+    /// let mut build = |state: &mut State| {
+    ///     state.build(rect, |ui| {
+    ///         let resp = ui.show(text_input().multiline(true));
+    ///         ui.set_focus(resp.id());
+    ///         let data = resp.data().to_string();
+    ///         (data, resp.submitted().map(String::from), ui.rect_of(resp.id()))
+    ///     })
+    /// };
+    /// let mut press = |state: &mut State, key, modifiers| state.event(&Event::KeyPressed { key, modifiers });
+    ///
+    /// build(&mut state);
+    /// for key in [Key::Char('a'), Key::Char('b'), Key::Enter, Key::Char('c')] {
+    ///     press(&mut state, key, Modifiers::NONE);
+    /// }
+    /// // Up keeps the column, so this is inserted between 'a' and 'b'
+    /// press(&mut state, Key::Up, Modifiers::NONE);
+    /// press(&mut state, Key::Char('x'), Modifiers::NONE);
+    ///
+    /// let (data, _, _) = build(&mut state);
+    /// assert_eq!(data, "axb\nc");
+    ///
+    /// // lines wider than the view are wrapped, and the view grows to fit them
+    /// press(&mut state, Key::End, Modifiers::NONE);
+    /// for ch in "defg".chars() {
+    ///     press(&mut state, Key::Char(ch), Modifiers::NONE);
+    /// }
+    /// build(&mut state);
+    /// let (data, _, rect) = build(&mut state);
+    /// assert_eq!(data, "axb\ncdefg");
+    /// assert_eq!(rect.unwrap().height(), 3);
+    ///
+    /// // ctrl+enter submits it
+    /// press(&mut state, Key::Enter, Modifiers::CTRL);
+    /// let (_, submitted, _) = build(&mut state);
+    /// assert_eq!(submitted.as_deref(), Some("axb\ncdefg"));
+    /// ```
+    ///
+    /// Lines are wrapped by how wide they are when drawn, so wide chars take up two columns
+    ///
+    /// ```rust
+    /// # use too::{view::State, math::{pos2, vec2, Rect}, backend::{Event, Key, Modifiers}, views::text_input};
+    /// let mut state = State::default();
+    /// let rect = Rect::from_min_size(pos2(0, 0), vec2(4, 10));
+    ///
+    /// let mut build = |state: &mut State| {
+    ///     state.build(rect, |ui| {
+    ///         let resp = ui.show(text_input().multiline(true));
+    ///         ui.set_focus(resp.id());
+    ///         ui.rect_of(resp.id())
+    ///     })
+    /// };
+    ///
+    /// build(&mut state);
+    /// for ch in "日本語".chars() {
+    ///     state.event(&Event::KeyPressed { key: Key::Char(ch), modifiers: Modifiers::NONE });
+    /// }
+    /// build(&mut state);
+    /// // "日本" fills the first line, so "語" is wrapped onto the next one
+    /// assert_eq!(build(&mut state).unwrap().height(), 2);
+    /// ```
+    pub const fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Draw every character as this one, e.g. for a password
+    ///
+    /// Only the drawing is masked, [`TextInputResponse::data`] is still the real text.
+    /// The mask has to be a single column wide so the cursor lines up, otherwise `*` is used.
+    /// Nothing can be copied or cut from a masked input
+    ///
+    /// ```rust
+    /// # use too::{view::test::{type_text, render_to_string}, view::State, views::text_input};
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| {
+    ///     let resp = ui.show(text_input().mask('•'));
+    ///     ui.set_focus(resp.id());
+    ///     let data = resp.data().to_string();
+    ///     data
+    /// };
+    /// assert_eq!(type_text(&mut state, "hunter2", app), "hunter2");
+    ///
+    /// let out = render_to_string(|ui| _ = ui.show(text_input().initial("hunter2").mask('•')));
+    /// assert_eq!(out, "•••••••");
+    /// ```
+    ///
+    /// Each masked char is a single column, even a wide one, so clicking and moving the cursor follows the mask
+    ///
+    /// ```rust
+    /// # use too::{math::pos2, view::{test::{click_at, type_text}, State}, views::text_input};
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| {
+    ///     let resp = ui.show(text_input().multiline(true).initial("日本語").mask('*'));
+    ///     ui.set_focus(resp.id());
+    ///     let data = resp.data().to_string();
+    ///     data
+    /// };
+    ///
+    /// // this is the second `*`, so its after the first char
+    /// click_at(&mut state, pos2(1, 0), app);
+    /// assert_eq!(type_text(&mut state, "x", app), "日x本語");
+    /// ```
+    pub const fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Only accept the characters this returns `true` for
+    ///
+    /// This applies to typed and pasted text, and new lines, any other characters are dropped
+    ///
+    /// ```rust
+    /// # use too::{backend::Event, view::{test::type_text, State}, views::text_input};
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| {
+    ///     let resp = ui.show(text_input().filter(|c| c.is_ascii_digit()));
+    ///     ui.set_focus(resp.id());
+    ///     let data = resp.data().to_string();
+    ///     data
+    /// };
+    /// assert_eq!(type_text(&mut state, "1a2b3", app), "123");
+    ///
+    /// state.event(&Event::Paste(String::from("4 five 5")));
+    /// assert_eq!(type_text(&mut state, "", app), "12345");
+    ///
+    /// // a new line is filtered too, so `Enter` only adds one if the filter accepts '\n'
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| {
+    ///     let resp = ui.show(text_input().multiline(true).filter(|c| c.is_ascii_digit()));
+    ///     ui.set_focus(resp.id());
+    ///     let data = resp.data().to_string();
+    ///     data
+    /// };
+    /// assert_eq!(type_text(&mut state, "1\n2", app), "12");
+    /// ```
+    pub const fn filter(mut self, filter: fn(char) -> bool) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
     pub fn placeholder(mut self, text: &'a str) -> Self {
         self.placeholder = Some(text);
         self
@@ -97,6 +250,19 @@ impl TextInputResponse {
         self.state.borrow().changed
     }
 
+    /// The index of the char the cursor is before
+    ///
+    /// A wide char takes up two cells, clicking either of them puts the cursor before it
+    ///
+    /// ```rust
+    /// # use too::{math::pos2, view::{test::{click_at, type_text}, State}};
+    /// let mut state = State::default();
+    /// let app = |ui: &too::view::Ui| ui.text_input(true).into_inner().cursor();
+    ///
+    /// assert_eq!(type_text(&mut state, "日本", app), 2);
+    /// assert_eq!(click_at(&mut state, pos2(1, 0), app), 0);
+    /// assert_eq!(click_at(&mut state, pos2(2, 0), app), 1);
+    /// ```
     pub fn cursor(&self) -> usize {
         self.state.borrow().cursor
     }
@@ -123,7 +289,7 @@ impl TextInputResponse {
         let mut state = self.state.borrow_mut();
         state.clear();
         state.buf = data.to_string();
-        state.cursor = state.buf.width();
+        state.cursor = state.end();
         state.selection = state.cursor;
     }
 }
@@ -132,6 +298,7 @@ impl TextInputResponse {
 pub struct TextInputView {
     state: InputState,
     enabled: bool,
+    multiline: bool,
     class: StyleKind<TextInputClass, TextInputStyle>,
 }
 
@@ -140,11 +307,16 @@ impl View for TextInputView {
     type Response = TextInputResponse;
 
     fn create(args: Self::Args<'_>) -> Self {
-        let mut input = Inner::default();
+        let mut input = Inner {
+            multiline: args.multiline,
+            mask: args.mask.map(Inner::fit_mask),
+            filter: args.filter,
+            ..Inner::default()
+        };
 
         if let Some(initial) = args.initial {
             input.buf = initial.to_string();
-            input.cursor = input.buf.width();
+            input.cursor = input.end();
             input.selection = input.cursor;
         };
         input.placeholder = args.placeholder.map(ToString::to_string);
@@ -154,13 +326,21 @@ impl View for TextInputView {
                 inner: Shared::new(Lock::new(input)),
             },
             enabled: args.enabled,
+            multiline: args.multiline,
             class: args.class,
         }
     }
 
     fn update(&mut self, args: Self::Args<'_>, _ui: &Ui) -> Self::Response {
         self.enabled = args.enabled;
+        self.multiline = args.multiline;
         self.class = args.class;
+        {
+            let mut g = self.state.inner.borrow_mut();
+            g.multiline = args.multiline;
+            g.mask = args.mask.map(Inner::fit_mask);
+            g.filter = args.filter;
+        }
 
         let mut resp = TextInputResponse {
             state: Shared::clone(&self.state.inner),
@@ -191,6 +371,17 @@ impl View for TextInputView {
         let mut state = self.state.inner.borrow_mut();
         state.submitted = false;
 
+        if let ViewEvent::Paste(data) = &event {
+            self.paste(&mut state, data);
+            return Handled::Sink;
+        }
+
+        if self.multiline {
+            if let Some(resp) = Self::multiline_mouse_event(&mut state, &event, &ctx) {
+                return resp;
+            }
+        }
+
         if let ViewEvent::MouseClicked {
             pos, inside: true, ..
         } = event
@@ -202,11 +393,11 @@ impl View for TextInputView {
             let diff = rect.width() - state.cursor.min(state.selection) as i32;
 
             if diff > 0 {
-                state.cursor = (left - 1).max(0).min(state.buf.width() as i32) as usize;
+                state.cursor = index_at_column(&state.display(), left - 1);
             } else {
                 let abs = (left - diff).unsigned_abs() as usize;
                 state.cursor = abs;
-                state.cursor = state.cursor.max(0).min(state.buf.width());
+                state.cursor = state.cursor.max(0).min(state.end());
             }
 
             state.selection = state.cursor;
@@ -239,8 +430,8 @@ impl View for TextInputView {
             cursor += delta;
             selection += delta;
 
-            state.cursor = (cursor as usize).max(0).min(state.buf.width());
-            state.selection = (selection as usize).max(0).min(state.buf.width());
+            state.cursor = (cursor as usize).max(0).min(state.end());
+            state.selection = (selection as usize).max(0).min(state.end());
 
             return Handled::Sink;
         }
@@ -265,6 +456,26 @@ impl View for TextInputView {
 
             Key::Delete if !state.has_selection() => state.delete_word(Direction::Forward),
 
+            Key::Char('c') if modifiers.is_ctrl_only() && state.has_selection() => {
+                if let Some(selection) = state.selection_buffer().filter(|_| state.mask.is_none()) {
+                    ctx.clipboard().set(selection)
+                }
+            }
+            Key::Char('x') if modifiers.is_ctrl_only() && state.has_selection() => {
+                if state.mask.is_some() {
+                    return Handled::Sink;
+                }
+                if let Some(selection) = state.selection_buffer() {
+                    ctx.clipboard().set(selection)
+                }
+                state.delete_selection()
+            }
+            Key::Char('v') if modifiers.is_ctrl_only() => {
+                if let Some(data) = ctx.clipboard().get() {
+                    self.paste(&mut state, &data)
+                }
+            }
+
             Key::Char(ch) if !modifiers.is_ctrl() => {
                 state.overwrite_selection(ch.encode_utf8(&mut buf))
             }
@@ -292,6 +503,10 @@ impl View for TextInputView {
             Key::Home => state.move_to_start(),
             Key::End => state.move_to_end(),
 
+            Key::Up if self.multiline && modifiers.is_none() => state.move_line(-1),
+            Key::Down if self.multiline && modifiers.is_none() => state.move_line(1),
+
+            Key::Enter if self.multiline && !modifiers.is_ctrl() => state.newline(),
             Key::Enter => state.submitted = true,
             _ => return Handled::Bubble,
         }
@@ -304,7 +519,14 @@ impl View for TextInputView {
     }
 
     fn layout(&mut self, _layout: Layout, space: Space) -> Size {
-        space.fit(Size::new(f32::INFINITY, 1.0))
+        let size = space.fit(Size::new(f32::INFINITY, 1.0));
+        if !self.multiline {
+            return size;
+        }
+
+        let mut state = self.state.inner.borrow_mut();
+        state.wrap = (size.width as usize).max(1);
+        space.fit(Size::new(size.width, state.lines().len() as f32))
     }
 
     fn draw(&mut self, mut render: Render) {
@@ -325,11 +547,100 @@ impl View for TextInputView {
             return;
         }
 
+        if self.multiline {
+            Self::draw_lines(self.enabled, &style, &state, &mut render);
+            return;
+        }
+
         Self::draw_text(self.enabled, &style, &state, &mut render);
     }
 }
 
 impl TextInputView {
+    fn paste(&self, state: &mut Inner, data: &str) {
+        let data = data.replace('\r', "");
+        // a single line input can't hold the line breaks, so they become spaces
+        let data = if self.multiline {
+            data
+        } else {
+            data.replace('\n', " ")
+        };
+        state.overwrite_selection(&data);
+    }
+
+    fn multiline_mouse_event(
+        state: &mut Inner,
+        event: &ViewEvent,
+        ctx: &EventCtx,
+    ) -> Option<Handled> {
+        let local = |pos: Pos2| pos - ctx.rect().left_top();
+        match *event {
+            ViewEvent::MouseClicked {
+                pos, inside: true, ..
+            } => {
+                state.cursor = state.index_at(local(pos));
+                state.reset_select();
+            }
+            ViewEvent::MouseDrag {
+                start,
+                current,
+                inside: true,
+                ..
+            } => {
+                state.cursor = state.index_at(local(start));
+                state.selection = state.index_at(local(current));
+            }
+            _ => return None,
+        }
+        Some(Handled::Sink)
+    }
+
+    fn draw_lines(enabled: bool, style: &TextInputStyle, state: &Inner, render: &mut Render) {
+        let fg = if enabled {
+            style.foreground
+        } else {
+            style.disabled_foreground
+        };
+
+        let data = state.display();
+        let lines = state.lines();
+        for (y, line) in (0..).zip(&lines) {
+            let start = str_indices::chars::to_byte_idx(&data, line.start);
+            let end = str_indices::chars::to_byte_idx(&data, line.end);
+
+            let mut x = 0;
+            for grapheme in data[start..end].graphemes(true) {
+                let cell = Grapheme::new(grapheme)
+                    .fg(fg)
+                    .attribute(style.text_attribute);
+                render.local_space(|render| {
+                    render.set(pos2(x, y), cell);
+                });
+                x += grapheme.width() as i32;
+            }
+        }
+
+        let mut highlight = |index: usize, color: Rgba| {
+            let pos = state.position_of(&lines, index);
+            render.local_space(|render| {
+                render.patch(pos, |cell| cell.set_bg(color));
+            });
+        };
+
+        if state.has_selection() {
+            let (start, end) = (
+                state.cursor.min(state.selection),
+                state.cursor.max(state.selection),
+            );
+            for index in start..end {
+                highlight(index, style.selection);
+            }
+            highlight(state.selection, style.cursor);
+        } else {
+            highlight(state.cursor, style.cursor);
+        }
+    }
+
     fn draw_placeholder(enabled: bool, style: &TextInputStyle, state: &Inner, render: &mut Render) {
         let Some(placeholder) = state.placeholder.as_deref().filter(|c| !c.is_empty()) else {
             Self::draw_cursors(0, style, state, render);
@@ -379,15 +690,16 @@ impl TextInputView {
             style.disabled_foreground
         };
 
+        let data = state.display();
         let (offset, start, end) = Self::fit_cursor(
-            &state.buf, //
+            &data, //
             state.cursor,
             state.selection,
             rect.width() - 1,
         );
 
         let mut x = offset;
-        for grapheme in state.buf[start..end].graphemes(true) {
+        for grapheme in data[start..end].graphemes(true) {
             let cell = Grapheme::new(grapheme)
                 .fg(fg)
                 .attribute(style.text_attribute);
@@ -409,9 +721,10 @@ impl TextInputView {
             return;
         }
 
-        // FIXME we have to find the start of the continuation run so we can highlight the main cell
-        let cursor = state.cursor as i32 + offset;
-        let selection = state.selection as i32 + offset;
+        // a wide char is highlighted from its first cell
+        let data = state.display();
+        let cursor = column_of(&data, state.cursor) + offset;
+        let selection = column_of(&data, state.selection) + offset;
 
         if state.has_selection() {
             for x in selection.min(cursor)..selection.max(cursor) {
@@ -462,9 +775,130 @@ struct Inner {
     selection: usize, // char indices
     changed: bool,
     submitted: bool,
+    multiline: bool,
+    wrap: usize, // the width lines are wrapped at, from the last layout
+    mask: Option<char>,
+    filter: Option<fn(char) -> bool>,
 }
 
 impl Inner {
+    /// The index of the end of the buffer
+    fn end(&self) -> usize {
+        self.buf.chars().count()
+    }
+
+    // the mask has to be a single column, so each char is a column
+    fn fit_mask(mask: char) -> char {
+        if mask.width() == Some(1) {
+            mask
+        } else {
+            '*'
+        }
+    }
+
+    /// The text that is drawn, with every char (other than new lines) masked
+    fn display(&self) -> Cow<'_, str> {
+        let Some(mask) = self.mask else {
+            return Cow::Borrowed(&self.buf);
+        };
+
+        self.buf
+            .chars()
+            .map(|c| if c == '\n' { c } else { mask })
+            .collect()
+    }
+
+    /// Drop any chars that the filter doesn't accept
+    fn accept<'a>(&self, data: &'a str) -> Cow<'a, str> {
+        match self.filter {
+            Some(filter) if !data.chars().all(filter) => {
+                Cow::Owned(data.chars().filter(|&c| filter(c)).collect())
+            }
+            _ => Cow::Borrowed(data),
+        }
+    }
+
+    /// The visual lines of the buffer, as char indices
+    ///
+    /// A line is broken at each new line, and wrapped when it's wider than the view.
+    /// A full line is followed by another (possibly empty) line, so there is always room for the cursor.
+    fn lines(&self) -> Vec<Range<usize>> {
+        let wrap = self.wrap.max(1) as i32;
+        let data = self.display();
+        let mut lines = vec![];
+        let mut offset = 0;
+        for line in data.split('\n') {
+            // this is measured the same way the lines are drawn
+            let (mut start, mut end, mut width) = (offset, offset, 0);
+            for grapheme in line.graphemes(true) {
+                let w = grapheme.width() as i32;
+                if width + w > wrap && end > start {
+                    lines.push(start..end);
+                    (start, width) = (end, 0);
+                }
+                end += grapheme.chars().count();
+                width += w;
+            }
+            lines.push(start..end);
+            if width >= wrap {
+                lines.push(end..end);
+            }
+            offset = end + 1;
+        }
+        lines
+    }
+
+    /// The visual line and column for this index
+    fn position_of(&self, lines: &[Range<usize>], index: usize) -> Pos2 {
+        // the end of a wrapped line is the start of the next one
+        let y = lines
+            .iter()
+            .zip(lines.iter().skip(1).map(Some).chain([None]))
+            .position(|(line, next)| {
+                index < line.end
+                    || (index == line.end && next.is_none_or(|next| next.start != line.end))
+            })
+            .unwrap_or(lines.len().saturating_sub(1));
+
+        // this is measured the same way the lines are drawn
+        let start = lines.get(y).map_or(0, |line| line.start);
+        let line = self
+            .display()
+            .chars()
+            .skip(start)
+            .take(index.saturating_sub(start))
+            .map(char_width)
+            .sum();
+        pos2(line, y as i32)
+    }
+
+    /// The index for this visual line and column, clamped to the line
+    fn index_at(&self, pos: Pos2) -> usize {
+        let lines = self.lines();
+        let y = (pos.y.max(0) as usize).min(lines.len() - 1);
+        let line = &lines[y];
+        let data = self.display();
+        let start = str_indices::chars::to_byte_idx(&data, line.start);
+        let end = str_indices::chars::to_byte_idx(&data, line.end);
+        line.start + index_at_column(&data[start..end], pos.x)
+    }
+
+    fn move_line(&mut self, delta: i32) {
+        let lines = self.lines();
+        let pos = self.position_of(&lines, self.selection);
+        let y = pos.y + delta;
+        if y < 0 || y >= lines.len() as i32 {
+            return;
+        }
+
+        self.cursor = self.index_at(pos2(pos.x, y));
+        self.reset_select();
+    }
+
+    fn newline(&mut self) {
+        self.overwrite_selection("\n");
+    }
+
     const fn has_selection(&self) -> bool {
         self.selection != self.cursor
     }
@@ -500,7 +934,7 @@ impl Inner {
     }
 
     fn move_to_end(&mut self) {
-        self.cursor = self.buf.width();
+        self.cursor = self.end();
         self.reset_select();
     }
 
@@ -510,11 +944,15 @@ impl Inner {
     }
 
     fn append(&mut self, data: &str) {
+        let data = self.accept(data);
         let w = data.width();
         if w == 0 {
             return;
         }
+        self.insert(&data);
+    }
 
+    fn insert(&mut self, data: &str) {
         let index = str_indices::chars::to_byte_idx(&self.buf, self.cursor);
         self.buf.insert_str(index, data);
 
@@ -525,6 +963,10 @@ impl Inner {
     }
 
     fn overwrite_selection(&mut self, data: &str) {
+        // a rejected char shouldn't delete the selection it would've replaced
+        if !data.is_empty() && self.accept(data).is_empty() {
+            return;
+        }
         self.delete_selection();
         self.append(data);
     }
@@ -536,7 +978,7 @@ impl Inner {
                     &self.buf, //
                     self.cursor,
                 )
-                .unwrap_or(self.buf.width())
+                .unwrap_or(self.end())
             }
             Direction::Backward => WordSep::find_prev_word(
                 &self.buf, //
@@ -550,7 +992,7 @@ impl Inner {
 
     // FIXME this has to skip to the end of the grapheme cluster
     fn move_cursor(&mut self, delta: i32) {
-        let total = self.buf.width() as i32;
+        let total = self.end() as i32;
         let mut cursor = self.selection as i32;
         let mut remaining = delta.abs();
 
@@ -574,7 +1016,7 @@ impl Inner {
 
     /// char index
     fn select_range(&mut self, pos: i32) {
-        self.selection = pos.max(0).min(self.buf.width() as i32) as usize
+        self.selection = pos.max(0).min(self.end() as i32) as usize
     }
 
     fn select_start(&mut self) {
@@ -582,7 +1024,7 @@ impl Inner {
     }
 
     fn select_end(&mut self) {
-        self.select_range(self.buf.width() as i32);
+        self.select_range(self.end() as i32);
     }
 
     fn select_word(&mut self, dir: Direction) {
@@ -753,11 +1195,106 @@ impl WordSep {
     }
 }
 
+/// Create a [`TextInput`]
+///
+/// Pasted text is inserted all at once. A single line input turns any new lines into spaces.
+///
+/// ```rust
+/// # use too::{view::State, math::{pos2, vec2, Rect}, backend::Event, views::text_input};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+///
+/// let mut build = |state: &mut State| {
+///     state.build(rect, |ui| {
+///         let resp = ui.show(text_input());
+///         ui.set_focus(resp.id());
+///         let data = resp.data().to_string();
+///         data
+///     })
+/// };
+///
+/// build(&mut state);
+/// state.event(&Event::Paste(String::from("hello\r\nworld")));
+/// assert_eq!(build(&mut state), "hello world");
+/// ```
+///
+/// `Ctrl+C`, `Ctrl+X` and `Ctrl+V` copy, cut and paste with the [`State`](crate::view::State)'s clipboard.
+///
+/// By default the terminal quits on `Ctrl+C` before the input sees it, so copying needs [`ctrl_c_quits`](crate::RunConfig::ctrl_c_quits) to be `false`.
+///
+/// ```rust
+/// # use too::{view::{ClipboardHandle, State}, math::{pos2, vec2, Rect}, backend::{Clipboard, Event, Key, Modifiers}, views::text_input};
+/// #[derive(Default)]
+/// struct Memory(Option<String>);
+///
+/// impl Clipboard for Memory {
+///     fn get(&mut self) -> Option<String> {
+///         self.0.clone()
+///     }
+///     fn set(&mut self, data: &str) {
+///         self.0 = Some(data.to_string())
+///     }
+/// }
+///
+/// let clipboard = ClipboardHandle::new(Memory::default());
+/// let mut state = State::default();
+/// state.set_clipboard(clipboard.clone());
+///
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 1));
+/// let mut build = |state: &mut State| {
+///     state.build(rect, |ui| {
+///         let resp = ui.show(text_input().initial("hello"));
+///         ui.set_focus(resp.id());
+///         let data = resp.data().to_string();
+///         data
+///     })
+/// };
+/// let mut press = |state: &mut State, key, modifiers| state.event(&Event::KeyPressed { key, modifiers });
+///
+/// build(&mut state);
+/// press(&mut state, Key::End, Modifiers::NONE);
+/// press(&mut state, Key::Home, Modifiers::SHIFT);
+///
+/// press(&mut state, Key::Char('c'), Modifiers::CTRL);
+/// assert_eq!(clipboard.get().as_deref(), Some("hello"));
+/// assert_eq!(build(&mut state), "hello");
+///
+/// press(&mut state, Key::Char('x'), Modifiers::CTRL);
+/// assert_eq!(build(&mut state), "");
+///
+/// press(&mut state, Key::Char('v'), Modifiers::CTRL);
+/// press(&mut state, Key::Char('v'), Modifiers::CTRL);
+/// assert_eq!(build(&mut state), "hellohello");
+/// ```
 pub fn text_input<'a>() -> TextInput<'a> {
     TextInput {
         enabled: true,
+        multiline: false,
         placeholder: None,
         initial: None,
+        mask: None,
+        filter: None,
         class: StyleKind::deferred(TextInputStyle::default),
     }
 }
+
+fn char_width(ch: char) -> i32 {
+    ch.width().unwrap_or(0) as i32
+}
+
+/// The column the char at `index` starts at
+fn column_of(data: &str, index: usize) -> i32 {
+    data.chars().take(index).map(char_width).sum()
+}
+
+/// The index of the char that covers this column, both cells of a wide char are the same index
+fn index_at_column(data: &str, column: i32) -> usize {
+    let mut x = 0;
+    for (i, ch) in data.chars().enumerate() {
+        x += char_width(ch);
+        if column < x {
+            return i;
+        }
+    }
+    data.chars().count()
+}
@@ -25,6 +25,11 @@ pub struct ToggleStyle {
 
     pub on_knob_hovered: Option<Rgba>,
     pub off_knob_hovered: Option<Rgba>,
+
+    /// How long the knob takes to slide from one end to the other
+    ///
+    /// A zero duration moves the knob instantly
+    pub transition: Duration,
 }
 
 impl ToggleStyle {
@@ -39,6 +44,7 @@ impl ToggleStyle {
             off_knob_color: palette.secondary,
             on_knob_hovered: None,
             off_knob_hovered: None,
+            transition: Duration::from_millis(120),
         }
     }
 
@@ -146,6 +152,11 @@ impl<'v> Builder<'v> for ToggleSwitch<'v> {
 pub struct ToggleSwitchView {
     value: bool,
     changed: bool,
+    // the value the knob is moving towards
+    target: bool,
+    // where the knob was when it started moving, and where it was last drawn
+    from: f32,
+    position: f32,
     axis: Axis,
     class: StyleKind<ToggleClass, ToggleStyle>,
 }
@@ -158,6 +169,9 @@ impl View for ToggleSwitchView {
         Self {
             value: *args.value,
             changed: false,
+            target: *args.value,
+            from: *args.value as u8 as f32,
+            position: *args.value as u8 as f32,
             axis: args.axis,
             class: args.class,
         }
@@ -184,19 +198,11 @@ impl View for ToggleSwitchView {
         Interest::MOUSE_INSIDE
     }
 
-    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+    fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
         match event {
             ViewEvent::MouseClicked { .. } => {
                 self.value = !self.value;
                 self.changed = true;
-
-                ctx.animation.add_once(ctx.current, || {
-                    Animation::new()
-                        .oneshot(true)
-                        .with(easing::sine_in_out)
-                        .schedule(Duration::from_millis(150))
-                        .unwrap()
-                });
             }
 
             ViewEvent::MouseDrag { delta, .. }
@@ -205,14 +211,6 @@ impl View for ToggleSwitchView {
             {
                 self.value = !self.value;
                 self.changed = true;
-
-                ctx.animation.add_once(ctx.current, || {
-                    Animation::new()
-                        .oneshot(true)
-                        .with(easing::sine_in_out)
-                        .schedule(Duration::from_millis(50))
-                        .unwrap()
-                });
             }
 
             _ => return Handled::Bubble,
@@ -244,15 +242,34 @@ impl View for ToggleSwitchView {
 
         render.fill_with(Pixel::new(style.track).fg(color));
 
-        let extent = self.axis.main::<f32>(rect.size()) - 1.0;
+        let target = selected as u8 as f32;
+
+        // the value changed (either by us, or by the user), so start moving from where the knob currently is
+        if self.target != selected {
+            self.target = selected;
+            self.from = self.position;
+
+            let duration = style.transition.mul_f32((target - self.from).abs());
+            if duration.is_zero() {
+                render.animation.remove(render.current);
+            } else {
+                let animation = Animation::new()
+                    .oneshot(true)
+                    .with(easing::sine_in_out)
+                    .schedule(duration)
+                    .unwrap();
+                render.animation.add(render.current, animation, 0.0);
+            }
+        }
 
-        let x = match render.animation.get_mut(render.current) {
-            Some(animation) if selected => lerp(0.0, extent, *animation.value),
-            Some(animation) if !selected => lerp(extent, 0.0, *animation.value),
-            _ if selected => extent,
-            _ => 0.0,
+        self.position = match render.animation.get(render.current) {
+            Some(animation) => lerp(self.from, target, *animation.value),
+            None => target,
         };
 
+        let extent = self.axis.main::<f32>(rect.size()) - 1.0;
+        let x = (self.position * extent).round();
+
         let color = match (render.is_hovered(), selected) {
             (true, true) => style.on_knob_hovered.unwrap_or(style.on_knob_color),
             (true, false) => style.off_knob_hovered.unwrap_or(style.off_knob_color),
@@ -271,6 +288,55 @@ impl View for ToggleSwitchView {
     }
 }
 
+/// Create a [`ToggleSwitch`] for this value
+///
+/// When the value changes, the knob slides over the [`ToggleStyle::transition`]
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use too::{animation::Animations, layout::Axis, math::{pos2, vec2, Rect}, renderer::{Cell, Pixel, Surface}, view::{CroppedSurface, Palette, State, ViewExt as _}, views::{toggle_switch, ToggleStyle}};
+/// let palette = Palette::dark();
+/// let mut state = State::new(palette, Animations::new());
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(10, 1));
+///
+/// let style = ToggleStyle {
+///     transition: Duration::from_millis(100),
+///     ..ToggleStyle::default(&palette, Axis::Horizontal, false)
+/// };
+///
+/// let mut value = false;
+/// let frame = |state: &mut State, style: ToggleStyle, value: &mut bool, dt| {
+///     state.update(dt);
+///     state.build(rect, |ui| toggle_switch(value).style(style).show(ui));
+///
+///     let mut surface = Surface::new(rect.size());
+///     state.render(&mut CroppedSurface { clip_rect: surface.rect(), surface: &mut surface });
+///
+///     // where the knob was drawn
+///     (0..4).find(|&x| {
+///         let on = Cell::Pixel(Pixel::new(style.on_knob).fg(style.on_knob_color).bg(palette.background));
+///         let off = Cell::Pixel(Pixel::new(style.off_knob).fg(style.off_knob_color).bg(palette.background));
+///         surface.get(pos2(x, 0)).is_some_and(|cell| *cell == on || *cell == off)
+///     })
+/// };
+///
+/// assert_eq!(frame(&mut state, style, &mut value, 0.0), Some(0));
+///
+/// // changing the value starts the knob moving
+/// value = true;
+/// assert_eq!(frame(&mut state, style, &mut value, 0.0), Some(0));
+/// assert_eq!(frame(&mut state, style, &mut value, 0.05), Some(2));
+///
+/// // changing it back halfway reverses from where the knob was, rather than snapping
+/// value = false;
+/// assert_eq!(frame(&mut state, style, &mut value, 0.0), Some(2));
+/// assert_eq!(frame(&mut state, style, &mut value, 1.0), Some(0));
+///
+/// // without a transition, the knob moves instantly
+/// let instant = ToggleStyle { transition: Duration::ZERO, ..style };
+/// value = true;
+/// assert_eq!(frame(&mut state, instant, &mut value, 0.0), Some(3));
+/// ```
 pub fn toggle_switch(value: &mut bool) -> ToggleSwitch<'_> {
     ToggleSwitch::new(value)
 }
@@ -0,0 +1,248 @@
+use std::{marker::PhantomData, time::Duration};
+
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{
+    math::{vec2, Pos2, Size, Space},
+    renderer::Rgba,
+    view::{
+        Builder, EventCtx, Handled, Interest, Layout, Palette, StyleKind, Ui, View, ViewEvent,
+        ViewId,
+    },
+    Str,
+};
+
+use super::{forward_view, label, Background, LabelStyle};
+
+pub type TooltipClass = fn(&Palette) -> TooltipStyle;
+
+/// The style of a [`Tooltip`]
+#[derive(Debug, Copy, Clone)]
+pub struct TooltipStyle {
+    /// The color of the text
+    pub foreground: Rgba,
+    /// The background color behind the text
+    pub background: Rgba,
+}
+
+impl TooltipStyle {
+    pub fn default(palette: &Palette) -> Self {
+        Self {
+            foreground: palette.foreground,
+            background: palette.surface,
+        }
+    }
+}
+
+/// A builder with a tooltip attached to it
+///
+/// This is created by [`ViewExt::tooltip`](crate::view::ViewExt::tooltip)
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Tooltip<B> {
+    text: Str,
+    delay: Duration,
+    class: StyleKind<TooltipClass, TooltipStyle>,
+    builder: B,
+}
+
+impl<B> Tooltip<B> {
+    pub(crate) fn new(text: impl Into<Str>, builder: B) -> Self {
+        Self {
+            text: text.into(),
+            delay: Duration::from_millis(500),
+            class: StyleKind::Deferred(TooltipStyle::default),
+            builder,
+        }
+    }
+
+    /// How long the mouse has to rest on the view before the tooltip is shown
+    ///
+    /// By default this is half a second
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn class(mut self, class: TooltipClass) -> Self {
+        self.class = StyleKind::Deferred(class);
+        self
+    }
+
+    pub fn style(mut self, style: TooltipStyle) -> Self {
+        self.class = StyleKind::Direct(style);
+        self
+    }
+}
+
+impl<'v, B: Builder<'v>> Builder<'v> for Tooltip<B> {
+    type View = TooltipView<B::View>;
+}
+
+/// A view that shows a tooltip when its inner view is hovered
+///
+/// The inner view is shown as its child, so it keeps its response and events.
+///
+/// The tooltip floats on [`Layer::Top`](crate::view::Layer::Top) near the mouse, and isn't interested in any events.
+///
+/// While the mouse rests on the view, but before the tooltip is shown, the view is [animating](crate::view::State::is_animating)
+///
+/// ```rust
+/// # use too::{backend::Event, math::{pos2, vec2, Rect}, view::{State, ViewExt as _}, views::label};
+/// let mut state = State::default();
+/// let rect = Rect::from_min_size(pos2(0, 0), vec2(20, 3));
+/// let app = |ui: &too::view::Ui| _ = ui.show(label("save").tooltip("Ctrl+S"));
+///
+/// state.build(rect, app);
+/// state.build(rect, app);
+/// assert!(!state.is_animating());
+///
+/// state.event(&Event::MouseMove { pos: pos2(2, 0) });
+/// state.update(0.1);
+/// state.build(rect, app);
+/// assert!(state.is_animating());
+///
+/// // once its shown, there is nothing left to wait for
+/// state.update(1.0);
+/// state.build(rect, app);
+/// assert!(!state.is_animating());
+/// ```
+#[derive(Debug)]
+pub struct TooltipView<V> {
+    text: Str,
+    delay: f32,
+    hovered: f32,
+    // the mouse is resting on the view, but the tooltip isn't shown yet
+    waiting: bool,
+    offset: Option<Pos2>,
+    float: Option<ViewId>,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V: View> View for TooltipView<V> {
+    type Args<'v> = Tooltip<V::Args<'v>>;
+    type Response = V::Response;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            text: args.text,
+            delay: args.delay.as_secs_f32(),
+            hovered: 0.0,
+            waiting: false,
+            offset: None,
+            float: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.text = args.text;
+        self.delay = args.delay.as_secs_f32();
+
+        let resp = ui.show(Inner::<V>(args.builder)).into_inner();
+        self.float = None;
+
+        self.waiting = false;
+        if !ui.is_hovered() {
+            self.hovered = 0.0;
+            self.offset = None;
+            return resp;
+        }
+
+        if self.offset.is_none() {
+            self.hovered += ui.dt();
+            if self.hovered >= self.delay {
+                self.offset = self.place(ui);
+            }
+            self.waiting = self.offset.is_none();
+        }
+
+        if self.offset.is_some() {
+            let style = match args.class {
+                StyleKind::Deferred(class) => (class)(&ui.palette()),
+                StyleKind::Direct(style) => style,
+            };
+
+            let float = ui.float(|ui| {
+                ui.show_children(Background::new(style.background), |ui| {
+                    ui.show(label(self.text.clone()).style(LabelStyle {
+                        foreground: style.foreground,
+                    }));
+                });
+            });
+            self.float = Some(float.id());
+        }
+
+        resp
+    }
+
+    fn interests(&self) -> Interest {
+        // the delay only needs to tick while the mouse is resting on the view
+        if self.waiting {
+            Interest::MOUSE_MOVE | Interest::ANIMATE
+        } else {
+            Interest::MOUSE_MOVE
+        }
+    }
+
+    fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
+        // moving the mouse hides the tooltip, and it has to rest again before its shown
+        if let ViewEvent::MouseMove { .. } = event {
+            self.hovered = 0.0;
+            self.offset = None;
+        }
+        Handled::Bubble
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        let node = layout.nodes.get_current();
+        let mut size = Size::ZERO;
+        for &child in &node.children {
+            if Some(child) != self.float {
+                size = size.max(layout.compute(child, space));
+                continue;
+            }
+
+            layout.compute(child, Space::new(Size::ZERO, Size::INFINITY));
+            layout.set_position(child, self.offset.unwrap_or_default());
+        }
+        space.fit(size)
+    }
+}
+
+impl<V> TooltipView<V> {
+    // below and to the right of the mouse, but kept on the screen. this is relative to our rect
+    fn place(&self, ui: &Ui) -> Option<Pos2> {
+        let rect = ui.rect_of(ui.current())?;
+        let screen = ui.client_rect();
+        let size = vec2(self.text.width() as i32, 1);
+
+        let pos = ui.cursor_pos() + vec2(1, 1);
+        let max = (screen.right_bottom() - size).max(screen.left_top());
+        Some(pos.clamp(screen.left_top(), max) - rect.left_top().to_vec2())
+    }
+}
+
+// this lets any view be shown as our child, without requiring its args to be its builder for every lifetime
+struct Inner<'v, V: View>(V::Args<'v>);
+
+impl<'v, V: View> Builder<'v> for Inner<'v, V> {
+    type View = InnerView<V>;
+}
+
+#[derive(Debug)]
+struct InnerView<V>(V);
+
+impl<V: View> View for InnerView<V> {
+    type Args<'v> = Inner<'v, V>;
+    type Response = V::Response;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self(V::create(args.0))
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.0.update(args.0, ui)
+    }
+
+    forward_view!(0 => flex, interactive, interests, primary_axis, event, size, layout_hash, layout, draw);
+}
@@ -0,0 +1,297 @@
+use std::ops::Range;
+
+use crate::{
+    backend::Key,
+    layout::Axis,
+    lock::{Lock, Shared},
+    math::{pos2, Size, Space},
+    view::{Builder, EventCtx, Handled, Interest, Layout, Ui, View, ViewEvent},
+};
+
+/// A vertical list that only shows the rows that are visible
+///
+/// This is created by [`list_virtual`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[must_use = "a view does nothing unless it is shown with `Ui::show_virtual`"]
+pub struct VirtualList {
+    total: usize,
+    row_height: Option<i32>,
+    overscan: usize,
+}
+
+impl VirtualList {
+    /// How tall each row is
+    ///
+    /// By default, the first visible row is measured and every row is assumed to be that tall
+    pub const fn row_height(mut self, row_height: i32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// How many rows, before and after the visible ones, should also be shown
+    ///
+    /// By default this is `2`
+    pub const fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+impl<'v> Builder<'v> for VirtualList {
+    type View = VirtualListView;
+}
+
+#[derive(Debug)]
+struct VirtualState {
+    total: usize,
+    row_height: i32,
+    // the scroll offset, in cells
+    offset: i32,
+    // how tall the view was, the last time it was laid out
+    viewport: Option<i32>,
+    // the rows that are currently children of the view, in order
+    shown: Range<usize>,
+    // a row to scroll to, before the next rows are shown
+    target: Option<usize>,
+}
+
+impl VirtualState {
+    fn content(&self) -> i32 {
+        (self.total as i32).saturating_mul(self.row_height)
+    }
+
+    fn clamp(&mut self) {
+        let max = self.content() - self.viewport.unwrap_or(0);
+        self.offset = self.offset.clamp(0, max.max(0));
+    }
+
+    fn visible(&self, viewport: i32) -> Range<usize> {
+        let start = (self.offset / self.row_height) as usize;
+        let end = (self.offset + viewport + self.row_height - 1) / self.row_height;
+        start.min(self.total)..(end.max(0) as usize).min(self.total)
+    }
+
+    fn update(&mut self, opts: &VirtualList, screen: i32) {
+        self.total = opts.total;
+        if let Some(height) = opts.row_height {
+            self.row_height = height.max(1);
+        }
+        if let Some(index) = self.target.take() {
+            self.scroll_to(index);
+        }
+        self.clamp();
+
+        // before the first layout, assume the list could be as tall as the screen
+        let visible = self.visible(self.viewport.unwrap_or(screen));
+        let start = visible.start.saturating_sub(opts.overscan);
+        let end = visible.end.saturating_add(opts.overscan).min(self.total);
+        self.shown = start..end;
+    }
+
+    fn scroll_to(&mut self, index: usize) {
+        let index = index.min(self.total.saturating_sub(1)) as i32;
+        let top = index * self.row_height;
+        let bottom = top + self.row_height;
+        let viewport = self.viewport.unwrap_or(0);
+
+        if top < self.offset {
+            self.offset = top;
+        } else if bottom > self.offset + viewport {
+            self.offset = bottom - viewport;
+        }
+        self.clamp();
+    }
+}
+
+impl Default for VirtualState {
+    fn default() -> Self {
+        Self {
+            total: 0,
+            row_height: 1,
+            offset: 0,
+            viewport: None,
+            shown: 0..0,
+            target: None,
+        }
+    }
+}
+
+/// The response from a [`VirtualList`]
+#[derive(Debug, Default)]
+pub struct VirtualListResponse {
+    state: Shared<Lock<VirtualState>>,
+}
+
+impl VirtualListResponse {
+    /// The rows that are visible in the list
+    ///
+    /// This doesn't include the overscan
+    pub fn visible(&self) -> Range<usize> {
+        let state = self.state.borrow();
+        state.visible(state.viewport.unwrap_or(0))
+    }
+
+    /// The rows that were shown, including the overscan
+    pub fn shown(&self) -> Range<usize> {
+        self.state.borrow().shown.clone()
+    }
+
+    /// How far the list has been scrolled, in cells
+    pub fn offset(&self) -> i32 {
+        self.state.borrow().offset
+    }
+
+    /// Scroll just enough so this row is visible
+    ///
+    /// This happens when the list is next shown
+    pub fn scroll_to(&self, index: usize) {
+        self.state.borrow_mut().target = Some(index);
+    }
+
+    // the view isn't updated on its first frame, so this response doesn't have its state yet
+    pub(crate) fn rows(&self, opts: &VirtualList, ui: &Ui) -> Range<usize> {
+        let mut state = self.state.borrow_mut();
+        if state.viewport.is_none() {
+            state.update(opts, ui.client_rect().height());
+        }
+        state.shown.clone()
+    }
+}
+
+/// A vertical list of rows, of which only the visible ones (and a few more) are shown
+///
+/// When focused:
+///
+/// | key | action |
+/// | --- | --- |
+/// | `Up` / `Down` | scroll by a row |
+/// | `PageUp` / `PageDown` | scroll by the height of the list |
+/// | `Home` / `End` | scroll to the first or last row |
+///
+/// Rows are matched to their views by position, so a row that scrolls can get the view (and its state) another row used to have
+#[derive(Debug)]
+pub struct VirtualListView {
+    opts: VirtualList,
+    state: Shared<Lock<VirtualState>>,
+}
+
+impl VirtualListView {
+    fn scroll(&mut self, delta: i32) {
+        let mut state = self.state.borrow_mut();
+        state.offset = state.offset.saturating_add(delta);
+        state.clamp();
+    }
+}
+
+impl View for VirtualListView {
+    type Args<'v> = VirtualList;
+    type Response = VirtualListResponse;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        let this = Self {
+            opts: args,
+            state: Shared::default(),
+        };
+        this.state.borrow_mut().update(&this.opts, 0);
+        this
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, ui: &Ui) -> Self::Response {
+        self.opts = args;
+        let screen = ui.client_rect().height();
+        self.state.borrow_mut().update(&self.opts, screen);
+        VirtualListResponse {
+            state: Shared::clone(&self.state),
+        }
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        Interest::MOUSE_INSIDE | Interest::FOCUS_INPUT
+    }
+
+    fn primary_axis(&self) -> Axis {
+        Axis::Vertical
+    }
+
+    fn event(&mut self, event: ViewEvent, _: EventCtx) -> Handled {
+        let (row, viewport) = {
+            let state = self.state.borrow();
+            (state.row_height, state.viewport.unwrap_or(0))
+        };
+
+        let delta = match event {
+            ViewEvent::MouseScroll { delta, modifiers } => {
+                let scale = if modifiers.is_ctrl() { 3 } else { 1 };
+                delta.y * scale
+            }
+            ViewEvent::KeyInput { key, .. } => match key {
+                Key::Up => -row,
+                Key::Down => row,
+                Key::PageUp => -viewport,
+                Key::PageDown => viewport,
+                Key::Home => i32::MIN,
+                Key::End => i32::MAX,
+                _ => return Handled::Bubble,
+            },
+            _ => return Handled::Bubble,
+        };
+
+        self.scroll(delta);
+        Handled::Sink
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        layout.enable_clipping();
+
+        let node = layout.nodes.get_current();
+        let mut state = self.state.borrow_mut();
+
+        let max = Size::new(
+            space.max.width,
+            match self.opts.row_height {
+                Some(height) => height as f32,
+                None => f32::INFINITY,
+            },
+        );
+
+        let mut width = 0.0_f32;
+        let mut measured = None;
+        for &child in &node.children {
+            let size = layout.compute(child, Space::new(Size::ZERO, max));
+            width = width.max(size.width);
+            measured.get_or_insert(size.height);
+        }
+
+        if let (None, Some(height)) = (self.opts.row_height, measured) {
+            state.row_height = (height.ceil() as i32).max(1);
+        }
+
+        let size = space.fit(Size::new(width, state.content() as f32));
+        state.viewport = Some(size.height.round() as i32);
+        state.clamp();
+
+        for (index, &child) in (state.shown.start..).zip(&node.children) {
+            let y = index as i32 * state.row_height - state.offset;
+            layout.set_position(child, pos2(0, y));
+        }
+
+        size
+    }
+}
+
+/// Create a [`VirtualList`] for `total` rows
+///
+/// Its rows are shown with [`Ui::show_virtual`](crate::view::Ui::show_virtual).
+///
+/// See [`Ui::list_virtual`](crate::view::Ui::list_virtual) for a shorthand
+pub const fn list_virtual(total: usize) -> VirtualList {
+    VirtualList {
+        total,
+        row_height: None,
+        overscan: 2,
+    }
+}
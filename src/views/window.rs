@@ -0,0 +1,307 @@
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{
+    math::{pos2, vec2, Pos2, Rect, Size, Space, Vec2},
+    renderer::{Border, Pixel, TextShape},
+    view::{
+        Builder, Elements, EventCtx, Handled, Interest, Layer, Layout, Render, StyleKind, Ui, View,
+        ViewEvent,
+    },
+    Str,
+};
+
+use super::{BorderClass, BorderStyle};
+
+/// A bordered window, with a title bar, that can be moved around by dragging its title bar
+///
+/// This is created by [`window`]
+#[must_use = "a view does nothing unless `show()` or `show_children()` is called"]
+pub struct Window<'a> {
+    title: Str,
+    pos: &'a mut Pos2,
+    closable: bool,
+    border: Border,
+    class: StyleKind<BorderClass, BorderStyle>,
+}
+
+impl<'a> Window<'a> {
+    /// Should the title bar have a close button?
+    ///
+    /// Clicking it sets [`WindowResponse::closed`], its up to you to stop showing the window
+    pub const fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// The border to draw around the window
+    ///
+    /// By default this is [`Border::THICK`]
+    pub const fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub const fn class(mut self, class: BorderClass) -> Self {
+        self.class = StyleKind::deferred(class);
+        self
+    }
+
+    pub const fn style(mut self, style: BorderStyle) -> Self {
+        self.class = StyleKind::direct(style);
+        self
+    }
+}
+
+impl<'v> Builder<'v> for Window<'v> {
+    type View = WindowView;
+}
+
+/// The response from a [`Window`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct WindowResponse {
+    moved: bool,
+    closed: bool,
+    raised: bool,
+}
+
+impl WindowResponse {
+    /// Was the window moved, either by dragging it or by keeping it on-screen?
+    pub const fn moved(&self) -> bool {
+        self.moved
+    }
+
+    /// Was the close button clicked?
+    pub const fn closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Was the window pressed on, and should be brought to the front?
+    ///
+    /// Windows are stacked in the order they are shown, so show this one last to bring it to the front
+    pub const fn raised(&self) -> bool {
+        self.raised
+    }
+}
+
+/// A view that draws a window, with its children inside of it
+///
+/// The window is placed at its position relative to where it is shown, and is kept inside of the space it was given.
+/// When shown at the top of the tree, that is a position on the screen.
+///
+/// It takes up the space from where it is shown, to the bottom-right corner of the window,
+/// so windows should be shown after everything else.
+///
+/// Windows are drawn on [`Layer::Top`], and stacked in the order they are shown
+#[derive(Debug)]
+pub struct WindowView {
+    title: Str,
+    pos: Pos2,
+    size: Vec2,
+    closable: bool,
+    border: Border,
+    class: StyleKind<BorderClass, BorderStyle>,
+
+    grabbed: bool,
+    moved: bool,
+    closed: bool,
+    raised: bool,
+}
+
+impl WindowView {
+    // the window, relative to this view
+    fn frame(&self) -> Rect {
+        Rect::from_min_size(self.pos, self.size)
+    }
+
+    fn close_button(&self) -> Option<Pos2> {
+        self.closable
+            .then(|| self.pos + vec2(self.size.x - 2, 0))
+            .filter(|pos| pos.x > self.pos.x)
+    }
+}
+
+impl View for WindowView {
+    type Args<'v> = Window<'v>;
+    type Response = WindowResponse;
+
+    fn create(args: Self::Args<'_>) -> Self {
+        Self {
+            title: args.title,
+            pos: *args.pos,
+            size: Vec2::ZERO,
+            closable: args.closable,
+            border: args.border,
+            class: args.class,
+
+            grabbed: false,
+            moved: false,
+            closed: false,
+            raised: false,
+        }
+    }
+
+    fn update(&mut self, args: Self::Args<'_>, _: &Ui) -> Self::Response {
+        self.title = args.title;
+        self.closable = args.closable;
+        self.border = args.border;
+        self.class = args.class;
+
+        let moved = std::mem::take(&mut self.moved);
+        if moved {
+            *args.pos = self.pos;
+        } else if self.pos != *args.pos {
+            self.pos = *args.pos;
+        }
+
+        WindowResponse {
+            moved,
+            closed: std::mem::take(&mut self.closed),
+            raised: std::mem::take(&mut self.raised),
+        }
+    }
+
+    fn interactive(&self) -> bool {
+        true
+    }
+
+    fn interests(&self) -> Interest {
+        Interest::MOUSE
+    }
+
+    fn event(&mut self, event: ViewEvent, ctx: EventCtx) -> Handled {
+        let origin = ctx.rect().left_top().to_vec2();
+        let frame = self.frame().translate(origin);
+
+        match event {
+            // every drag starts with a press, so this is where a grab starts or ends
+            ViewEvent::MouseHeld { pos, inside, .. } => {
+                self.grabbed = inside && pos.y == frame.top() && frame.contains(pos);
+                if !inside || !frame.contains(pos) {
+                    return Handled::Bubble;
+                }
+                self.raised = true;
+                Handled::Sink
+            }
+
+            ViewEvent::MouseClicked {
+                pos, inside: true, ..
+            } if frame.contains(pos) => {
+                if self.close_button().map(|close| close + origin) == Some(pos) {
+                    self.closed = true;
+                }
+                Handled::Sink
+            }
+
+            ViewEvent::MouseDrag { delta, .. } if self.grabbed => {
+                self.pos += delta;
+                self.moved = true;
+                Handled::Sink
+            }
+
+            _ => Handled::Bubble,
+        }
+    }
+
+    fn layout(&mut self, mut layout: Layout, space: Space) -> Size {
+        layout.set_layer(Layer::Top);
+        layout.new_layer();
+
+        // the title bar is always there, even without a border
+        let mut margin = self.border.as_margin();
+        margin.top = margin.top.max(1);
+        let (margin, offset) = (margin.sum(), margin.left_top());
+
+        let node = layout.nodes.get_current();
+        let mut size = Size::ZERO;
+        let child_space = Space::new(Size::ZERO, space.max - margin);
+        for &child in &node.children {
+            size = size.max(layout.compute(child, child_space));
+        }
+
+        // the title, a column on each side of it and the close button
+        let title = self.title.width() as f32 + 2.0 + if self.closable { 2.0 } else { 0.0 };
+        let size = Size::new(
+            (size.width + margin.width).max(title),
+            size.height + margin.height,
+        );
+        self.size = space.fit(size).into();
+
+        // keep the whole window inside of the space, the right and bottom edges are exclusive
+        let max = (Vec2::from(space.max) - self.size).max(Vec2::ZERO);
+        let pos = self.pos.clamp(Pos2::ZERO, max.to_pos2());
+        if pos != self.pos {
+            self.pos = pos;
+            self.moved = true;
+        }
+
+        for &child in &node.children {
+            layout.set_position(child, self.pos + offset.to_vec2());
+        }
+
+        space.fit(Size::from(self.pos.to_vec2() + self.size))
+    }
+
+    fn draw(&mut self, mut render: Render) {
+        let frame = self.frame();
+        let hovered = frame
+            .translate(render.offset().to_vec2())
+            .contains(render.mouse_pos());
+        let focused = render.is_focus_within();
+
+        let style = match self.class {
+            StyleKind::Deferred(style) => (style)(render.palette, hovered, focused),
+            StyleKind::Direct(style) => style,
+        };
+
+        let color = match (focused, hovered) {
+            (true, _) => style.border_focused.unwrap_or(style.border),
+            (false, true) => style.border_hovered.unwrap_or(style.border),
+            (false, false) => style.border,
+        };
+
+        let background = render.palette.background;
+        let close = self.close_button().map(|pos| pos - self.pos.to_vec2());
+        render.crop(frame, |render| {
+            let (w, h) = (frame.width() - 1, frame.height() - 1);
+            let border = self.border;
+            render
+                .fill_bg(background)
+                .horizontal_line(0, 1..=w, Pixel::new(border.top).fg(color))
+                .horizontal_line(h, 1..=w, Pixel::new(border.bottom).fg(color))
+                .vertical_line(0, 1..=h, Pixel::new(border.left).fg(color))
+                .vertical_line(w, 1..=h, Pixel::new(border.right).fg(color))
+                .set(pos2(0, 0), Pixel::new(border.left_top).fg(color))
+                .set(pos2(w, 0), Pixel::new(border.right_top).fg(color))
+                .set(pos2(0, h), Pixel::new(border.left_bottom).fg(color))
+                .set(pos2(w, h), Pixel::new(border.right_bottom).fg(color));
+
+            if let Some(close) = close {
+                render.set(close, Pixel::new(Elements::CROSS).fg(style.title));
+            }
+        });
+
+        // leave room for the close button, and the corners
+        let width = frame.width() - 2 - if close.is_some() { 2 } else { 0 };
+        let title = Rect::from_min_size(self.pos + vec2(1, 0), vec2(width.max(0), 1));
+        render.crop(title, |render| {
+            render.text(TextShape::new(&self.title).fg(style.title));
+        });
+
+        self.default_draw(render);
+    }
+}
+
+/// Create a [`Window`] with a title, at a position
+///
+/// Dragging the title bar moves the window, and updates the position. The window is kept on-screen.
+///
+/// See [`Ui::window`](crate::view::Ui::window) for a shorthand
+pub fn window(title: impl Into<Str>, pos: &mut Pos2) -> Window<'_> {
+    Window {
+        title: title.into(),
+        pos,
+        closable: false,
+        border: Border::THICK,
+        class: StyleKind::deferred(BorderStyle::default),
+    }
+}
@@ -11,6 +11,7 @@ pub struct Wrap {
     axis: Axis,
     main_justify: Justify,
     cross_justify: Justify,
+    last_justify: Justify,
     cross_align: Align,
     row_gap: f32,
     column_gap: f32,
@@ -22,6 +23,7 @@ impl Wrap {
             axis,
             main_justify: Justify::Start,
             cross_justify: Justify::Start,
+            last_justify: Justify::Start,
             cross_align: Align::START,
             row_gap: 0.0,
             column_gap: 0.0,
@@ -33,6 +35,62 @@ impl Wrap {
         self
     }
 
+    /// How the space left over on each line is shared between its children
+    ///
+    /// This is the same as [`main_justify`](Self::main_justify).
+    ///
+    /// Once the children wrap, the last line uses [`last_justify`](Self::last_justify) instead.
+    ///
+    /// ```rust
+    /// # use too::{layout::Justify, view::test::render_to_string, views::horizontal_wrap};
+    /// let out = render_to_string(|ui| {
+    ///     ui.exact_width(10, |ui| {
+    ///         ui.show_children(horizontal_wrap().row_gap(1).justify(Justify::SpaceBetween), |ui| {
+    ///             for label in ["aa", "bb", "cc", "dd", "ee"] {
+    ///                 ui.label(label);
+    ///             }
+    ///         });
+    ///     });
+    /// });
+    /// assert_eq!(out.lines().take(2).map(str::trim_end).collect::<Vec<_>>(), ["aa  bb  cc", "dd ee"]);
+    /// ```
+    pub const fn justify(self, justify: Justify) -> Self {
+        self.main_justify(justify)
+    }
+
+    /// How the last line is justified, once the children wrap
+    ///
+    /// This defaults to [`Justify::Start`], so a short last line isn't spread out. If the children fit on one line, [`justify`](Self::justify) is used.
+    ///
+    /// ```rust
+    /// # use too::{layout::Justify, view::test::render_to_string, views::horizontal_wrap};
+    /// let wrap = |last: Option<Justify>| {
+    ///     render_to_string(|ui| {
+    ///         ui.exact_width(10, |ui| {
+    ///             let mut wrap = horizontal_wrap().row_gap(1).justify(Justify::Center);
+    ///             if let Some(last) = last {
+    ///                 wrap = wrap.last_justify(last);
+    ///             }
+    ///             ui.show_children(wrap, |ui| {
+    ///                 for label in ["aa", "bb", "cc", "dd"] {
+    ///                     ui.label(label);
+    ///                 }
+    ///             });
+    ///         });
+    ///     })
+    /// };
+    /// let lines = |out: String| out.lines().take(2).map(|s| s.trim_end().to_string()).collect::<Vec<_>>();
+    ///
+    /// // the short last line isn't centered with the others
+    /// assert_eq!(lines(wrap(None)), [" aa bb cc", "dd"]);
+    /// // unless its configured otherwise
+    /// assert_eq!(lines(wrap(Some(Justify::Center))), [" aa bb cc", "    dd"]);
+    /// ```
+    pub const fn last_justify(mut self, last_justify: Justify) -> Self {
+        self.last_justify = last_justify;
+        self
+    }
+
     pub const fn cross_justify(mut self, cross_justify: Justify) -> Self {
         self.cross_justify = cross_justify;
         self
@@ -165,9 +223,12 @@ impl View for WrapView {
             let run = self.state.runs[i].clone();
             let run_cross = self.state.cross[i];
 
-            for (child, j) in self
-                .wrap
-                .main_justify
+            let justify = match self.state.runs.len() {
+                len if len > 1 && i == len - 1 => self.wrap.last_justify,
+                _ => self.wrap.main_justify,
+            };
+
+            for (child, j) in justify
                 .layout(&self.state.main[run.clone()], main, main_gap)
                 .zip(run)
             {